@@ -0,0 +1,736 @@
+/*!
+Container query parsing and representation.
+
+Parses `@container` conditions (optional container name, size features,
+range syntax and `style()` queries) into a normalized form so that
+equivalent queries are printed identically.
+*/
+
+use crate::{
+  CssParseError,
+  at_queries::{
+    has_balanced_parens,
+    media_query::{Fraction, MediaRulePair, MediaRuleValue},
+    messages::ContainerQueryErrors,
+  },
+  css_types::Length,
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList, stringify_tokens},
+};
+use std::fmt::{self, Display};
+
+/// Size features that accept `min-` and `max-` prefixes and range syntax
+const RANGE_SIZE_FEATURES: [&str; 4] = ["width", "height", "inline-size", "block-size"];
+
+/// Names that cannot be used as a container name
+const RESERVED_CONTAINER_NAMES: [&str; 5] = ["none", "and", "not", "or", "style"];
+
+/// Style query for CSS container queries, e.g. `style(--theme: dark)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerStyleQuery {
+  pub property: String,
+  pub value: Option<String>,
+}
+
+impl ContainerStyleQuery {
+  pub fn new(property: String, value: Option<String>) -> Self {
+    Self { property, value }
+  }
+}
+
+impl Display for ContainerStyleQuery {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.value {
+      Some(value) => write!(f, "style({}: {})", self.property, value),
+      None => write!(f, "style({})", self.property),
+    }
+  }
+}
+
+/// All container query rules
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerQueryRule {
+  Feature(MediaRulePair),
+  Style(ContainerStyleQuery),
+  Not(Box<ContainerQueryRule>),
+  And(Vec<ContainerQueryRule>),
+  Or(Vec<ContainerQueryRule>),
+}
+
+impl Display for ContainerQueryRule {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", ContainerQuery::format_rule(self, true))
+  }
+}
+
+/// Main ContainerQuery struct for CSS container queries
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerQuery {
+  pub name: Option<String>,
+  pub condition: Option<ContainerQueryRule>,
+}
+
+impl ContainerQuery {
+  pub fn new(name: Option<String>, condition: Option<ContainerQueryRule>) -> Self {
+    Self {
+      name,
+      condition: condition.map(Self::normalize),
+    }
+  }
+
+  pub fn normalize(rule: ContainerQueryRule) -> ContainerQueryRule {
+    match rule {
+      ContainerQueryRule::Not(inner) => match Self::normalize(*inner) {
+        ContainerQueryRule::Not(double_negated) => *double_negated,
+        normalized => ContainerQueryRule::Not(Box::new(normalized)),
+      },
+      ContainerQueryRule::And(rules) => {
+        let mut flattened = Vec::new();
+
+        for rule in rules {
+          match Self::normalize(rule) {
+            ContainerQueryRule::And(inner) => flattened.extend(inner),
+            normalized => flattened.push(normalized),
+          }
+        }
+
+        Self::single_or(
+          merge_size_bounds(dedupe(flattened)),
+          ContainerQueryRule::And,
+        )
+      }
+      ContainerQueryRule::Or(rules) => {
+        let mut flattened = Vec::new();
+
+        for rule in rules {
+          match Self::normalize(rule) {
+            ContainerQueryRule::Or(inner) => flattened.extend(inner),
+            normalized => flattened.push(normalized),
+          }
+        }
+
+        Self::single_or(dedupe(flattened), ContainerQueryRule::Or)
+      }
+      _ => rule,
+    }
+  }
+
+  fn single_or(
+    mut rules: Vec<ContainerQueryRule>,
+    wrap: fn(Vec<ContainerQueryRule>) -> ContainerQueryRule,
+  ) -> ContainerQueryRule {
+    if rules.len() == 1 {
+      rules.remove(0)
+    } else {
+      wrap(rules)
+    }
+  }
+
+  /// Inline-axis bounds (`min-`/`max-` `width` or `inline-size`) that apply
+  /// to every match of the query. Used to order container queries so that
+  /// wider `min-*` and narrower `max-*` queries come later.
+  pub fn inline_size_bounds(&self) -> (Option<Length>, Option<Length>) {
+    let rules = match &self.condition {
+      Some(ContainerQueryRule::And(rules)) => rules.iter().collect::<Vec<_>>(),
+      Some(rule) => vec![rule],
+      None => vec![],
+    };
+
+    let mut lower = None;
+    let mut upper = None;
+
+    for rule in rules {
+      if let ContainerQueryRule::Feature(pair) = rule
+        && let MediaRuleValue::Length(length) = &pair.value
+      {
+        match pair.key.as_str() {
+          "min-width" | "min-inline-size" => lower = Some(length.clone()),
+          "max-width" | "max-inline-size" => upper = Some(length.clone()),
+          _ => {}
+        }
+      }
+    }
+
+    (lower, upper)
+  }
+
+  fn format_rule(rule: &ContainerQueryRule, is_top_level: bool) -> String {
+    match rule {
+      ContainerQueryRule::Feature(pair) => pair.to_string(),
+      ContainerQueryRule::Style(style) => style.to_string(),
+      ContainerQueryRule::Not(inner) => {
+        let inner = match inner.as_ref() {
+          ContainerQueryRule::And(_) | ContainerQueryRule::Or(_) => {
+            format!("({})", Self::format_rule(inner, false))
+          }
+          ContainerQueryRule::Not(_) => Self::format_rule(inner, false),
+          _ => Self::format_rule(inner, false),
+        };
+
+        if is_top_level {
+          format!("not {}", inner)
+        } else {
+          format!("(not {})", inner)
+        }
+      }
+      ContainerQueryRule::And(rules) => Self::format_rules(rules, " and "),
+      ContainerQueryRule::Or(rules) => Self::format_rules(rules, " or "),
+    }
+  }
+
+  fn format_rules(rules: &[ContainerQueryRule], separator: &str) -> String {
+    rules
+      .iter()
+      .map(|rule| match rule {
+        ContainerQueryRule::And(_) | ContainerQueryRule::Or(_) => {
+          format!("({})", Self::format_rule(rule, false))
+        }
+        _ => Self::format_rule(rule, false),
+      })
+      .collect::<Vec<_>>()
+      .join(separator)
+  }
+}
+
+impl Display for ContainerQuery {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "@container")?;
+
+    if let Some(name) = &self.name {
+      write!(f, " {}", name)?;
+    }
+
+    if let Some(condition) = &self.condition {
+      write!(f, " {}", Self::format_rule(condition, true))?;
+    }
+
+    Ok(())
+  }
+}
+
+impl ContainerQuery {
+  pub fn parser() -> TokenParser<ContainerQuery> {
+    TokenParser::new(
+      |tokens| {
+        match tokens.consume_next_token()? {
+          Some(SimpleToken::AtKeyword(keyword)) if keyword == "container" => {}
+          _ => {
+            return Err(CssParseError::ParseError {
+              message: "Expected @container at-keyword".to_string(),
+            });
+          }
+        }
+
+        if !matches!(tokens.peek()?, Some(SimpleToken::Whitespace)) {
+          return Err(CssParseError::ParseError {
+            message: "Expected whitespace after @container".to_string(),
+          });
+        }
+
+        skip_whitespace(tokens)?;
+
+        let name = match tokens.peek()? {
+          Some(SimpleToken::Ident(name)) if name != "not" => {
+            if RESERVED_CONTAINER_NAMES.contains(&name.as_str()) || name.starts_with("--") {
+              return Err(CssParseError::ParseError {
+                message: format!("Invalid container name '{}'", name),
+              });
+            }
+
+            tokens.consume_next_token()?;
+            skip_whitespace(tokens)?;
+
+            Some(name)
+          }
+          _ => None,
+        };
+
+        let condition = if tokens.peek()?.is_some() {
+          Some(parse_condition(tokens)?)
+        } else {
+          None
+        };
+
+        skip_whitespace(tokens)?;
+
+        if name.is_none() && condition.is_none() {
+          return Err(CssParseError::ParseError {
+            message: "Expected a container name or condition".to_string(),
+          });
+        }
+
+        Ok(ContainerQuery::new(name, condition))
+      },
+      "container_query_parser",
+    )
+  }
+}
+
+/// Validate container query string
+pub fn validate_container_query(input: &str) -> Result<ContainerQuery, String> {
+  if !has_balanced_parens(input) {
+    return Err(ContainerQueryErrors::UNBALANCED_PARENS.to_string());
+  }
+
+  ContainerQuery::parser()
+    .parse_to_end(input)
+    .map_err(|_| ContainerQueryErrors::SYNTAX_ERROR.to_string())
+}
+
+fn skip_whitespace(tokens: &mut TokenList) -> Result<(), CssParseError> {
+  while let Some(SimpleToken::Whitespace) = tokens.peek()? {
+    tokens.consume_next_token()?;
+  }
+
+  Ok(())
+}
+
+fn expect_token(tokens: &mut TokenList, expected: SimpleToken) -> Result<(), CssParseError> {
+  match tokens.consume_next_token()? {
+    Some(token) if token == expected => Ok(()),
+    token => Err(CssParseError::ParseError {
+      message: format!("Expected {:?}, got {:?}", expected, token),
+    }),
+  }
+}
+
+/// `not <query-in-parens>` | `<query-in-parens> [ and <query-in-parens> ]*`
+/// | `<query-in-parens> [ or <query-in-parens> ]*`
+fn parse_condition(tokens: &mut TokenList) -> Result<ContainerQueryRule, CssParseError> {
+  if let Some(SimpleToken::Ident(keyword)) = tokens.peek()?
+    && keyword == "not"
+  {
+    tokens.consume_next_token()?;
+    skip_whitespace(tokens)?;
+
+    return Ok(ContainerQueryRule::Not(Box::new(parse_query_in_parens(
+      tokens,
+    )?)));
+  }
+
+  let mut rules = vec![parse_query_in_parens(tokens)?];
+  let mut combinator: Option<String> = None;
+
+  loop {
+    let checkpoint = tokens.save_position();
+
+    skip_whitespace(tokens)?;
+
+    let keyword = match tokens.peek()? {
+      Some(SimpleToken::Ident(keyword)) if keyword == "and" || keyword == "or" => keyword,
+      _ => {
+        tokens.restore_position(checkpoint)?;
+        break;
+      }
+    };
+
+    // Mixing `and` and `or` on the same level requires parentheses
+    if combinator
+      .as_ref()
+      .is_some_and(|current| *current != keyword)
+    {
+      return Err(CssParseError::ParseError {
+        message: "Cannot mix 'and' and 'or' without parentheses".to_string(),
+      });
+    }
+
+    tokens.consume_next_token()?;
+    skip_whitespace(tokens)?;
+
+    rules.push(parse_query_in_parens(tokens)?);
+    combinator = Some(keyword);
+  }
+
+  Ok(match combinator.as_deref() {
+    Some("and") => ContainerQueryRule::And(rules),
+    Some("or") => ContainerQueryRule::Or(rules),
+    _ => rules.remove(0),
+  })
+}
+
+/// `( <container-condition> )` | `( <size-feature> )` | `style( ... )`
+fn parse_query_in_parens(tokens: &mut TokenList) -> Result<ContainerQueryRule, CssParseError> {
+  match tokens.peek()? {
+    Some(SimpleToken::Function(name)) if name == "style" => {
+      tokens.consume_next_token()?;
+      parse_style_query(tokens)
+    }
+    Some(SimpleToken::LeftParen) => {
+      let checkpoint = tokens.save_position();
+
+      if let Ok(feature) = parse_size_feature(tokens) {
+        return Ok(feature);
+      }
+
+      tokens.restore_position(checkpoint)?;
+
+      expect_token(tokens, SimpleToken::LeftParen)?;
+      skip_whitespace(tokens)?;
+
+      let condition = parse_condition(tokens)?;
+
+      skip_whitespace(tokens)?;
+      expect_token(tokens, SimpleToken::RightParen)?;
+
+      Ok(condition)
+    }
+    token => Err(CssParseError::ParseError {
+      message: format!("Expected container query in parentheses, got {:?}", token),
+    }),
+  }
+}
+
+fn parse_size_feature(tokens: &mut TokenList) -> Result<ContainerQueryRule, CssParseError> {
+  expect_token(tokens, SimpleToken::LeftParen)?;
+  skip_whitespace(tokens)?;
+
+  let rule = match tokens.peek()? {
+    Some(SimpleToken::Ident(key)) => {
+      tokens.consume_next_token()?;
+      skip_whitespace(tokens)?;
+
+      match tokens.peek()? {
+        Some(SimpleToken::Colon) => {
+          tokens.consume_next_token()?;
+          skip_whitespace(tokens)?;
+
+          let value = parse_feature_value(tokens)?;
+
+          validate_feature(&key, &value)?;
+
+          ContainerQueryRule::Feature(MediaRulePair::new(key, value))
+        }
+        Some(SimpleToken::Delim('<' | '>')) => {
+          let (op, inclusive) = parse_comparison(tokens)?;
+          let value = parse_range_length(tokens)?;
+
+          range_rule(&key, op, inclusive, value)?
+        }
+        token => {
+          return Err(CssParseError::ParseError {
+            message: format!("Expected ':' or comparison after feature, got {:?}", token),
+          });
+        }
+      }
+    }
+    Some(SimpleToken::Dimension { .. }) => {
+      let lower = parse_range_length(tokens)?;
+      let (first_op, first_inclusive) = parse_comparison(tokens)?;
+
+      let key = match tokens.consume_next_token()? {
+        Some(SimpleToken::Ident(key)) => key,
+        token => {
+          return Err(CssParseError::ParseError {
+            message: format!("Expected size feature name, got {:?}", token),
+          });
+        }
+      };
+
+      skip_whitespace(tokens)?;
+
+      // `400px < width` reads as `width > 400px`
+      let first = range_rule(&key, flip(first_op), first_inclusive, lower)?;
+
+      if let Some(SimpleToken::Delim('<' | '>')) = tokens.peek()? {
+        let (second_op, second_inclusive) = parse_comparison(tokens)?;
+
+        if second_op != first_op {
+          return Err(CssParseError::ParseError {
+            message: "Range comparisons must point in the same direction".to_string(),
+          });
+        }
+
+        let upper = parse_range_length(tokens)?;
+
+        ContainerQueryRule::And(vec![
+          first,
+          range_rule(&key, second_op, second_inclusive, upper)?,
+        ])
+      } else {
+        first
+      }
+    }
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected size feature, got {:?}", token),
+      });
+    }
+  };
+
+  skip_whitespace(tokens)?;
+  expect_token(tokens, SimpleToken::RightParen)?;
+
+  Ok(rule)
+}
+
+fn flip(op: char) -> char {
+  if op == '<' { '>' } else { '<' }
+}
+
+fn parse_comparison(tokens: &mut TokenList) -> Result<(char, bool), CssParseError> {
+  let op = match tokens.consume_next_token()? {
+    Some(SimpleToken::Delim(op @ ('<' | '>'))) => op,
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected '<' or '>', got {:?}", token),
+      });
+    }
+  };
+
+  let inclusive = if let Some(SimpleToken::Delim('=')) = tokens.peek()? {
+    tokens.consume_next_token()?;
+    true
+  } else {
+    false
+  };
+
+  skip_whitespace(tokens)?;
+
+  Ok((op, inclusive))
+}
+
+fn parse_range_length(tokens: &mut TokenList) -> Result<Length, CssParseError> {
+  let length = match tokens.consume_next_token()? {
+    Some(SimpleToken::Dimension { value, unit }) => Length::new(value as f32, unit),
+    Some(SimpleToken::Number(0.0)) => Length::new(0.0, "px".to_string()),
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected length in range comparison, got {:?}", token),
+      });
+    }
+  };
+
+  skip_whitespace(tokens)?;
+
+  Ok(length)
+}
+
+/// `(width > 400px)` is normalized to `(min-width: 400.01px)` the same way
+/// media query ranges are.
+fn range_rule(
+  key: &str,
+  op: char,
+  inclusive: bool,
+  mut length: Length,
+) -> Result<ContainerQueryRule, CssParseError> {
+  const EPSILON: f32 = 0.01;
+
+  if !RANGE_SIZE_FEATURES.contains(&key) {
+    return Err(CssParseError::ParseError {
+      message: format!("'{}' does not support range syntax", key),
+    });
+  }
+
+  let prefix = if op == '>' {
+    if !inclusive {
+      length.value += EPSILON;
+    }
+
+    "min"
+  } else {
+    if !inclusive {
+      length.value -= EPSILON;
+    }
+
+    "max"
+  };
+
+  Ok(ContainerQueryRule::Feature(MediaRulePair::new(
+    format!("{}-{}", prefix, key),
+    MediaRuleValue::Length(length),
+  )))
+}
+
+fn parse_feature_value(tokens: &mut TokenList) -> Result<MediaRuleValue, CssParseError> {
+  match tokens.consume_next_token()? {
+    Some(SimpleToken::Dimension { value, unit }) => {
+      Ok(MediaRuleValue::Length(Length::new(value as f32, unit)))
+    }
+    Some(SimpleToken::Ident(value)) => Ok(MediaRuleValue::String(value)),
+    Some(SimpleToken::Number(numerator)) => {
+      let checkpoint = tokens.save_position();
+
+      skip_whitespace(tokens)?;
+
+      if let Some(SimpleToken::Delim('/')) = tokens.peek()? {
+        tokens.consume_next_token()?;
+        skip_whitespace(tokens)?;
+
+        if let Some(SimpleToken::Number(denominator)) = tokens.consume_next_token()? {
+          return Ok(MediaRuleValue::Fraction(Fraction {
+            numerator: numerator as i32,
+            denominator: denominator as i32,
+          }));
+        }
+
+        return Err(CssParseError::ParseError {
+          message: "Expected denominator in ratio".to_string(),
+        });
+      }
+
+      tokens.restore_position(checkpoint)?;
+
+      Ok(MediaRuleValue::Number(numerator as f32))
+    }
+    token => Err(CssParseError::ParseError {
+      message: format!("Expected size feature value, got {:?}", token),
+    }),
+  }
+}
+
+fn validate_feature(key: &str, value: &MediaRuleValue) -> Result<(), CssParseError> {
+  let base_key = key
+    .strip_prefix("min-")
+    .or_else(|| key.strip_prefix("max-"))
+    .unwrap_or(key);
+
+  let is_valid = match base_key {
+    "width" | "height" | "inline-size" | "block-size" => match value {
+      MediaRuleValue::Length(_) => true,
+      MediaRuleValue::Number(number) => *number == 0.0,
+      _ => false,
+    },
+    "aspect-ratio" => matches!(
+      value,
+      MediaRuleValue::Fraction(_) | MediaRuleValue::Number(_)
+    ),
+    "orientation" => {
+      key == base_key
+        && matches!(value, MediaRuleValue::String(value) if value == "portrait" || value == "landscape")
+    }
+    _ => false,
+  };
+
+  if is_valid {
+    Ok(())
+  } else {
+    Err(CssParseError::ParseError {
+      message: format!("Invalid container size feature '{}: {}'", key, value),
+    })
+  }
+}
+
+/// `style(--name)` or `style(--name: <value>)`; the function token has
+/// already been consumed.
+fn parse_style_query(tokens: &mut TokenList) -> Result<ContainerQueryRule, CssParseError> {
+  skip_whitespace(tokens)?;
+
+  let property = match tokens.consume_next_token()? {
+    Some(SimpleToken::Ident(property)) if property.starts_with("--") => property,
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected custom property in style query, got {:?}", token),
+      });
+    }
+  };
+
+  skip_whitespace(tokens)?;
+
+  let value = match tokens.consume_next_token()? {
+    Some(SimpleToken::RightParen) => return Ok(style_rule(property, None)),
+    Some(SimpleToken::Colon) => {
+      let value = stringify_tokens(&tokens.consume_enclosed()?);
+
+      if value.is_empty() {
+        return Err(CssParseError::ParseError {
+          message: "Expected value in style query".to_string(),
+        });
+      }
+
+      value
+    }
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected ':' or ')' in style query, got {:?}", token),
+      });
+    }
+  };
+
+  Ok(style_rule(property, Some(value)))
+}
+
+fn style_rule(property: String, value: Option<String>) -> ContainerQueryRule {
+  ContainerQueryRule::Style(ContainerStyleQuery::new(property, value))
+}
+
+fn dedupe(rules: Vec<ContainerQueryRule>) -> Vec<ContainerQueryRule> {
+  let mut unique: Vec<ContainerQueryRule> = Vec::with_capacity(rules.len());
+
+  for rule in rules {
+    if !unique.contains(&rule) {
+      unique.push(rule);
+    }
+  }
+
+  unique
+}
+
+/// Keeps only the tightest `min-*` and `max-*` bound of each size feature
+/// when all of its bounds share a unit.
+fn merge_size_bounds(rules: Vec<ContainerQueryRule>) -> Vec<ContainerQueryRule> {
+  let mut merged: Vec<ContainerQueryRule> = Vec::with_capacity(rules.len());
+
+  for rule in rules {
+    let ContainerQueryRule::Feature(pair) = &rule else {
+      merged.push(rule);
+      continue;
+    };
+
+    let MediaRuleValue::Length(length) = &pair.value else {
+      merged.push(rule);
+      continue;
+    };
+
+    let is_min = pair.key.starts_with("min-");
+
+    // Exact features such as `width` are not bounds and are kept as written
+    if !is_min && !pair.key.starts_with("max-") {
+      merged.push(rule);
+      continue;
+    }
+
+    let existing = merged.iter_mut().find(|existing| {
+      matches!(
+        existing,
+        ContainerQueryRule::Feature(existing_pair)
+          if existing_pair.key == pair.key
+            && matches!(&existing_pair.value, MediaRuleValue::Length(existing_length) if existing_length.unit == length.unit)
+      )
+    });
+
+    match existing {
+      Some(ContainerQueryRule::Feature(existing_pair)) => {
+        if let MediaRuleValue::Length(existing_length) = &mut existing_pair.value
+          && ((is_min && length.value > existing_length.value)
+            || (!is_min && length.value < existing_length.value))
+        {
+          existing_length.value = length.value;
+        }
+      }
+      _ => merged.push(rule),
+    }
+  }
+
+  merged
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_container_query_display() {
+    let query = ContainerQuery::parser()
+      .parse_to_end("@container sidebar (min-width: 400px)")
+      .unwrap();
+
+    assert_eq!(query.to_string(), "@container sidebar (min-width: 400px)");
+  }
+
+  #[test]
+  fn test_has_balanced_parens() {
+    assert!(has_balanced_parens("(min-width: 400px)"));
+    assert!(!has_balanced_parens("(min-width: 400px"));
+    assert!(!has_balanced_parens("min-width: 400px)"));
+  }
+}
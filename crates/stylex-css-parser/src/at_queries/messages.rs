@@ -1,5 +1,5 @@
 /*!
-Error messages for media query parsing and validation.
+Error messages for media query, container query and `@supports` parsing and validation.
 */
 
 pub struct MediaQueryErrors;
@@ -9,6 +9,20 @@ impl MediaQueryErrors {
   pub const UNBALANCED_PARENS: &'static str = "Unbalanced parentheses in media query.";
 }
 
+pub struct ContainerQueryErrors;
+
+impl ContainerQueryErrors {
+  pub const SYNTAX_ERROR: &'static str = "Invalid container query syntax.";
+  pub const UNBALANCED_PARENS: &'static str = "Unbalanced parentheses in container query.";
+}
+
+pub struct SupportsConditionErrors;
+
+impl SupportsConditionErrors {
+  pub const SYNTAX_ERROR: &'static str = "Invalid @supports condition syntax.";
+  pub const UNBALANCED_PARENS: &'static str = "Unbalanced parentheses in @supports condition.";
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -1,14 +1,40 @@
 /*!
 At-rule parsing functionality.
 
-This module contains parsers for CSS at-rules, particularly media queries.
+This module contains parsers for CSS at-rules, particularly media and container queries
+and `@supports` conditions.
 Includes support for complex media query parsing, validation, and transformation.
 */
 
+pub mod container_query;
 pub mod media_query;
 pub mod media_query_transform;
 pub mod messages;
+pub mod supports_condition;
 
+pub use container_query::{ContainerQuery, ContainerQueryRule, validate_container_query};
 pub use media_query::{MediaQuery, MediaQueryRule, validate_media_query};
 pub use media_query_transform::last_media_query_wins_transform;
-pub use messages::MediaQueryErrors;
+pub use messages::{ContainerQueryErrors, MediaQueryErrors, SupportsConditionErrors};
+pub use supports_condition::{SupportsCondition, SupportsQuery, validate_supports_query};
+
+/// Whether every `(` in the input is closed and no `)` appears unopened
+pub(crate) fn has_balanced_parens(input: &str) -> bool {
+  let mut count = 0;
+
+  for ch in input.chars() {
+    match ch {
+      '(' => count += 1,
+      ')' => {
+        count -= 1;
+
+        if count < 0 {
+          return false;
+        }
+      }
+      _ => {}
+    }
+  }
+
+  count == 0
+}
@@ -0,0 +1,336 @@
+/*!
+`@supports` condition parsing and representation.
+
+Parses feature queries (declarations, `selector()`, `font-tech()` and
+`font-format()` combined with `not`, `and` and `or`) and prints them in a
+canonical form so that formatting differences do not change the output.
+*/
+
+use crate::{
+  CssParseError,
+  at_queries::{has_balanced_parens, messages::SupportsConditionErrors},
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList, stringify_tokens},
+};
+use std::fmt::{self, Display};
+
+/// All `@supports` conditions
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupportsCondition {
+  Declaration { property: String, value: String },
+  Selector(String),
+  FontTech(String),
+  FontFormat(String),
+  Not(Box<SupportsCondition>),
+  And(Vec<SupportsCondition>),
+  Or(Vec<SupportsCondition>),
+}
+
+impl SupportsCondition {
+  pub fn normalize(condition: SupportsCondition) -> SupportsCondition {
+    match condition {
+      SupportsCondition::Not(inner) => match Self::normalize(*inner) {
+        SupportsCondition::Not(double_negated) => *double_negated,
+        normalized => SupportsCondition::Not(Box::new(normalized)),
+      },
+      SupportsCondition::And(conditions) => Self::flatten(conditions, true),
+      SupportsCondition::Or(conditions) => Self::flatten(conditions, false),
+      _ => condition,
+    }
+  }
+
+  fn flatten(conditions: Vec<SupportsCondition>, is_and: bool) -> SupportsCondition {
+    let mut flattened: Vec<SupportsCondition> = Vec::with_capacity(conditions.len());
+
+    for condition in conditions {
+      let inner = match Self::normalize(condition) {
+        SupportsCondition::And(inner) if is_and => inner,
+        SupportsCondition::Or(inner) if !is_and => inner,
+        normalized => vec![normalized],
+      };
+
+      for condition in inner {
+        if !flattened.contains(&condition) {
+          flattened.push(condition);
+        }
+      }
+    }
+
+    if flattened.len() == 1 {
+      flattened.remove(0)
+    } else if is_and {
+      SupportsCondition::And(flattened)
+    } else {
+      SupportsCondition::Or(flattened)
+    }
+  }
+
+  /// Formats the condition so it can be used where `<supports-in-parens>`
+  /// is expected.
+  fn to_in_parens(&self) -> String {
+    match self {
+      SupportsCondition::Not(_) | SupportsCondition::And(_) | SupportsCondition::Or(_) => {
+        format!("({})", self)
+      }
+      _ => self.to_string(),
+    }
+  }
+
+  fn join(conditions: &[SupportsCondition], separator: &str) -> String {
+    conditions
+      .iter()
+      .map(|condition| condition.to_in_parens())
+      .collect::<Vec<_>>()
+      .join(separator)
+  }
+}
+
+impl Display for SupportsCondition {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SupportsCondition::Declaration { property, value } => write!(f, "({}: {})", property, value),
+      SupportsCondition::Selector(selector) => write!(f, "selector({})", selector),
+      SupportsCondition::FontTech(tech) => write!(f, "font-tech({})", tech),
+      SupportsCondition::FontFormat(format) => write!(f, "font-format({})", format),
+      SupportsCondition::Not(inner) => write!(f, "not {}", inner.to_in_parens()),
+      SupportsCondition::And(conditions) => write!(f, "{}", Self::join(conditions, " and ")),
+      SupportsCondition::Or(conditions) => write!(f, "{}", Self::join(conditions, " or ")),
+    }
+  }
+}
+
+/// A complete `@supports <condition>` at-rule prelude
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupportsQuery {
+  pub condition: SupportsCondition,
+}
+
+impl SupportsQuery {
+  pub fn new(condition: SupportsCondition) -> Self {
+    Self {
+      condition: SupportsCondition::normalize(condition),
+    }
+  }
+
+  pub fn parser() -> TokenParser<SupportsQuery> {
+    TokenParser::new(
+      |tokens| {
+        match tokens.consume_next_token()? {
+          Some(SimpleToken::AtKeyword(keyword)) if keyword == "supports" => {}
+          _ => {
+            return Err(CssParseError::ParseError {
+              message: "Expected @supports at-keyword".to_string(),
+            });
+          }
+        }
+
+        skip_whitespace(tokens)?;
+
+        let condition = parse_condition(tokens)?;
+
+        skip_whitespace(tokens)?;
+
+        Ok(SupportsQuery::new(condition))
+      },
+      "supports_query_parser",
+    )
+  }
+}
+
+impl Display for SupportsQuery {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "@supports {}", self.condition)
+  }
+}
+
+/// Validate `@supports` at-rule string
+pub fn validate_supports_query(input: &str) -> Result<SupportsQuery, String> {
+  if !has_balanced_parens(input) {
+    return Err(SupportsConditionErrors::UNBALANCED_PARENS.to_string());
+  }
+
+  SupportsQuery::parser()
+    .parse_to_end(input)
+    .map_err(|_| SupportsConditionErrors::SYNTAX_ERROR.to_string())
+}
+
+fn skip_whitespace(tokens: &mut TokenList) -> Result<(), CssParseError> {
+  while let Some(SimpleToken::Whitespace) = tokens.peek()? {
+    tokens.consume_next_token()?;
+  }
+
+  Ok(())
+}
+
+/// `not <supports-in-parens>` | `<supports-in-parens> [ and <supports-in-parens> ]*`
+/// | `<supports-in-parens> [ or <supports-in-parens> ]*`
+fn parse_condition(tokens: &mut TokenList) -> Result<SupportsCondition, CssParseError> {
+  if let Some(SimpleToken::Ident(keyword)) = tokens.peek()?
+    && keyword == "not"
+  {
+    tokens.consume_next_token()?;
+
+    if !matches!(tokens.peek()?, Some(SimpleToken::Whitespace)) {
+      return Err(CssParseError::ParseError {
+        message: "Expected whitespace after 'not'".to_string(),
+      });
+    }
+
+    skip_whitespace(tokens)?;
+
+    return Ok(SupportsCondition::Not(Box::new(parse_in_parens(tokens)?)));
+  }
+
+  let mut conditions = vec![parse_in_parens(tokens)?];
+  let mut combinator: Option<String> = None;
+
+  loop {
+    let checkpoint = tokens.save_position();
+
+    skip_whitespace(tokens)?;
+
+    let keyword = match tokens.peek()? {
+      Some(SimpleToken::Ident(keyword)) if keyword == "and" || keyword == "or" => keyword,
+      _ => {
+        tokens.restore_position(checkpoint)?;
+        break;
+      }
+    };
+
+    // Mixing `and` and `or` on the same level requires parentheses
+    if combinator
+      .as_ref()
+      .is_some_and(|current| *current != keyword)
+    {
+      return Err(CssParseError::ParseError {
+        message: "Cannot mix 'and' and 'or' without parentheses".to_string(),
+      });
+    }
+
+    tokens.consume_next_token()?;
+    skip_whitespace(tokens)?;
+
+    conditions.push(parse_in_parens(tokens)?);
+    combinator = Some(keyword);
+  }
+
+  Ok(match combinator.as_deref() {
+    Some("and") => SupportsCondition::And(conditions),
+    Some("or") => SupportsCondition::Or(conditions),
+    _ => conditions.remove(0),
+  })
+}
+
+/// `( <supports-condition> )` | `( <declaration> )` | `selector( ... )`
+/// | `font-tech( ... )` | `font-format( ... )`
+fn parse_in_parens(tokens: &mut TokenList) -> Result<SupportsCondition, CssParseError> {
+  match tokens.consume_next_token()? {
+    Some(SimpleToken::Function(name)) => {
+      let argument = stringify_tokens(&tokens.consume_enclosed()?);
+
+      if argument.is_empty() {
+        return Err(CssParseError::ParseError {
+          message: format!("Expected argument in {}()", name),
+        });
+      }
+
+      match name.as_str() {
+        "selector" => Ok(SupportsCondition::Selector(argument)),
+        "font-tech" if is_single_ident(&argument) => Ok(SupportsCondition::FontTech(argument)),
+        "font-format" if is_single_ident(&argument) => Ok(SupportsCondition::FontFormat(argument)),
+        _ => Err(CssParseError::ParseError {
+          message: format!("Unsupported function '{}({})' in @supports", name, argument),
+        }),
+      }
+    }
+    Some(SimpleToken::LeftParen) => {
+      let checkpoint = tokens.save_position();
+
+      if let Ok(declaration) = parse_declaration(tokens) {
+        return Ok(declaration);
+      }
+
+      tokens.restore_position(checkpoint)?;
+      skip_whitespace(tokens)?;
+
+      let condition = parse_condition(tokens)?;
+
+      skip_whitespace(tokens)?;
+
+      match tokens.consume_next_token()? {
+        Some(SimpleToken::RightParen) => Ok(condition),
+        token => Err(CssParseError::ParseError {
+          message: format!("Expected ')', got {:?}", token),
+        }),
+      }
+    }
+    token => Err(CssParseError::ParseError {
+      message: format!(
+        "Expected @supports condition in parentheses, got {:?}",
+        token
+      ),
+    }),
+  }
+}
+
+/// `<property>: <value>)`; the opening parenthesis has already been consumed.
+fn parse_declaration(tokens: &mut TokenList) -> Result<SupportsCondition, CssParseError> {
+  skip_whitespace(tokens)?;
+
+  let property = match tokens.consume_next_token()? {
+    Some(SimpleToken::Ident(property)) => property,
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected property name, got {:?}", token),
+      });
+    }
+  };
+
+  skip_whitespace(tokens)?;
+
+  if !matches!(tokens.consume_next_token()?, Some(SimpleToken::Colon)) {
+    return Err(CssParseError::ParseError {
+      message: format!("Expected ':' after '{}'", property),
+    });
+  }
+
+  let value = stringify_tokens(&tokens.consume_enclosed()?);
+
+  if value.is_empty() {
+    return Err(CssParseError::ParseError {
+      message: format!("Expected value for '{}'", property),
+    });
+  }
+
+  Ok(SupportsCondition::Declaration { property, value })
+}
+
+fn is_single_ident(argument: &str) -> bool {
+  !argument.is_empty()
+    && argument
+      .chars()
+      .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_supports_query_display() {
+    let query = SupportsQuery::parser()
+      .parse_to_end("@supports (display:grid)")
+      .unwrap();
+
+    assert_eq!(query.to_string(), "@supports (display: grid)");
+  }
+
+  #[test]
+  fn test_double_negation_is_removed() {
+    let condition = SupportsCondition::normalize(SupportsCondition::Not(Box::new(
+      SupportsCondition::Not(Box::new(SupportsCondition::Selector("a > b".to_string()))),
+    )));
+
+    assert_eq!(condition.to_string(), "selector(a > b)");
+  }
+}
@@ -194,10 +194,7 @@ impl CalcValue {
     }
 
     // Parse first value or group
-    let first_value = match Self::try_parse_parenthesized_group(tokens) {
-      Ok(grouped) => CalcValue::Group(grouped),
-      Err(_) => Self::parse_calc_value(tokens)?,
-    };
+    let first_value = Self::parse_calc_operand(tokens)?;
 
     // Collect values and operators
     let mut values_and_operators = vec![CalcValueOrOperator::Value(first_value)];
@@ -226,10 +223,7 @@ impl CalcValue {
           }
 
           // Parse next value or group
-          let next_value = match Self::try_parse_parenthesized_group(tokens) {
-            Ok(grouped) => CalcValue::Group(grouped),
-            Err(_) => Self::parse_calc_value(tokens)?,
-          };
+          let next_value = Self::parse_calc_operand(tokens)?;
           values_and_operators.push(CalcValueOrOperator::Value(next_value));
         }
         Err(_) => {
@@ -257,101 +251,107 @@ impl CalcValue {
     }
   }
 
+  /// Parse an operand: a parenthesized group, a nested calc() or a basic value
+  fn parse_calc_operand(tokens: &mut TokenList) -> Result<CalcValue, CssParseError> {
+    let checkpoint = tokens.current_index;
+
+    if let Ok(grouped) = Self::try_parse_parenthesized_group(tokens) {
+      return Ok(CalcValue::Group(grouped));
+    }
+    tokens.set_current_index(checkpoint);
+
+    if let Ok(Some(SimpleToken::Function(fn_name))) = tokens.peek()
+      && fn_name.eq_ignore_ascii_case("calc")
+    {
+      // A nested calc() behaves exactly like a parenthesized group
+      let calc = (Calc::parser().run)(tokens)?;
+      return Ok(CalcValue::Group(Group::new(calc.value)));
+    }
+
+    Self::parse_calc_value(tokens)
+  }
+
   /// Apply operator precedence: multiplication/division first, then addition/subtraction
   fn split_by_multiplication_or_division(
     values_and_operators: Vec<CalcValueOrOperator>,
   ) -> Result<CalcValue, CssParseError> {
-    if values_and_operators.len() == 1 {
-      match &values_and_operators[0] {
-        CalcValueOrOperator::Value(value) => return Ok(value.clone()),
-        CalcValueOrOperator::Operator(_) => {
-          return Err(CssParseError::ParseError {
-            message: "Invalid operator".to_string(),
-          });
+    // Fold every run of * and / left to right, so `a / b * c` is `(a / b) * c`
+    let mut terms: Vec<CalcValueOrOperator> = vec![];
+    let mut pending_operator: Option<String> = None;
+
+    for item in values_and_operators {
+      match item {
+        CalcValueOrOperator::Operator(operator) if operator == "*" || operator == "/" => {
+          pending_operator = Some(operator);
         }
-      }
-    }
+        CalcValueOrOperator::Value(right) if pending_operator.is_some() => {
+          let Some(CalcValueOrOperator::Value(left)) = terms.pop() else {
+            return Err(CssParseError::ParseError {
+              message: "Invalid operator".to_string(),
+            });
+          };
 
-    // Find first * or / operator
-    let first_operator = values_and_operators
-      .iter()
-      .position(|item| matches!(item, CalcValueOrOperator::Operator(op) if op == "*" || op == "/"));
+          let product = match pending_operator.take().as_deref() {
+            Some("*") => CalcValue::Multiplication(Multiplication::new(left, right)),
+            _ => CalcValue::Division(Division::new(left, right)),
+          };
 
-    match first_operator {
-      None => {
-        // No * or / found, handle + and -
-        Self::compose_add_and_subtraction(values_and_operators)
-      }
-      Some(op_index) => {
-        let left_slice = values_and_operators[..op_index].to_vec();
-        let right_slice = values_and_operators[op_index + 1..].to_vec();
-
-        if let CalcValueOrOperator::Operator(operator) = &values_and_operators[op_index] {
-          let left = Self::compose_add_and_subtraction(left_slice)?;
-          let right = Self::split_by_multiplication_or_division(right_slice)?;
-
-          match operator.as_str() {
-            "*" => Ok(CalcValue::Multiplication(Multiplication::new(left, right))),
-            "/" => Ok(CalcValue::Division(Division::new(left, right))),
-            _ => Err(CssParseError::ParseError {
-              message: "Invalid operator".to_string(),
-            }),
-          }
-        } else {
-          Err(CssParseError::ParseError {
-            message: "Expected operator".to_string(),
-          })
+          terms.push(CalcValueOrOperator::Value(product));
         }
+        item => terms.push(item),
       }
     }
+
+    if pending_operator.is_some() {
+      return Err(CssParseError::ParseError {
+        message: "Expected a value after operator".to_string(),
+      });
+    }
+
+    Self::compose_add_and_subtraction(terms)
   }
 
-  /// Handle addition and subtraction operations
+  /// Handle addition and subtraction operations, left to right
   fn compose_add_and_subtraction(
     values_and_operators: Vec<CalcValueOrOperator>,
   ) -> Result<CalcValue, CssParseError> {
-    if values_and_operators.len() == 1 {
-      match &values_and_operators[0] {
-        CalcValueOrOperator::Value(value) => return Ok(value.clone()),
-        CalcValueOrOperator::Operator(op) => {
+    let mut items = values_and_operators.into_iter();
+
+    let mut result = match items.next() {
+      Some(CalcValueOrOperator::Value(value)) => value,
+      Some(CalcValueOrOperator::Operator(op)) => {
+        return Err(CssParseError::ParseError {
+          message: format!("Invalid operator: {}", op),
+        });
+      }
+      None => {
+        return Err(CssParseError::ParseError {
+          message: "No valid operator found".to_string(),
+        });
+      }
+    };
+
+    while let Some(item) = items.next() {
+      let (CalcValueOrOperator::Operator(operator), Some(CalcValueOrOperator::Value(right))) =
+        (item, items.next())
+      else {
+        return Err(CssParseError::ParseError {
+          message: "Expected operator".to_string(),
+        });
+      };
+
+      result = match operator.as_str() {
+        "+" => CalcValue::Addition(Addition::new(result, right)),
+        "-" => CalcValue::Subtraction(Subtraction::new(result, right)),
+        _ => {
           return Err(CssParseError::ParseError {
-            message: format!("Invalid operator: {}", op),
+            message: "Invalid operator".to_string(),
           });
         }
-      }
+      };
     }
 
-    // Find first + or - operator
-    let first_operator = values_and_operators
-      .iter()
-      .position(|item| matches!(item, CalcValueOrOperator::Operator(op) if op == "+" || op == "-"));
-
-    match first_operator {
-      None => Err(CssParseError::ParseError {
-        message: "No valid operator found".to_string(),
-      }),
-      Some(op_index) => {
-        let left_slice = values_and_operators[..op_index].to_vec();
-        let right_slice = values_and_operators[op_index + 1..].to_vec();
-
-        if let CalcValueOrOperator::Operator(operator) = &values_and_operators[op_index] {
-          let left = Self::compose_add_and_subtraction(left_slice)?;
-          let right = Self::compose_add_and_subtraction(right_slice)?;
-
-          match operator.as_str() {
-            "+" => Ok(CalcValue::Addition(Addition::new(left, right))),
-            "-" => Ok(CalcValue::Subtraction(Subtraction::new(left, right))),
-            _ => Err(CssParseError::ParseError {
-              message: "Invalid operator".to_string(),
-            }),
-          }
-        } else {
-          Err(CssParseError::ParseError {
-            message: "Expected operator".to_string(),
-          })
-        }
-      }
-    }
+    Ok(result)
   }
 
   /// Try to parse a parenthesized group
@@ -439,6 +439,202 @@ impl Display for CalcValue {
   }
 }
 
+impl CalcValue {
+  /// Folds arithmetic between compatible operands: numbers, percentages and
+  /// dimensions sharing a unit are added together, and any of them can be
+  /// multiplied or divided by a plain number. Mixed units and constants are
+  /// kept as they are, and nested groups are unwrapped when they no longer
+  /// need parentheses.
+  pub fn simplify(&self) -> CalcValue {
+    match self {
+      CalcValue::Addition(_) | CalcValue::Subtraction(_) => simplify_sum(self),
+      CalcValue::Multiplication(op) => {
+        let left = op.left.simplify();
+        let right = op.right.simplify();
+
+        let folded = match (&left, &right) {
+          (_, CalcValue::Number(factor)) => fold_numeric(&left, |value| value * to_f64(*factor)),
+          (CalcValue::Number(factor), _) => fold_numeric(&right, |value| value * to_f64(*factor)),
+          _ => None,
+        };
+
+        folded.unwrap_or_else(|| {
+          CalcValue::Multiplication(Multiplication::new(group_sum(left), group_sum(right)))
+        })
+      }
+      CalcValue::Division(op) => {
+        let left = op.left.simplify();
+        let right = op.right.simplify();
+
+        let folded = match &right {
+          CalcValue::Number(divisor) if *divisor != 0.0 => {
+            fold_numeric(&left, |value| value / to_f64(*divisor))
+          }
+          _ => None,
+        };
+
+        folded.unwrap_or_else(|| {
+          CalcValue::Division(Division::new(group_sum(left), group_product(right)))
+        })
+      }
+      CalcValue::Group(group) => group.expr.simplify(),
+      _ => self.clone(),
+    }
+  }
+}
+
+/// Key under which two operands can be added together, `None` for operands
+/// that can't be folded.
+fn numeric_kind(value: &CalcValue) -> Option<String> {
+  match value {
+    CalcValue::Number(_) => Some(String::new()),
+    CalcValue::Dimension(dimension) => Some(dimension.unit.to_lowercase()),
+    CalcValue::Percentage(_) => Some("%".to_string()),
+    _ => None,
+  }
+}
+
+fn numeric_value(value: &CalcValue) -> Option<f32> {
+  match value {
+    CalcValue::Number(number) => Some(*number),
+    CalcValue::Dimension(dimension) => Some(dimension.value),
+    CalcValue::Percentage(percentage) => Some(percentage.value),
+    _ => None,
+  }
+}
+
+fn map_numeric(value: &CalcValue, f: impl Fn(f32) -> f32) -> Option<CalcValue> {
+  // Adding zero turns a negative zero into a positive one
+  let map = |value: f32| f(value) + 0.0;
+
+  match value {
+    CalcValue::Number(number) => Some(CalcValue::Number(map(*number))),
+    CalcValue::Dimension(dimension) => Some(CalcValue::Dimension(CalcDimension::new(
+      map(dimension.value),
+      dimension.unit.clone(),
+    ))),
+    CalcValue::Percentage(percentage) => Some(CalcValue::Percentage(Percentage::new(map(
+      percentage.value,
+    )))),
+    _ => None,
+  }
+}
+
+/// Applies an arithmetic operation to a numeric operand when its result can
+/// be written without losing precision, so `100% / 4` becomes `25%` while
+/// `100% / 3` is kept as it is.
+fn fold_numeric(value: &CalcValue, f: impl Fn(f64) -> f64) -> Option<CalcValue> {
+  let result = f(to_f64(numeric_value(value)?));
+  let rounded = (result * 10000.0).round() / 10000.0;
+
+  // The tolerance only absorbs binary floating point noise, e.g. `0.1 + 0.2`
+  if !rounded.is_finite() || (rounded - result).abs() > 1e-9 * result.abs().max(1.0) {
+    return None;
+  }
+
+  map_numeric(value, |_| rounded as f32)
+}
+
+/// Widens a value through its shortest decimal form, so that `0.1` stays
+/// `0.1` instead of picking up the error of its `f32` representation
+fn to_f64(value: f32) -> f64 {
+  value.to_string().parse().unwrap_or(value as f64)
+}
+
+fn group_sum(value: CalcValue) -> CalcValue {
+  match value {
+    CalcValue::Addition(_) | CalcValue::Subtraction(_) => CalcValue::Group(Group::new(value)),
+    _ => value,
+  }
+}
+
+fn group_product(value: CalcValue) -> CalcValue {
+  match value {
+    CalcValue::Multiplication(_) | CalcValue::Division(_) => CalcValue::Group(Group::new(value)),
+    _ => group_sum(value),
+  }
+}
+
+/// Flattens a sum into signed terms, looking through parentheses
+fn collect_terms(value: &CalcValue, negate: bool, terms: &mut Vec<(bool, CalcValue)>) {
+  match value {
+    CalcValue::Addition(op) => {
+      collect_terms(&op.left, negate, terms);
+      collect_terms(&op.right, negate, terms);
+    }
+    CalcValue::Subtraction(op) => {
+      collect_terms(&op.left, negate, terms);
+      collect_terms(&op.right, !negate, terms);
+    }
+    CalcValue::Group(group) => collect_terms(&group.expr, negate, terms),
+    _ => terms.push((negate, value.simplify())),
+  }
+}
+
+fn simplify_sum(value: &CalcValue) -> CalcValue {
+  let mut terms = vec![];
+  collect_terms(value, false, &mut terms);
+
+  // Numeric terms are stored with their sign applied, everything else keeps
+  // its sign next to it.
+  let mut combined: Vec<(bool, CalcValue)> = vec![];
+
+  for (negate, term) in terms {
+    let Some(kind) = numeric_kind(&term) else {
+      combined.push((negate, term));
+      continue;
+    };
+
+    let term = if negate {
+      map_numeric(&term, |value| -value).unwrap_or(term)
+    } else {
+      term
+    };
+
+    let existing = combined
+      .iter_mut()
+      .find(|(_, existing)| numeric_kind(existing).as_ref() == Some(&kind));
+
+    match existing {
+      Some((_, existing)) => {
+        let addend = to_f64(numeric_value(&term).unwrap_or_default());
+
+        match fold_numeric(existing, |value| value + addend) {
+          Some(sum) => *existing = sum,
+          None => combined.push((false, term)),
+        }
+      }
+      None => combined.push((false, term)),
+    }
+  }
+
+  // The leading term can't carry a minus sign unless it's numeric
+  if let Some(position) = combined.iter().position(|(negate, _)| !negate) {
+    let first = combined.remove(position);
+    combined.insert(0, first);
+  } else {
+    combined.insert(0, (false, CalcValue::Number(0.0)));
+  }
+
+  let mut combined = combined.into_iter();
+  let (_, mut result) = combined.next().unwrap_or((false, CalcValue::Number(0.0)));
+
+  for (negate, term) in combined {
+    let is_negative = numeric_value(&term).is_some_and(|value| value < 0.0);
+
+    result = if negate {
+      CalcValue::Subtraction(Subtraction::new(result, group_sum(term)))
+    } else if is_negative {
+      let term = map_numeric(&term, |value| -value).unwrap_or(term);
+      CalcValue::Subtraction(Subtraction::new(result, term))
+    } else {
+      CalcValue::Addition(Addition::new(result, group_sum(term)))
+    };
+  }
+
+  result
+}
+
 /// Main Calc expression container
 #[derive(Debug, Clone, PartialEq)]
 pub struct Calc {
@@ -451,6 +647,12 @@ impl Calc {
     Calc { value }
   }
 
+  /// Returns the expression with compatible operands folded together, see
+  /// [`CalcValue::simplify`].
+  pub fn simplify(&self) -> Calc {
+    Calc::new(self.value.simplify())
+  }
+
   /// Parse method for compatibility with existing tests
   pub fn parse() -> TokenParser<Calc> {
     Self::parser()
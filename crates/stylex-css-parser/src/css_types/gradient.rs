@@ -0,0 +1,734 @@
+/*!
+CSS gradient parsing.
+
+Handles `linear-gradient()`, `radial-gradient()` and `conic-gradient()`, their
+`repeating-*` variants, color interpolation methods and color stop lists.
+*/
+
+use crate::{
+  CssParseError,
+  css_types::{
+    Angle, AnglePercentage, Color, Length, LengthPercentage,
+    length_percentage::length_percentage_parser,
+    position::{HorizontalKeyword, Position, VerticalKeyword},
+  },
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+/// Color spaces allowed after `in` that interpolate on rectangular coordinates
+pub const RECTANGULAR_COLOR_SPACES: &[&str] = &[
+  "srgb",
+  "srgb-linear",
+  "display-p3",
+  "a98-rgb",
+  "prophoto-rgb",
+  "rec2020",
+  "lab",
+  "oklab",
+  "xyz",
+  "xyz-d50",
+  "xyz-d65",
+];
+
+/// Color spaces allowed after `in` that interpolate on a hue angle
+pub const POLAR_COLOR_SPACES: &[&str] = &["hsl", "hwb", "lch", "oklch"];
+
+/// Hue interpolation methods of polar color spaces
+pub const HUE_INTERPOLATION_METHODS: &[&str] = &["shorter", "longer", "increasing", "decreasing"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient {
+  Linear(LinearGradient),
+  Radial(RadialGradient),
+  Conic(ConicGradient),
+}
+
+/// `in <color-space> [<hue-interpolation-method> hue]?`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorInterpolationMethod {
+  pub color_space: String,
+  pub hue_interpolation: Option<String>,
+}
+
+/// Item of a color stop list, positioned by lengths for linear and radial
+/// gradients and by angles for conic gradients.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorStopListItem<P> {
+  ColorStop { color: Color, positions: Vec<P> },
+  Hint(P),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinearGradientDirection {
+  Angle(Angle),
+  ToSide(Option<HorizontalKeyword>, Option<VerticalKeyword>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient {
+  pub repeating: bool,
+  pub direction: Option<LinearGradientDirection>,
+  pub interpolation: Option<ColorInterpolationMethod>,
+  pub stops: Vec<ColorStopListItem<LengthPercentage>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadialGradientShape {
+  Circle,
+  Ellipse,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadialGradientExtent {
+  ClosestSide,
+  ClosestCorner,
+  FarthestSide,
+  FarthestCorner,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadialGradientSize {
+  Extent(RadialGradientExtent),
+  Circle(Length),
+  Ellipse(LengthPercentage, LengthPercentage),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradient {
+  pub repeating: bool,
+  pub shape: Option<RadialGradientShape>,
+  pub size: Option<RadialGradientSize>,
+  pub position: Option<Position>,
+  pub interpolation: Option<ColorInterpolationMethod>,
+  pub stops: Vec<ColorStopListItem<LengthPercentage>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+  pub repeating: bool,
+  pub from: Option<Angle>,
+  pub position: Option<Position>,
+  pub interpolation: Option<ColorInterpolationMethod>,
+  pub stops: Vec<ColorStopListItem<AnglePercentage>>,
+}
+
+impl Gradient {
+  pub fn parser() -> TokenParser<Gradient> {
+    TokenParser::new(
+      |tokens| {
+        let name = match tokens.consume_next_token()? {
+          Some(SimpleToken::Function(name)) => name,
+          token => {
+            return Err(CssParseError::ParseError {
+              message: format!("Expected gradient function, got {:?}", token),
+            });
+          }
+        };
+
+        let repeating = name.starts_with("repeating-");
+        let kind = name.trim_start_matches("repeating-");
+
+        skip_whitespace(tokens)?;
+
+        let gradient = match kind {
+          "linear-gradient" => Gradient::Linear(parse_linear_gradient(tokens, repeating)?),
+          "radial-gradient" => Gradient::Radial(parse_radial_gradient(tokens, repeating)?),
+          "conic-gradient" => Gradient::Conic(parse_conic_gradient(tokens, repeating)?),
+          _ => {
+            return Err(CssParseError::ParseError {
+              message: format!("Unknown gradient function {}()", name),
+            });
+          }
+        };
+
+        skip_whitespace(tokens)?;
+        expect_token(tokens, SimpleToken::RightParen)?;
+
+        Ok(gradient)
+      },
+      "gradient",
+    )
+  }
+
+  pub fn is_repeating(&self) -> bool {
+    match self {
+      Gradient::Linear(gradient) => gradient.repeating,
+      Gradient::Radial(gradient) => gradient.repeating,
+      Gradient::Conic(gradient) => gradient.repeating,
+    }
+  }
+}
+
+fn parse_linear_gradient(
+  tokens: &mut TokenList,
+  repeating: bool,
+) -> Result<LinearGradient, CssParseError> {
+  let mut direction = None;
+  let mut interpolation = None;
+
+  loop {
+    if interpolation.is_none()
+      && let Some(method) = parse_interpolation_method(tokens)?
+    {
+      interpolation = Some(method);
+    } else if direction.is_none()
+      && let Some(parsed) = parse_linear_direction(tokens)?
+    {
+      direction = Some(parsed);
+    } else {
+      break;
+    }
+
+    skip_whitespace(tokens)?;
+  }
+
+  if direction.is_some() || interpolation.is_some() {
+    expect_token(tokens, SimpleToken::Comma)?;
+  }
+
+  Ok(LinearGradient {
+    repeating,
+    direction,
+    interpolation,
+    stops: parse_color_stop_list(tokens, &length_percentage_parser())?,
+  })
+}
+
+fn parse_linear_direction(
+  tokens: &mut TokenList,
+) -> Result<Option<LinearGradientDirection>, CssParseError> {
+  if let Some(angle) = try_parse(tokens, &Angle::parser()) {
+    return Ok(Some(LinearGradientDirection::Angle(angle)));
+  }
+
+  if !consume_ident(tokens, "to")? {
+    return Ok(None);
+  }
+
+  let mut horizontal = None;
+  let mut vertical = None;
+
+  for _ in 0..2 {
+    skip_whitespace(tokens)?;
+
+    match tokens.peek()? {
+      Some(SimpleToken::Ident(side)) if horizontal.is_none() && side == "left" => {
+        horizontal = Some(HorizontalKeyword::Left);
+      }
+      Some(SimpleToken::Ident(side)) if horizontal.is_none() && side == "right" => {
+        horizontal = Some(HorizontalKeyword::Right);
+      }
+      Some(SimpleToken::Ident(side)) if vertical.is_none() && side == "top" => {
+        vertical = Some(VerticalKeyword::Top);
+      }
+      Some(SimpleToken::Ident(side)) if vertical.is_none() && side == "bottom" => {
+        vertical = Some(VerticalKeyword::Bottom);
+      }
+      _ => break,
+    }
+
+    tokens.consume_next_token()?;
+  }
+
+  if horizontal.is_none() && vertical.is_none() {
+    return Err(CssParseError::ParseError {
+      message: "Expected a side or corner after `to`".to_string(),
+    });
+  }
+
+  Ok(Some(LinearGradientDirection::ToSide(horizontal, vertical)))
+}
+
+fn parse_radial_gradient(
+  tokens: &mut TokenList,
+  repeating: bool,
+) -> Result<RadialGradient, CssParseError> {
+  let mut shape = None;
+  let mut size = None;
+  let mut interpolation = None;
+
+  loop {
+    if interpolation.is_none()
+      && let Some(method) = parse_interpolation_method(tokens)?
+    {
+      interpolation = Some(method);
+    } else if shape.is_none()
+      && let Some(parsed) = parse_radial_shape(tokens)?
+    {
+      shape = Some(parsed);
+    } else if size.is_none()
+      && let Some(parsed) = parse_radial_size(tokens)?
+    {
+      size = Some(parsed);
+    } else {
+      break;
+    }
+
+    skip_whitespace(tokens)?;
+  }
+
+  let position = parse_at_position(tokens)?;
+
+  skip_whitespace(tokens)?;
+
+  if interpolation.is_none()
+    && let Some(method) = parse_interpolation_method(tokens)?
+  {
+    interpolation = Some(method);
+    skip_whitespace(tokens)?;
+  }
+
+  match (&shape, &size) {
+    (Some(RadialGradientShape::Circle), Some(RadialGradientSize::Ellipse(..)))
+    | (Some(RadialGradientShape::Ellipse), Some(RadialGradientSize::Circle(_))) => {
+      return Err(CssParseError::ParseError {
+        message: "Radial gradient size does not match its shape".to_string(),
+      });
+    }
+    _ => {}
+  }
+
+  if shape.is_some() || size.is_some() || position.is_some() || interpolation.is_some() {
+    expect_token(tokens, SimpleToken::Comma)?;
+  }
+
+  Ok(RadialGradient {
+    repeating,
+    shape,
+    size,
+    position,
+    interpolation,
+    stops: parse_color_stop_list(tokens, &length_percentage_parser())?,
+  })
+}
+
+fn parse_radial_shape(
+  tokens: &mut TokenList,
+) -> Result<Option<RadialGradientShape>, CssParseError> {
+  if consume_ident(tokens, "circle")? {
+    return Ok(Some(RadialGradientShape::Circle));
+  }
+
+  if consume_ident(tokens, "ellipse")? {
+    return Ok(Some(RadialGradientShape::Ellipse));
+  }
+
+  Ok(None)
+}
+
+fn parse_radial_size(tokens: &mut TokenList) -> Result<Option<RadialGradientSize>, CssParseError> {
+  for (keyword, extent) in [
+    ("closest-side", RadialGradientExtent::ClosestSide),
+    ("closest-corner", RadialGradientExtent::ClosestCorner),
+    ("farthest-side", RadialGradientExtent::FarthestSide),
+    ("farthest-corner", RadialGradientExtent::FarthestCorner),
+  ] {
+    if consume_ident(tokens, keyword)? {
+      return Ok(Some(RadialGradientSize::Extent(extent)));
+    }
+  }
+
+  let Some(first) = try_parse(tokens, &length_percentage_parser()) else {
+    return Ok(None);
+  };
+
+  let checkpoint = tokens.current_index;
+  skip_whitespace(tokens)?;
+
+  if let Some(second) = try_parse(tokens, &length_percentage_parser()) {
+    return Ok(Some(RadialGradientSize::Ellipse(first, second)));
+  }
+
+  tokens.set_current_index(checkpoint);
+
+  match first {
+    LengthPercentage::Length(length) => Ok(Some(RadialGradientSize::Circle(length))),
+    _ => Err(CssParseError::ParseError {
+      message: "A circle radius must be a length".to_string(),
+    }),
+  }
+}
+
+fn parse_conic_gradient(
+  tokens: &mut TokenList,
+  repeating: bool,
+) -> Result<ConicGradient, CssParseError> {
+  let mut from = None;
+  let mut interpolation = None;
+
+  loop {
+    if interpolation.is_none()
+      && let Some(method) = parse_interpolation_method(tokens)?
+    {
+      interpolation = Some(method);
+    } else if from.is_none() && consume_ident(tokens, "from")? {
+      skip_whitespace(tokens)?;
+      from = Some((Angle::parser().run)(tokens)?);
+    } else {
+      break;
+    }
+
+    skip_whitespace(tokens)?;
+  }
+
+  let position = parse_at_position(tokens)?;
+
+  skip_whitespace(tokens)?;
+
+  if interpolation.is_none()
+    && let Some(method) = parse_interpolation_method(tokens)?
+  {
+    interpolation = Some(method);
+    skip_whitespace(tokens)?;
+  }
+
+  if from.is_some() || position.is_some() || interpolation.is_some() {
+    expect_token(tokens, SimpleToken::Comma)?;
+  }
+
+  Ok(ConicGradient {
+    repeating,
+    from,
+    position,
+    interpolation,
+    stops: parse_color_stop_list(tokens, &AnglePercentage::parser())?,
+  })
+}
+
+fn parse_at_position(tokens: &mut TokenList) -> Result<Option<Position>, CssParseError> {
+  if !consume_ident(tokens, "at")? {
+    return Ok(None);
+  }
+
+  skip_whitespace(tokens)?;
+
+  (Position::parser().run)(tokens).map(Some)
+}
+
+fn parse_interpolation_method(
+  tokens: &mut TokenList,
+) -> Result<Option<ColorInterpolationMethod>, CssParseError> {
+  if !consume_ident(tokens, "in")? {
+    return Ok(None);
+  }
+
+  skip_whitespace(tokens)?;
+
+  let color_space = match tokens.consume_next_token()? {
+    Some(SimpleToken::Ident(space))
+      if RECTANGULAR_COLOR_SPACES.contains(&space.as_str())
+        || POLAR_COLOR_SPACES.contains(&space.as_str()) =>
+    {
+      space
+    }
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected a color space, got {:?}", token),
+      });
+    }
+  };
+
+  let mut hue_interpolation = None;
+
+  if POLAR_COLOR_SPACES.contains(&color_space.as_str()) {
+    let checkpoint = tokens.current_index;
+    skip_whitespace(tokens)?;
+
+    match tokens.peek()? {
+      Some(SimpleToken::Ident(method)) if HUE_INTERPOLATION_METHODS.contains(&method.as_str()) => {
+        tokens.consume_next_token()?;
+        skip_whitespace(tokens)?;
+
+        if !consume_ident(tokens, "hue")? {
+          return Err(CssParseError::ParseError {
+            message: format!("Expected `hue` after `{}`", method),
+          });
+        }
+
+        hue_interpolation = Some(method);
+      }
+      _ => tokens.set_current_index(checkpoint),
+    }
+  }
+
+  Ok(Some(ColorInterpolationMethod {
+    color_space,
+    hue_interpolation,
+  }))
+}
+
+/// `<color-stop> , [ <color-hint>? , <color-stop> ]#`
+fn parse_color_stop_list<P: Clone + fmt::Debug + 'static>(
+  tokens: &mut TokenList,
+  position_parser: &TokenParser<P>,
+) -> Result<Vec<ColorStopListItem<P>>, CssParseError> {
+  let mut items = vec![];
+
+  loop {
+    skip_whitespace(tokens)?;
+
+    let item = if let Some(color) = try_parse(tokens, &Color::parse()) {
+      let mut positions = vec![];
+
+      while positions.len() < 2 {
+        let checkpoint = tokens.current_index;
+        skip_whitespace(tokens)?;
+
+        match try_parse(tokens, position_parser) {
+          Some(position) => positions.push(position),
+          None => {
+            tokens.set_current_index(checkpoint);
+            break;
+          }
+        }
+      }
+
+      ColorStopListItem::ColorStop { color, positions }
+    } else {
+      let hint = (position_parser.run)(tokens)?;
+
+      if !matches!(items.last(), Some(ColorStopListItem::ColorStop { .. })) {
+        return Err(CssParseError::ParseError {
+          message: "A color hint must follow a color stop".to_string(),
+        });
+      }
+
+      ColorStopListItem::Hint(hint)
+    };
+
+    items.push(item);
+
+    skip_whitespace(tokens)?;
+
+    if tokens.peek()? != Some(SimpleToken::Comma) {
+      break;
+    }
+
+    tokens.consume_next_token()?;
+  }
+
+  let stop_count = items
+    .iter()
+    .filter(|item| matches!(item, ColorStopListItem::ColorStop { .. }))
+    .count();
+
+  if stop_count < 2 || matches!(items.last(), Some(ColorStopListItem::Hint(_))) {
+    return Err(CssParseError::ParseError {
+      message: "A gradient needs at least two color stops".to_string(),
+    });
+  }
+
+  Ok(items)
+}
+
+/// Runs a parser and rewinds the tokens when it fails
+pub(crate) fn try_parse<T: Clone + fmt::Debug + 'static>(
+  tokens: &mut TokenList,
+  parser: &TokenParser<T>,
+) -> Option<T> {
+  let checkpoint = tokens.current_index;
+
+  match (parser.run)(tokens) {
+    Ok(value) => Some(value),
+    Err(_) => {
+      tokens.set_current_index(checkpoint);
+      None
+    }
+  }
+}
+
+pub(crate) fn consume_ident(tokens: &mut TokenList, expected: &str) -> Result<bool, CssParseError> {
+  match tokens.peek()? {
+    Some(SimpleToken::Ident(ident)) if ident == expected => {
+      tokens.consume_next_token()?;
+      Ok(true)
+    }
+    _ => Ok(false),
+  }
+}
+
+pub(crate) fn skip_whitespace(tokens: &mut TokenList) -> Result<(), CssParseError> {
+  while let Some(SimpleToken::Whitespace) = tokens.peek()? {
+    tokens.consume_next_token()?;
+  }
+
+  Ok(())
+}
+
+pub(crate) fn expect_token(
+  tokens: &mut TokenList,
+  expected: SimpleToken,
+) -> Result<(), CssParseError> {
+  match tokens.consume_next_token()? {
+    Some(token) if token == expected => Ok(()),
+    token => Err(CssParseError::ParseError {
+      message: format!("Expected {:?}, got {:?}", expected, token),
+    }),
+  }
+}
+
+impl Display for ColorInterpolationMethod {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "in {}", self.color_space)?;
+
+    if let Some(hue_interpolation) = &self.hue_interpolation {
+      write!(f, " {} hue", hue_interpolation)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<P: Display> Display for ColorStopListItem<P> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ColorStopListItem::ColorStop { color, positions } => {
+        write!(f, "{}", color)?;
+
+        for position in positions {
+          write!(f, " {}", position)?;
+        }
+
+        Ok(())
+      }
+      ColorStopListItem::Hint(position) => write!(f, "{}", position),
+    }
+  }
+}
+
+impl Display for LinearGradientDirection {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LinearGradientDirection::Angle(angle) => write!(f, "{}", angle),
+      LinearGradientDirection::ToSide(horizontal, vertical) => {
+        let sides = [
+          horizontal.as_ref().map(|side| side.as_str()),
+          vertical.as_ref().map(|side| side.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        write!(f, "to {}", sides.join(" "))
+      }
+    }
+  }
+}
+
+impl Display for RadialGradientShape {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RadialGradientShape::Circle => write!(f, "circle"),
+      RadialGradientShape::Ellipse => write!(f, "ellipse"),
+    }
+  }
+}
+
+impl Display for RadialGradientExtent {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RadialGradientExtent::ClosestSide => write!(f, "closest-side"),
+      RadialGradientExtent::ClosestCorner => write!(f, "closest-corner"),
+      RadialGradientExtent::FarthestSide => write!(f, "farthest-side"),
+      RadialGradientExtent::FarthestCorner => write!(f, "farthest-corner"),
+    }
+  }
+}
+
+impl Display for RadialGradientSize {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RadialGradientSize::Extent(extent) => write!(f, "{}", extent),
+      RadialGradientSize::Circle(radius) => write!(f, "{}", radius),
+      RadialGradientSize::Ellipse(x, y) => write!(f, "{} {}", x, y),
+    }
+  }
+}
+
+/// Writes `<function>(<prelude>, <stops>)`, omitting an empty prelude
+fn write_gradient<P: Display>(
+  f: &mut fmt::Formatter<'_>,
+  name: &str,
+  repeating: bool,
+  prelude: Vec<String>,
+  stops: &[ColorStopListItem<P>],
+) -> fmt::Result {
+  let prefix = if repeating { "repeating-" } else { "" };
+  let stops = stops
+    .iter()
+    .map(|stop| stop.to_string())
+    .collect::<Vec<_>>()
+    .join(", ");
+
+  if prelude.is_empty() {
+    write!(f, "{}{}({})", prefix, name, stops)
+  } else {
+    write!(f, "{}{}({}, {})", prefix, name, prelude.join(" "), stops)
+  }
+}
+
+impl Display for LinearGradient {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let prelude = [
+      self
+        .direction
+        .as_ref()
+        .map(|direction| direction.to_string()),
+      self.interpolation.as_ref().map(|method| method.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    write_gradient(f, "linear-gradient", self.repeating, prelude, &self.stops)
+  }
+}
+
+impl Display for RadialGradient {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let prelude = [
+      self.shape.as_ref().map(|shape| shape.to_string()),
+      self.size.as_ref().map(|size| size.to_string()),
+      self
+        .position
+        .as_ref()
+        .map(|position| format!("at {}", position)),
+      self.interpolation.as_ref().map(|method| method.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    write_gradient(f, "radial-gradient", self.repeating, prelude, &self.stops)
+  }
+}
+
+impl Display for ConicGradient {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let prelude = [
+      self.from.as_ref().map(|angle| format!("from {}", angle)),
+      self
+        .position
+        .as_ref()
+        .map(|position| format!("at {}", position)),
+      self.interpolation.as_ref().map(|method| method.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    write_gradient(f, "conic-gradient", self.repeating, prelude, &self.stops)
+  }
+}
+
+impl Display for Gradient {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Gradient::Linear(gradient) => gradient.fmt(f),
+      Gradient::Radial(gradient) => gradient.fmt(f),
+      Gradient::Conic(gradient) => gradient.fmt(f),
+    }
+  }
+}
@@ -0,0 +1,214 @@
+/*!
+CSS `<image>` parsing.
+
+Handles `url()`, gradients and `image-set()`.
+*/
+
+use crate::{
+  CssParseError,
+  css_types::{
+    Resolution,
+    gradient::{Gradient, expect_token, skip_whitespace},
+  },
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Image {
+  Url(String),
+  Gradient(Box<Gradient>),
+  ImageSet(Vec<ImageSetOption>),
+}
+
+/// `[ <image> | <string> ] [ <resolution> || type(<string>) ]?`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSetOption {
+  pub image: ImageSetSource,
+  pub resolution: Option<Resolution>,
+  pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageSetSource {
+  Image(Box<Image>),
+  String(String),
+}
+
+impl Image {
+  pub fn parser() -> TokenParser<Image> {
+    TokenParser::new(parse_image, "image")
+  }
+
+  /// Parser for comma separated image layers, e.g. `background-image` values
+  pub fn list_parser() -> TokenParser<Vec<Image>> {
+    TokenParser::new(
+      |tokens| {
+        let mut images = vec![];
+
+        loop {
+          skip_whitespace(tokens)?;
+          images.push(parse_image(tokens)?);
+          skip_whitespace(tokens)?;
+
+          if tokens.peek()? != Some(SimpleToken::Comma) {
+            break;
+          }
+
+          tokens.consume_next_token()?;
+        }
+
+        Ok(images)
+      },
+      "image_list",
+    )
+  }
+}
+
+fn parse_image(tokens: &mut TokenList) -> Result<Image, CssParseError> {
+  match tokens.peek()? {
+    Some(SimpleToken::Url(url)) => {
+      tokens.consume_next_token()?;
+      Ok(Image::Url(url))
+    }
+    Some(SimpleToken::Function(name)) if name == "url" => {
+      tokens.consume_next_token()?;
+      skip_whitespace(tokens)?;
+
+      let url = match tokens.consume_next_token()? {
+        Some(SimpleToken::String(url)) => url,
+        token => {
+          return Err(CssParseError::ParseError {
+            message: format!("Expected a string in url(), got {:?}", token),
+          });
+        }
+      };
+
+      skip_whitespace(tokens)?;
+      expect_token(tokens, SimpleToken::RightParen)?;
+
+      Ok(Image::Url(url))
+    }
+    Some(SimpleToken::Function(name)) if name == "image-set" || name == "-webkit-image-set" => {
+      tokens.consume_next_token()?;
+      parse_image_set(tokens)
+    }
+    Some(SimpleToken::Function(name)) if name.ends_with("-gradient") => {
+      (Gradient::parser().run)(tokens).map(|gradient| Image::Gradient(Box::new(gradient)))
+    }
+    token => Err(CssParseError::ParseError {
+      message: format!("Expected an image, got {:?}", token),
+    }),
+  }
+}
+
+fn parse_image_set(tokens: &mut TokenList) -> Result<Image, CssParseError> {
+  let mut options = vec![];
+
+  loop {
+    skip_whitespace(tokens)?;
+
+    let image = match tokens.peek()? {
+      Some(SimpleToken::String(value)) => {
+        tokens.consume_next_token()?;
+        ImageSetSource::String(value)
+      }
+      _ => ImageSetSource::Image(Box::new(parse_image(tokens)?)),
+    };
+
+    let mut resolution = None;
+    let mut mime_type = None;
+
+    loop {
+      skip_whitespace(tokens)?;
+
+      match tokens.peek()? {
+        Some(SimpleToken::Function(name)) if name == "type" && mime_type.is_none() => {
+          tokens.consume_next_token()?;
+          skip_whitespace(tokens)?;
+
+          match tokens.consume_next_token()? {
+            Some(SimpleToken::String(value)) => mime_type = Some(value),
+            token => {
+              return Err(CssParseError::ParseError {
+                message: format!("Expected a string in type(), got {:?}", token),
+              });
+            }
+          }
+
+          skip_whitespace(tokens)?;
+          expect_token(tokens, SimpleToken::RightParen)?;
+        }
+        Some(SimpleToken::Dimension { .. }) if resolution.is_none() => {
+          resolution = Some((Resolution::parser().run)(tokens)?);
+        }
+        _ => break,
+      }
+    }
+
+    options.push(ImageSetOption {
+      image,
+      resolution,
+      mime_type,
+    });
+
+    match tokens.consume_next_token()? {
+      Some(SimpleToken::Comma) => {}
+      Some(SimpleToken::RightParen) => break,
+      token => {
+        return Err(CssParseError::ParseError {
+          message: format!("Expected a comma or closing paren, got {:?}", token),
+        });
+      }
+    }
+  }
+
+  Ok(Image::ImageSet(options))
+}
+
+impl Display for ImageSetSource {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ImageSetSource::Image(image) => write!(f, "{}", image),
+      ImageSetSource::String(value) => write!(f, "\"{}\"", value),
+    }
+  }
+}
+
+impl Display for ImageSetOption {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.image)?;
+
+    if let Some(resolution) = &self.resolution {
+      write!(f, " {}", resolution)?;
+    }
+
+    if let Some(mime_type) = &self.mime_type {
+      write!(f, " type(\"{}\")", mime_type)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Display for Image {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Image::Url(url) if url.contains(['"', '\'', '(', ')', ' ']) => {
+        write!(f, "url(\"{}\")", url.replace('"', "\\\""))
+      }
+      Image::Url(url) => write!(f, "url({})", url),
+      Image::Gradient(gradient) => write!(f, "{}", gradient),
+      Image::ImageSet(options) => {
+        let options = options
+          .iter()
+          .map(|option| option.to_string())
+          .collect::<Vec<_>>()
+          .join(", ");
+
+        write!(f, "image-set({})", options)
+      }
+    }
+  }
+}
@@ -44,6 +44,10 @@ pub mod basic_shape;
 pub mod blend_mode;
 pub mod flex;
 
+// Image types
+pub mod gradient;
+pub mod image;
+
 pub use alpha_value::AlphaValue;
 pub use angle::Angle;
 pub use angle_percentage::{AnglePercentage, angle_percentage_parser};
@@ -70,6 +74,12 @@ pub use filter_function::{
 };
 pub use flex::Flex;
 pub use frequency::Frequency;
+pub use gradient::{
+  ColorInterpolationMethod, ColorStopListItem, ConicGradient, Gradient, LinearGradient,
+  LinearGradientDirection, RadialGradient, RadialGradientExtent, RadialGradientShape,
+  RadialGradientSize,
+};
+pub use image::{Image, ImageSetOption, ImageSetSource};
 pub use length::Length;
 pub use length_percentage::{LengthPercentage, length_percentage_parser};
 pub use position::{Horizontal, HorizontalKeyword, Position, Vertical, VerticalKeyword};
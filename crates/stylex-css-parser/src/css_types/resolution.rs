@@ -1,14 +1,14 @@
 /*!
 CSS Resolution type parsing.
 
-Handles resolution values with 'dpi' (dots per inch), 'dpcm' (dots per cm), and 'dppx' (dots per px) units.
+Handles resolution values with 'dpi' (dots per inch), 'dpcm' (dots per cm), 'dppx' (dots per px) and its 'x' alias units.
 */
 
 use crate::{token_parser::TokenParser, token_types::SimpleToken};
 use std::fmt::{self, Display};
 
 /// Valid resolution units
-pub const RESOLUTION_UNITS: &[&str] = &["dpi", "dpcm", "dppx"];
+pub const RESOLUTION_UNITS: &[&str] = &["dpi", "dpcm", "dppx", "x"];
 
 /// CSS Resolution value with unit
 #[derive(Debug, Clone, PartialEq)]
@@ -110,10 +110,11 @@ mod tests {
   #[test]
   fn test_resolution_units_constant() {
     let units = Resolution::units();
-    assert_eq!(units.len(), 3);
+    assert_eq!(units.len(), 4);
     assert!(units.contains(&"dpi"));
     assert!(units.contains(&"dpcm"));
     assert!(units.contains(&"dppx"));
+    assert!(units.contains(&"x"));
   }
 
   #[test]
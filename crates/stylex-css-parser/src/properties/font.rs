@@ -0,0 +1,473 @@
+/*!
+CSS Font property parsing.
+
+Handles the `font` shorthand and `font-family` lists. Family names are printed
+unquoted whenever that's unambiguous, so `"Inter", sans-serif` and
+`Inter, sans-serif` produce the same output.
+*/
+
+use crate::{
+  CssParseError,
+  css_types::{
+    Angle, LengthPercentage,
+    gradient::{consume_ident, skip_whitespace, try_parse},
+  },
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+/// Generic family keywords, they must be quoted to be used as family names
+pub const GENERIC_FONT_FAMILIES: &[&str] = &[
+  "serif",
+  "sans-serif",
+  "monospace",
+  "cursive",
+  "fantasy",
+  "system-ui",
+  "ui-serif",
+  "ui-sans-serif",
+  "ui-monospace",
+  "ui-rounded",
+  "math",
+  "emoji",
+  "fangsong",
+];
+
+/// Keywords that can't appear in an unquoted family name
+const RESERVED_FAMILY_KEYWORDS: &[&str] = &[
+  "inherit",
+  "initial",
+  "unset",
+  "revert",
+  "revert-layer",
+  "default",
+];
+
+const FONT_STRETCH_KEYWORDS: &[&str] = &[
+  "ultra-condensed",
+  "extra-condensed",
+  "condensed",
+  "semi-condensed",
+  "semi-expanded",
+  "expanded",
+  "extra-expanded",
+  "ultra-expanded",
+];
+
+const FONT_SIZE_KEYWORDS: &[&str] = &[
+  "xx-small",
+  "x-small",
+  "small",
+  "medium",
+  "large",
+  "x-large",
+  "xx-large",
+  "xxx-large",
+  "larger",
+  "smaller",
+  "math",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FamilyName {
+  Generic(String),
+  Named(String),
+}
+
+/// Value of `font-family`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFamily {
+  pub families: Vec<FamilyName>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontStyle {
+  Normal,
+  Italic,
+  Oblique(Option<Angle>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontWeight {
+  Normal,
+  Bold,
+  Bolder,
+  Lighter,
+  Number(f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSize {
+  Keyword(String),
+  LengthPercentage(LengthPercentage),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineHeight {
+  Normal,
+  Number(f32),
+  LengthPercentage(LengthPercentage),
+}
+
+/// Value of the `font` shorthand. System font keywords (`caption`, `menu`, ...)
+/// are not parsed since they can't be expanded into longhands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Font {
+  pub style: Option<FontStyle>,
+  /// `normal` or `small-caps`
+  pub variant: Option<String>,
+  pub weight: Option<FontWeight>,
+  /// `normal` or a `<font-width-css3>` keyword
+  pub stretch: Option<String>,
+  pub size: FontSize,
+  pub line_height: Option<LineHeight>,
+  pub family: FontFamily,
+}
+
+impl FontFamily {
+  pub fn parser() -> TokenParser<FontFamily> {
+    TokenParser::new(
+      |tokens| {
+        let family = parse_font_family(tokens)?;
+        skip_whitespace(tokens)?;
+
+        Ok(family)
+      },
+      "font_family",
+    )
+  }
+}
+
+impl Font {
+  pub fn parser() -> TokenParser<Font> {
+    TokenParser::new(parse_font, "font")
+  }
+}
+
+fn parse_font_family(tokens: &mut TokenList) -> Result<FontFamily, CssParseError> {
+  let mut families = vec![];
+
+  loop {
+    skip_whitespace(tokens)?;
+    families.push(parse_family_name(tokens)?);
+    skip_whitespace(tokens)?;
+
+    if tokens.peek()? != Some(SimpleToken::Comma) {
+      break;
+    }
+
+    tokens.consume_next_token()?;
+  }
+
+  Ok(FontFamily { families })
+}
+
+fn parse_family_name(tokens: &mut TokenList) -> Result<FamilyName, CssParseError> {
+  if let Some(SimpleToken::String(name)) = tokens.peek()? {
+    tokens.consume_next_token()?;
+    return Ok(FamilyName::Named(name));
+  }
+
+  let mut words: Vec<String> = vec![];
+
+  loop {
+    let checkpoint = tokens.current_index;
+    skip_whitespace(tokens)?;
+
+    match tokens.peek()? {
+      Some(SimpleToken::Ident(word)) => {
+        tokens.consume_next_token()?;
+        words.push(word);
+      }
+      _ => {
+        tokens.set_current_index(checkpoint);
+        break;
+      }
+    }
+  }
+
+  match words.as_slice() {
+    [] => Err(CssParseError::ParseError {
+      message: format!("Expected a family name, got {:?}", tokens.peek()?),
+    }),
+    [word] if GENERIC_FONT_FAMILIES.contains(&word.as_str()) => {
+      Ok(FamilyName::Generic(word.clone()))
+    }
+    _ if words
+      .iter()
+      .any(|word| RESERVED_FAMILY_KEYWORDS.contains(&word.as_str())) =>
+    {
+      Err(CssParseError::ParseError {
+        message: format!("Reserved keyword in family name \"{}\"", words.join(" ")),
+      })
+    }
+    _ => Ok(FamilyName::Named(words.join(" "))),
+  }
+}
+
+fn parse_font_style(tokens: &mut TokenList) -> Result<Option<FontStyle>, CssParseError> {
+  if consume_ident(tokens, "italic")? {
+    return Ok(Some(FontStyle::Italic));
+  }
+
+  if consume_ident(tokens, "oblique")? {
+    let checkpoint = tokens.current_index;
+    skip_whitespace(tokens)?;
+
+    let angle = try_parse(tokens, &Angle::parser());
+
+    if angle.is_none() {
+      tokens.set_current_index(checkpoint);
+    }
+
+    return Ok(Some(FontStyle::Oblique(angle)));
+  }
+
+  Ok(None)
+}
+
+fn parse_font_weight(tokens: &mut TokenList) -> Result<Option<FontWeight>, CssParseError> {
+  let weight = match tokens.peek()? {
+    Some(SimpleToken::Ident(ident)) if ident == "bold" => FontWeight::Bold,
+    Some(SimpleToken::Ident(ident)) if ident == "bolder" => FontWeight::Bolder,
+    Some(SimpleToken::Ident(ident)) if ident == "lighter" => FontWeight::Lighter,
+    Some(SimpleToken::Number(value)) if (1.0..=1000.0).contains(&value) => {
+      FontWeight::Number(value as f32)
+    }
+    _ => return Ok(None),
+  };
+
+  tokens.consume_next_token()?;
+
+  Ok(Some(weight))
+}
+
+fn parse_keyword(
+  tokens: &mut TokenList,
+  keywords: &[&str],
+) -> Result<Option<String>, CssParseError> {
+  match tokens.peek()? {
+    Some(SimpleToken::Ident(ident)) if keywords.contains(&ident.as_str()) => {
+      tokens.consume_next_token()?;
+      Ok(Some(ident))
+    }
+    _ => Ok(None),
+  }
+}
+
+fn parse_font_size(tokens: &mut TokenList) -> Result<FontSize, CssParseError> {
+  if let Some(keyword) = parse_keyword(tokens, FONT_SIZE_KEYWORDS)? {
+    return Ok(FontSize::Keyword(keyword));
+  }
+
+  (LengthPercentage::parser().run)(tokens).map(FontSize::LengthPercentage)
+}
+
+fn parse_line_height(tokens: &mut TokenList) -> Result<LineHeight, CssParseError> {
+  if consume_ident(tokens, "normal")? {
+    return Ok(LineHeight::Normal);
+  }
+
+  if let Some(SimpleToken::Number(value)) = tokens.peek()?
+    && value >= 0.0
+  {
+    tokens.consume_next_token()?;
+    return Ok(LineHeight::Number(value as f32));
+  }
+
+  (LengthPercentage::parser().run)(tokens).map(LineHeight::LengthPercentage)
+}
+
+fn parse_font(tokens: &mut TokenList) -> Result<Font, CssParseError> {
+  let mut style = None;
+  let mut variant = None;
+  let mut weight = None;
+  let mut stretch = None;
+  // `normal` is valid for every prefix longhand, so it fills whichever is left
+  let mut normal_count = 0;
+
+  loop {
+    skip_whitespace(tokens)?;
+
+    if style.is_none()
+      && let Some(value) = parse_font_style(tokens)?
+    {
+      style = Some(value);
+    } else if variant.is_none() && consume_ident(tokens, "small-caps")? {
+      variant = Some("small-caps".to_string());
+    } else if weight.is_none()
+      && let Some(value) = parse_font_weight(tokens)?
+    {
+      weight = Some(value);
+    } else if stretch.is_none()
+      && let Some(value) = parse_keyword(tokens, FONT_STRETCH_KEYWORDS)?
+    {
+      stretch = Some(value);
+    } else if normal_count < 4 && consume_ident(tokens, "normal")? {
+      normal_count += 1;
+    } else {
+      break;
+    }
+  }
+
+  for _ in 0..normal_count {
+    if style.is_none() {
+      style = Some(FontStyle::Normal);
+    } else if variant.is_none() {
+      variant = Some("normal".to_string());
+    } else if weight.is_none() {
+      weight = Some(FontWeight::Normal);
+    } else if stretch.is_none() {
+      stretch = Some("normal".to_string());
+    } else {
+      return Err(CssParseError::ParseError {
+        message: "Too many `normal` keywords in font".to_string(),
+      });
+    }
+  }
+
+  let size = parse_font_size(tokens)?;
+
+  let checkpoint = tokens.current_index;
+  skip_whitespace(tokens)?;
+
+  let line_height = if tokens.peek()? == Some(SimpleToken::Delim('/')) {
+    tokens.consume_next_token()?;
+    skip_whitespace(tokens)?;
+    Some(parse_line_height(tokens)?)
+  } else {
+    tokens.set_current_index(checkpoint);
+    None
+  };
+
+  let family = parse_font_family(tokens)?;
+  skip_whitespace(tokens)?;
+
+  Ok(Font {
+    style,
+    variant,
+    weight,
+    stretch,
+    size,
+    line_height,
+    family,
+  })
+}
+
+/// Returns true if `name` reads back as the same family name when unquoted.
+fn can_be_unquoted(name: &str) -> bool {
+  let mut tokens = TokenList::new(name);
+  let mut words = vec![];
+
+  while let Ok(Some(token)) = tokens.consume_next_token() {
+    match token {
+      SimpleToken::Ident(word)
+        if !GENERIC_FONT_FAMILIES.contains(&word.as_str())
+          && !RESERVED_FAMILY_KEYWORDS.contains(&word.as_str()) =>
+      {
+        words.push(word)
+      }
+      SimpleToken::Whitespace => {}
+      _ => return false,
+    }
+  }
+
+  !words.is_empty() && words.join(" ") == name
+}
+
+impl Display for FamilyName {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FamilyName::Generic(name) => write!(f, "{}", name),
+      FamilyName::Named(name) if can_be_unquoted(name) => write!(f, "{}", name),
+      FamilyName::Named(name) => write!(f, "\"{}\"", name.replace('"', "\\\"")),
+    }
+  }
+}
+
+impl Display for FontFamily {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let families = self
+      .families
+      .iter()
+      .map(|family| family.to_string())
+      .collect::<Vec<_>>()
+      .join(", ");
+
+    write!(f, "{}", families)
+  }
+}
+
+impl Display for FontStyle {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FontStyle::Normal => write!(f, "normal"),
+      FontStyle::Italic => write!(f, "italic"),
+      FontStyle::Oblique(Some(angle)) => write!(f, "oblique {}", angle),
+      FontStyle::Oblique(None) => write!(f, "oblique"),
+    }
+  }
+}
+
+impl Display for FontWeight {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FontWeight::Normal => write!(f, "normal"),
+      FontWeight::Bold => write!(f, "bold"),
+      FontWeight::Bolder => write!(f, "bolder"),
+      FontWeight::Lighter => write!(f, "lighter"),
+      FontWeight::Number(value) => write!(f, "{}", value),
+    }
+  }
+}
+
+impl Display for FontSize {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FontSize::Keyword(keyword) => write!(f, "{}", keyword),
+      FontSize::LengthPercentage(value) => write!(f, "{}", value),
+    }
+  }
+}
+
+impl Display for LineHeight {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LineHeight::Normal => write!(f, "normal"),
+      LineHeight::Number(value) => write!(f, "{}", value),
+      LineHeight::LengthPercentage(value) => write!(f, "{}", value),
+    }
+  }
+}
+
+impl Display for Font {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut parts = vec![];
+
+    if let Some(style) = &self.style {
+      parts.push(style.to_string());
+    }
+
+    if let Some(variant) = &self.variant {
+      parts.push(variant.clone());
+    }
+
+    if let Some(weight) = &self.weight {
+      parts.push(weight.to_string());
+    }
+
+    if let Some(stretch) = &self.stretch {
+      parts.push(stretch.clone());
+    }
+
+    match &self.line_height {
+      Some(line_height) => parts.push(format!("{}/{}", self.size, line_height)),
+      None => parts.push(self.size.to_string()),
+    }
+
+    parts.push(self.family.to_string());
+
+    write!(f, "{}", parts.join(" "))
+  }
+}
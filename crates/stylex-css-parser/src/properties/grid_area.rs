@@ -0,0 +1,165 @@
+/*!
+CSS Grid placement property parsing.
+
+Handles `grid-area`, `grid-row` and `grid-column`, which are made of `<grid-line>`
+values separated by `/`.
+*/
+
+use crate::{
+  CssParseError,
+  css_types::gradient::{consume_ident, skip_whitespace},
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+/// `<grid-line>`
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridLine {
+  Auto,
+  /// `<integer>? <custom-ident>?`, at least one of them is present
+  Line {
+    index: Option<i32>,
+    name: Option<String>,
+  },
+  /// `span && [ <integer> || <custom-ident> ]`
+  Span {
+    count: Option<u32>,
+    name: Option<String>,
+  },
+}
+
+/// Value of `grid-area`, `grid-row` and `grid-column`
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridArea {
+  pub lines: Vec<GridLine>,
+}
+
+impl GridLine {
+  pub fn parser() -> TokenParser<GridLine> {
+    TokenParser::new(parse_grid_line, "grid_line")
+  }
+}
+
+impl GridArea {
+  /// Parser for `grid-area`, which accepts up to four lines
+  pub fn parser() -> TokenParser<GridArea> {
+    TokenParser::new(|tokens| parse_grid_lines(tokens, 4), "grid_area")
+  }
+
+  /// Parser for `grid-row` and `grid-column`, which accept up to two lines
+  pub fn line_pair_parser() -> TokenParser<GridArea> {
+    TokenParser::new(|tokens| parse_grid_lines(tokens, 2), "grid_line_pair")
+  }
+}
+
+fn parse_grid_lines(tokens: &mut TokenList, max_lines: usize) -> Result<GridArea, CssParseError> {
+  let mut lines = vec![];
+
+  loop {
+    skip_whitespace(tokens)?;
+    lines.push(parse_grid_line(tokens)?);
+    skip_whitespace(tokens)?;
+
+    if tokens.peek()? != Some(SimpleToken::Delim('/')) {
+      break;
+    }
+
+    if lines.len() == max_lines {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected at most {} grid lines", max_lines),
+      });
+    }
+
+    tokens.consume_next_token()?;
+  }
+
+  Ok(GridArea { lines })
+}
+
+fn parse_grid_line(tokens: &mut TokenList) -> Result<GridLine, CssParseError> {
+  if consume_ident(tokens, "auto")? {
+    return Ok(GridLine::Auto);
+  }
+
+  let mut span = false;
+  let mut index: Option<f64> = None;
+  let mut name: Option<String> = None;
+
+  loop {
+    skip_whitespace(tokens)?;
+
+    match tokens.peek()? {
+      Some(SimpleToken::Ident(ident)) if ident == "span" && !span => {
+        span = true;
+      }
+      Some(SimpleToken::Ident(ident))
+        if name.is_none() && !matches!(ident.as_str(), "span" | "auto") =>
+      {
+        name = Some(ident);
+      }
+      Some(SimpleToken::Number(value))
+        if index.is_none() && value.fract() == 0.0 && value != 0.0 =>
+      {
+        index = Some(value);
+      }
+      _ => break,
+    }
+
+    tokens.consume_next_token()?;
+  }
+
+  if index.is_none() && name.is_none() {
+    return Err(CssParseError::ParseError {
+      message: format!("Expected a grid line, got {:?}", tokens.peek()?),
+    });
+  }
+
+  if span {
+    if index.is_some_and(|index| index < 0.0) {
+      return Err(CssParseError::ParseError {
+        message: "A span count must be a positive integer".to_string(),
+      });
+    }
+
+    Ok(GridLine::Span {
+      count: index.map(|index| index as u32),
+      name,
+    })
+  } else {
+    Ok(GridLine::Line {
+      index: index.map(|index| index as i32),
+      name,
+    })
+  }
+}
+
+impl Display for GridLine {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (prefix, index, name) = match self {
+      GridLine::Auto => return write!(f, "auto"),
+      GridLine::Line { index, name } => (None, index.map(|index| index.to_string()), name),
+      GridLine::Span { count, name } => (Some("span"), count.map(|count| count.to_string()), name),
+    };
+
+    let parts = [prefix.map(str::to_string), index, name.clone()]
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>();
+
+    write!(f, "{}", parts.join(" "))
+  }
+}
+
+impl Display for GridArea {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let lines = self
+      .lines
+      .iter()
+      .map(|line| line.to_string())
+      .collect::<Vec<_>>()
+      .join(" / ");
+
+    write!(f, "{}", lines)
+  }
+}
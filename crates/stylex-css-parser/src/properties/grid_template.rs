@@ -0,0 +1,513 @@
+/*!
+CSS Grid template property parsing.
+
+Handles `grid-template-columns`, `grid-template-rows` and `grid-template-areas`,
+including `repeat()`, `minmax()`, `fit-content()` and bracketed line names.
+Values are printed in a canonical form so equivalent declarations compare equal.
+*/
+
+use crate::{
+  CssParseError,
+  css_types::{
+    Flex, LengthPercentage,
+    gradient::{consume_ident, expect_token, skip_whitespace, try_parse},
+  },
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+/// `<track-breadth>` (and `<inflexible-breadth>` when not a flex value)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackBreadth {
+  LengthPercentage(LengthPercentage),
+  Flex(Flex),
+  MinContent,
+  MaxContent,
+  Auto,
+}
+
+/// `<track-size>`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackSize {
+  Breadth(TrackBreadth),
+  MinMax(TrackBreadth, TrackBreadth),
+  FitContent(LengthPercentage),
+}
+
+/// Repetition count of a `repeat()` function
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepeatCount {
+  Count(u32),
+  AutoFill,
+  AutoFit,
+}
+
+/// `repeat( <count> , <track-list> )`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackRepeat {
+  pub count: RepeatCount,
+  pub tracks: Vec<TrackListItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackListItem {
+  LineNames(Vec<String>),
+  Size(TrackSize),
+  Repeat(TrackRepeat),
+}
+
+/// Value of `grid-template-columns` and `grid-template-rows`
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridTemplateTracks {
+  None,
+  Subgrid(Vec<Vec<String>>),
+  Tracks(Vec<TrackListItem>),
+}
+
+/// Value of `grid-template-areas`
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridTemplateAreas {
+  None,
+  /// Rows of cells, a null cell token is stored as `.`
+  Rows(Vec<Vec<String>>),
+}
+
+impl TrackBreadth {
+  pub fn parser() -> TokenParser<TrackBreadth> {
+    TokenParser::new(parse_track_breadth, "track_breadth")
+  }
+}
+
+impl TrackSize {
+  pub fn parser() -> TokenParser<TrackSize> {
+    TokenParser::new(parse_track_size, "track_size")
+  }
+}
+
+impl GridTemplateTracks {
+  pub fn parser() -> TokenParser<GridTemplateTracks> {
+    TokenParser::new(
+      |tokens| {
+        skip_whitespace(tokens)?;
+
+        let tracks = if consume_ident(tokens, "none")? {
+          GridTemplateTracks::None
+        } else if consume_ident(tokens, "subgrid")? {
+          let mut line_names = vec![];
+
+          loop {
+            skip_whitespace(tokens)?;
+
+            if tokens.peek()? != Some(SimpleToken::LeftBracket) {
+              break;
+            }
+
+            line_names.push(parse_line_names(tokens)?);
+          }
+
+          GridTemplateTracks::Subgrid(line_names)
+        } else {
+          GridTemplateTracks::Tracks(parse_track_list(tokens, None)?)
+        };
+
+        skip_whitespace(tokens)?;
+
+        Ok(tracks)
+      },
+      "grid_template_tracks",
+    )
+  }
+}
+
+impl GridTemplateAreas {
+  pub fn parser() -> TokenParser<GridTemplateAreas> {
+    TokenParser::new(
+      |tokens| {
+        skip_whitespace(tokens)?;
+
+        if consume_ident(tokens, "none")? {
+          skip_whitespace(tokens)?;
+          return Ok(GridTemplateAreas::None);
+        }
+
+        let mut rows = vec![];
+
+        while let Some(SimpleToken::String(row)) = tokens.peek()? {
+          tokens.consume_next_token()?;
+          rows.push(row);
+          skip_whitespace(tokens)?;
+        }
+
+        if rows.is_empty() {
+          return Err(CssParseError::ParseError {
+            message: "Expected `none` or at least one string".to_string(),
+          });
+        }
+
+        GridTemplateAreas::from_rows(&rows).map_err(|message| CssParseError::ParseError { message })
+      },
+      "grid_template_areas",
+    )
+  }
+
+  /// Builds the areas from the row strings, checking that every row has the
+  /// same number of cells and that every named area is a filled rectangle.
+  pub fn from_rows(rows: &[String]) -> Result<GridTemplateAreas, String> {
+    let rows = rows
+      .iter()
+      .map(|row| tokenize_area_row(row))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let columns = rows.first().map_or(0, Vec::len);
+
+    if columns == 0 {
+      return Err("Rows must contain at least one cell".to_string());
+    }
+
+    if let Some(row) = rows.iter().find(|row| row.len() != columns) {
+      return Err(format!(
+        "Every row must have {} cells, found a row with {}",
+        columns,
+        row.len()
+      ));
+    }
+
+    let mut names: Vec<&String> = vec![];
+
+    for cell in rows.iter().flatten() {
+      if cell != "." && !names.contains(&cell) {
+        names.push(cell);
+      }
+    }
+
+    for name in names {
+      let positions = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(row_index, row)| {
+          row
+            .iter()
+            .enumerate()
+            .filter(move |(_, cell)| *cell == name)
+            .map(move |(column_index, _)| (row_index, column_index))
+        })
+        .collect::<Vec<_>>();
+
+      let min_row = positions.iter().map(|(row, _)| *row).min().unwrap_or(0);
+      let max_row = positions.iter().map(|(row, _)| *row).max().unwrap_or(0);
+      let min_column = positions
+        .iter()
+        .map(|(_, column)| *column)
+        .min()
+        .unwrap_or(0);
+      let max_column = positions
+        .iter()
+        .map(|(_, column)| *column)
+        .max()
+        .unwrap_or(0);
+
+      if positions.len() != (max_row - min_row + 1) * (max_column - min_column + 1) {
+        return Err(format!("Area \"{}\" is not a rectangle", name));
+      }
+    }
+
+    Ok(GridTemplateAreas::Rows(rows))
+  }
+}
+
+/// Splits a `grid-template-areas` row into named and null cell tokens.
+fn tokenize_area_row(row: &str) -> Result<Vec<String>, String> {
+  let is_name_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii();
+
+  let mut cells = vec![];
+  let mut chars = row.chars().peekable();
+
+  while let Some(&c) = chars.peek() {
+    if c.is_whitespace() {
+      chars.next();
+    } else if c == '.' {
+      while chars.next_if_eq(&'.').is_some() {}
+      cells.push(".".to_string());
+    } else if is_name_char(c) {
+      let mut name = String::new();
+
+      while let Some(c) = chars.next_if(|c| is_name_char(*c)) {
+        name.push(c);
+      }
+
+      cells.push(name);
+    } else {
+      return Err(format!("Unexpected character \"{}\" in row \"{}\"", c, row));
+    }
+  }
+
+  Ok(cells)
+}
+
+fn parse_track_breadth(tokens: &mut TokenList) -> Result<TrackBreadth, CssParseError> {
+  if let Some(flex) = try_parse(tokens, &Flex::parser()) {
+    return Ok(TrackBreadth::Flex(flex));
+  }
+
+  if consume_ident(tokens, "min-content")? {
+    return Ok(TrackBreadth::MinContent);
+  }
+
+  if consume_ident(tokens, "max-content")? {
+    return Ok(TrackBreadth::MaxContent);
+  }
+
+  if consume_ident(tokens, "auto")? {
+    return Ok(TrackBreadth::Auto);
+  }
+
+  match try_parse(tokens, &LengthPercentage::parser()) {
+    Some(value) => Ok(TrackBreadth::LengthPercentage(value)),
+    None => Err(CssParseError::ParseError {
+      message: format!("Expected a track breadth, got {:?}", tokens.peek()?),
+    }),
+  }
+}
+
+fn parse_track_size(tokens: &mut TokenList) -> Result<TrackSize, CssParseError> {
+  match tokens.peek()? {
+    Some(SimpleToken::Function(name)) if name == "minmax" => {
+      tokens.consume_next_token()?;
+      skip_whitespace(tokens)?;
+      let min = parse_track_breadth(tokens)?;
+
+      if matches!(min, TrackBreadth::Flex(_)) {
+        return Err(CssParseError::ParseError {
+          message: "The minimum of minmax() cannot be a flex value".to_string(),
+        });
+      }
+
+      skip_whitespace(tokens)?;
+      expect_token(tokens, SimpleToken::Comma)?;
+      skip_whitespace(tokens)?;
+      let max = parse_track_breadth(tokens)?;
+      skip_whitespace(tokens)?;
+      expect_token(tokens, SimpleToken::RightParen)?;
+
+      Ok(TrackSize::MinMax(min, max))
+    }
+    Some(SimpleToken::Function(name)) if name == "fit-content" => {
+      tokens.consume_next_token()?;
+      skip_whitespace(tokens)?;
+      let limit = (LengthPercentage::parser().run)(tokens)?;
+      skip_whitespace(tokens)?;
+      expect_token(tokens, SimpleToken::RightParen)?;
+
+      Ok(TrackSize::FitContent(limit))
+    }
+    _ => parse_track_breadth(tokens).map(TrackSize::Breadth),
+  }
+}
+
+/// Parses `[ <custom-ident>* ]`
+fn parse_line_names(tokens: &mut TokenList) -> Result<Vec<String>, CssParseError> {
+  expect_token(tokens, SimpleToken::LeftBracket)?;
+
+  let mut names = vec![];
+
+  loop {
+    skip_whitespace(tokens)?;
+
+    match tokens.consume_next_token()? {
+      Some(SimpleToken::RightBracket) => break,
+      Some(SimpleToken::Ident(name)) if !matches!(name.as_str(), "span" | "auto") => {
+        names.push(name)
+      }
+      token => {
+        return Err(CssParseError::ParseError {
+          message: format!("Expected a line name, got {:?}", token),
+        });
+      }
+    }
+  }
+
+  Ok(names)
+}
+
+fn parse_repeat(tokens: &mut TokenList) -> Result<TrackRepeat, CssParseError> {
+  skip_whitespace(tokens)?;
+
+  let count = match tokens.consume_next_token()? {
+    Some(SimpleToken::Ident(ident)) if ident == "auto-fill" => RepeatCount::AutoFill,
+    Some(SimpleToken::Ident(ident)) if ident == "auto-fit" => RepeatCount::AutoFit,
+    Some(SimpleToken::Number(value)) if value >= 1.0 && value.fract() == 0.0 => {
+      RepeatCount::Count(value as u32)
+    }
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected a repeat count, got {:?}", token),
+      });
+    }
+  };
+
+  skip_whitespace(tokens)?;
+  expect_token(tokens, SimpleToken::Comma)?;
+
+  let tracks = parse_track_list(tokens, Some(SimpleToken::RightParen))?;
+  expect_token(tokens, SimpleToken::RightParen)?;
+
+  Ok(TrackRepeat { count, tracks })
+}
+
+/// Parses a whitespace separated track list up to `end` (or the end of input).
+/// Line names must alternate with track sizes.
+fn parse_track_list(
+  tokens: &mut TokenList,
+  end: Option<SimpleToken>,
+) -> Result<Vec<TrackListItem>, CssParseError> {
+  let mut items = vec![];
+
+  loop {
+    skip_whitespace(tokens)?;
+
+    let next = tokens.peek()?;
+
+    if next.is_none() || next == end {
+      break;
+    }
+
+    let item = match next {
+      Some(SimpleToken::LeftBracket) => {
+        if matches!(items.last(), Some(TrackListItem::LineNames(_))) {
+          return Err(CssParseError::ParseError {
+            message: "Line names must be separated by a track size".to_string(),
+          });
+        }
+
+        TrackListItem::LineNames(parse_line_names(tokens)?)
+      }
+      Some(SimpleToken::Function(name)) if name == "repeat" => {
+        if end.is_some() {
+          return Err(CssParseError::ParseError {
+            message: "repeat() cannot be nested".to_string(),
+          });
+        }
+
+        tokens.consume_next_token()?;
+        TrackListItem::Repeat(parse_repeat(tokens)?)
+      }
+      _ => TrackListItem::Size(parse_track_size(tokens)?),
+    };
+
+    items.push(item);
+  }
+
+  if !items
+    .iter()
+    .any(|item| !matches!(item, TrackListItem::LineNames(_)))
+  {
+    return Err(CssParseError::ParseError {
+      message: "A track list must contain at least one track size".to_string(),
+    });
+  }
+
+  Ok(items)
+}
+
+fn write_line_names(f: &mut fmt::Formatter<'_>, names: &[String]) -> fmt::Result {
+  write!(f, "[{}]", names.join(" "))
+}
+
+fn write_track_list(f: &mut fmt::Formatter<'_>, items: &[TrackListItem]) -> fmt::Result {
+  for (index, item) in items.iter().enumerate() {
+    if index > 0 {
+      write!(f, " ")?;
+    }
+
+    write!(f, "{}", item)?;
+  }
+
+  Ok(())
+}
+
+impl Display for TrackBreadth {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      TrackBreadth::LengthPercentage(value) => write!(f, "{}", value),
+      TrackBreadth::Flex(flex) => write!(f, "{}", flex),
+      TrackBreadth::MinContent => write!(f, "min-content"),
+      TrackBreadth::MaxContent => write!(f, "max-content"),
+      TrackBreadth::Auto => write!(f, "auto"),
+    }
+  }
+}
+
+impl Display for TrackSize {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      TrackSize::Breadth(breadth) => write!(f, "{}", breadth),
+      TrackSize::MinMax(min, max) => write!(f, "minmax({}, {})", min, max),
+      TrackSize::FitContent(limit) => write!(f, "fit-content({})", limit),
+    }
+  }
+}
+
+impl Display for RepeatCount {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RepeatCount::Count(count) => write!(f, "{}", count),
+      RepeatCount::AutoFill => write!(f, "auto-fill"),
+      RepeatCount::AutoFit => write!(f, "auto-fit"),
+    }
+  }
+}
+
+impl Display for TrackRepeat {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "repeat({}, ", self.count)?;
+    write_track_list(f, &self.tracks)?;
+    write!(f, ")")
+  }
+}
+
+impl Display for TrackListItem {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      TrackListItem::LineNames(names) => write_line_names(f, names),
+      TrackListItem::Size(size) => write!(f, "{}", size),
+      TrackListItem::Repeat(repeat) => write!(f, "{}", repeat),
+    }
+  }
+}
+
+impl Display for GridTemplateTracks {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      GridTemplateTracks::None => write!(f, "none"),
+      GridTemplateTracks::Subgrid(line_names) => {
+        write!(f, "subgrid")?;
+
+        for names in line_names {
+          write!(f, " ")?;
+          write_line_names(f, names)?;
+        }
+
+        Ok(())
+      }
+      GridTemplateTracks::Tracks(items) => write_track_list(f, items),
+    }
+  }
+}
+
+impl Display for GridTemplateAreas {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      GridTemplateAreas::None => write!(f, "none"),
+      GridTemplateAreas::Rows(rows) => {
+        let rows = rows
+          .iter()
+          .map(|row| format!("\"{}\"", row.join(" ")))
+          .collect::<Vec<_>>()
+          .join(" ");
+
+        write!(f, "{}", rows)
+      }
+    }
+  }
+}
@@ -7,8 +7,17 @@ Each property has its own module with specialized parsing for that property's sy
 
 pub mod border_radius;
 pub mod box_shadow;
+pub mod font;
+pub mod grid_area;
+pub mod grid_template;
 pub mod transform;
 
 pub use border_radius::{BorderRadiusIndividual, BorderRadiusShorthand};
 pub use box_shadow::{BoxShadow, BoxShadowList};
+pub use font::{FamilyName, Font, FontFamily, FontSize, FontStyle, FontWeight, LineHeight};
+pub use grid_area::{GridArea, GridLine};
+pub use grid_template::{
+  GridTemplateAreas, GridTemplateTracks, RepeatCount, TrackBreadth, TrackListItem, TrackRepeat,
+  TrackSize,
+};
 pub use transform::Transform;
@@ -3,5 +3,7 @@ At-queries module tests.
 */
 
 pub mod media_query_transform_test;
+pub mod parse_container_query_test;
 pub mod parse_media_query_test;
+pub mod parse_supports_condition_test;
 pub mod validation_media_query_test;
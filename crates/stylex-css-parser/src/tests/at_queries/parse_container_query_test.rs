@@ -0,0 +1,195 @@
+use crate::at_queries::{
+  ContainerQueryErrors, container_query::ContainerQuery, validate_container_query,
+};
+
+#[cfg(test)]
+mod style_value_parser_at_queries {
+  use super::*;
+
+  fn normalized(input: &str) -> String {
+    ContainerQuery::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {:?}", input, error))
+      .to_string()
+  }
+
+  #[cfg(test)]
+  mod parse_container_queries {
+    use super::*;
+
+    #[test]
+    fn named_and_anonymous_containers() {
+      assert_eq!(
+        normalized("@container (min-width: 400px)"),
+        "@container (min-width: 400px)"
+      );
+      assert_eq!(
+        normalized("@container sidebar (min-width: 400px)"),
+        "@container sidebar (min-width: 400px)"
+      );
+      assert_eq!(normalized("@container sidebar"), "@container sidebar");
+    }
+
+    #[test]
+    fn logical_size_features() {
+      assert_eq!(
+        normalized("@container (min-inline-size: 30em)"),
+        "@container (min-inline-size: 30em)"
+      );
+      assert_eq!(
+        normalized("@container (max-block-size: 600px)"),
+        "@container (max-block-size: 600px)"
+      );
+      assert_eq!(
+        normalized("@container (orientation: landscape)"),
+        "@container (orientation: landscape)"
+      );
+      assert_eq!(
+        normalized("@container (aspect-ratio: 16/9)"),
+        "@container (aspect-ratio: 16 / 9)"
+      );
+    }
+
+    #[test]
+    fn range_syntax() {
+      assert_eq!(
+        normalized("@container (width >= 400px)"),
+        "@container (min-width: 400px)"
+      );
+      assert_eq!(
+        normalized("@container (inline-size > 400px)"),
+        "@container (min-inline-size: 400.01px)"
+      );
+      assert_eq!(
+        normalized("@container (400px <= width <= 800px)"),
+        "@container (min-width: 400px) and (max-width: 800px)"
+      );
+      assert_eq!(
+        normalized("@container card (400px < inline-size < 800px)"),
+        "@container card (min-inline-size: 400.01px) and (max-inline-size: 799.99px)"
+      );
+    }
+
+    #[test]
+    fn style_queries() {
+      assert_eq!(
+        normalized("@container style(--theme: dark)"),
+        "@container style(--theme: dark)"
+      );
+      assert_eq!(
+        normalized("@container style(--compact)"),
+        "@container style(--compact)"
+      );
+      assert_eq!(
+        normalized("@container card (min-width: 400px) and style(--theme:   dark)"),
+        "@container card (min-width: 400px) and style(--theme: dark)"
+      );
+    }
+
+    #[test]
+    fn logical_combinators() {
+      assert_eq!(
+        normalized("@container (min-width: 400px) or (orientation: portrait)"),
+        "@container (min-width: 400px) or (orientation: portrait)"
+      );
+      assert_eq!(
+        normalized("@container not (min-width: 400px)"),
+        "@container not (min-width: 400px)"
+      );
+      assert_eq!(
+        normalized("@container ((min-width: 400px) and (max-width: 800px)) or style(--wide)"),
+        "@container ((min-width: 400px) and (max-width: 800px)) or style(--wide)"
+      );
+    }
+
+    #[test]
+    fn normalizes_equivalent_queries() {
+      assert_eq!(
+        normalized("@container not (not (min-width: 400px))"),
+        "@container (min-width: 400px)"
+      );
+      assert_eq!(
+        normalized("@container ((min-width: 400px) and (min-width: 500px)) and (max-width: 900px)"),
+        "@container (min-width: 500px) and (max-width: 900px)"
+      );
+      assert_eq!(
+        normalized("@container (min-width: 400px) or (min-width: 400px)"),
+        "@container (min-width: 400px)"
+      );
+    }
+
+    #[test]
+    fn keeps_exact_size_features() {
+      assert_eq!(
+        normalized("@container (width: 400px) and (width: 500px)"),
+        "@container (width: 400px) and (width: 500px)"
+      );
+      assert_eq!(
+        normalized("@container (min-width: 400px) and (width: 500px) and (min-width: 450px)"),
+        "@container (min-width: 450px) and (width: 500px)"
+      );
+    }
+
+    #[test]
+    fn inline_size_bounds() {
+      let query = ContainerQuery::parser()
+        .parse_to_end("@container (min-inline-size: 400px) and (max-width: 60em)")
+        .unwrap();
+
+      let (lower, upper) = query.inline_size_bounds();
+
+      assert_eq!(lower.unwrap().to_string(), "400px");
+      assert_eq!(upper.unwrap().to_string(), "60em");
+    }
+  }
+
+  #[cfg(test)]
+  mod validation_container_queries {
+    use super::*;
+
+    #[test]
+    fn throws_syntax_error_for_invalid_queries() {
+      let test_cases = [
+        "@container",
+        "@container ",
+        "@container ()",
+        "@container none (min-width: 400px)",
+        "@container (min-width: )",
+        "@container (min-width: 400px) and (max-width: 800px) or (orientation: portrait)",
+        "@container (color: red)",
+        "@container (orientation: sideways)",
+        "@container (aspect-ratio > 1px)",
+        "@container (400px < width > 800px)",
+        "@container style(color: red)",
+        "@container style(--theme:)",
+      ];
+
+      for invalid_query in test_cases {
+        assert_eq!(
+          validate_container_query(invalid_query).unwrap_err(),
+          ContainerQueryErrors::SYNTAX_ERROR,
+          "Should throw SYNTAX_ERROR for: {}",
+          invalid_query
+        );
+      }
+    }
+
+    #[test]
+    fn throws_unbalanced_parens_error() {
+      let test_cases = [
+        "@container (min-width: 400px",
+        "@container min-width: 400px)",
+        "@container style(--theme: dark",
+      ];
+
+      for invalid_query in test_cases {
+        assert_eq!(
+          validate_container_query(invalid_query).unwrap_err(),
+          ContainerQueryErrors::UNBALANCED_PARENS,
+          "Should throw UNBALANCED_PARENS for: {}",
+          invalid_query
+        );
+      }
+    }
+  }
+}
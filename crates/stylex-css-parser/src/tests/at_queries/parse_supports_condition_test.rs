@@ -0,0 +1,155 @@
+use crate::at_queries::{SupportsConditionErrors, SupportsQuery, validate_supports_query};
+
+#[cfg(test)]
+mod style_value_parser_at_queries {
+  use super::*;
+
+  fn normalized(input: &str) -> String {
+    SupportsQuery::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {:?}", input, error))
+      .to_string()
+  }
+
+  #[cfg(test)]
+  mod parse_supports_conditions {
+    use super::*;
+
+    #[test]
+    fn declarations() {
+      assert_eq!(
+        normalized("@supports (display: grid)"),
+        "@supports (display: grid)"
+      );
+      assert_eq!(
+        normalized("@supports (display:grid)"),
+        "@supports (display: grid)"
+      );
+      assert_eq!(
+        normalized("@supports (  display :   grid  )"),
+        "@supports (display: grid)"
+      );
+      assert_eq!(
+        normalized("@supports (color: oklab(0 0 0))"),
+        "@supports (color: oklab(0 0 0))"
+      );
+      assert_eq!(
+        normalized("@supports (transform: translate( 1px ,2px ))"),
+        "@supports (transform: translate(1px, 2px))"
+      );
+      assert_eq!(
+        normalized("@supports (--custom: 1px solid)"),
+        "@supports (--custom: 1px solid)"
+      );
+    }
+
+    #[test]
+    fn functions() {
+      assert_eq!(
+        normalized("@supports selector(:has(a))"),
+        "@supports selector(:has(a))"
+      );
+      assert_eq!(
+        normalized("@supports selector( a   >   b )"),
+        "@supports selector(a > b)"
+      );
+      assert_eq!(
+        normalized("@supports font-tech(color-COLRv1)"),
+        "@supports font-tech(color-COLRv1)"
+      );
+      assert_eq!(
+        normalized("@supports font-format(woff2)"),
+        "@supports font-format(woff2)"
+      );
+    }
+
+    #[test]
+    fn logical_combinators() {
+      assert_eq!(
+        normalized("@supports not (hover: hover)"),
+        "@supports not (hover: hover)"
+      );
+      assert_eq!(
+        normalized("@supports (display: grid) and (gap: 1px)"),
+        "@supports (display: grid) and (gap: 1px)"
+      );
+      assert_eq!(
+        normalized("@supports (display: grid) or selector(:has(a))"),
+        "@supports (display: grid) or selector(:has(a))"
+      );
+      assert_eq!(
+        normalized("@supports (display: grid) and (not (display: inline-grid))"),
+        "@supports (display: grid) and (not (display: inline-grid))"
+      );
+      assert_eq!(
+        normalized("@supports ((display: flex) or (display: grid)) and (gap: 1px)"),
+        "@supports ((display: flex) or (display: grid)) and (gap: 1px)"
+      );
+    }
+
+    #[test]
+    fn normalizes_equivalent_conditions() {
+      assert_eq!(
+        normalized("@supports not (not (display: grid))"),
+        "@supports (display: grid)"
+      );
+      assert_eq!(
+        normalized("@supports ((display: grid))"),
+        "@supports (display: grid)"
+      );
+      assert_eq!(
+        normalized("@supports ((display: grid) and (gap: 1px)) and (display:grid)"),
+        "@supports (display: grid) and (gap: 1px)"
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod validation_supports_conditions {
+    use super::*;
+
+    #[test]
+    fn throws_syntax_error_for_invalid_conditions() {
+      let test_cases = [
+        "@supports",
+        "@supports ",
+        "@supports ()",
+        "@supports display: grid",
+        "@supports (display:)",
+        "@supports (display: grid) and (gap: 1px) or (color: red)",
+        "@supports not(display: grid)",
+        "@supports selector()",
+        "@supports font-tech(color colrv1)",
+        "@supports unknown(foo)",
+        "@supports (display: grid) (gap: 1px)",
+      ];
+
+      for invalid_condition in test_cases {
+        assert_eq!(
+          validate_supports_query(invalid_condition).unwrap_err(),
+          SupportsConditionErrors::SYNTAX_ERROR,
+          "Should throw SYNTAX_ERROR for: {}",
+          invalid_condition
+        );
+      }
+    }
+
+    #[test]
+    fn throws_unbalanced_parens_error() {
+      let test_cases = [
+        "@supports (display:grid",
+        "@supports display: grid)",
+        "@supports selector(:has(a)",
+      ];
+
+      for invalid_condition in test_cases {
+        assert_eq!(
+          validate_supports_query(invalid_condition).unwrap_err(),
+          SupportsConditionErrors::UNBALANCED_PARENS,
+          "Should throw UNBALANCED_PARENS for: {}",
+          invalid_condition
+        );
+      }
+    }
+  }
+}
@@ -255,7 +255,6 @@ mod test_css_type_calc {
   }
 
   #[test]
-  #[ignore]
   fn parses_nested_calc_expressions() {
     let calc = Calc::parser()
       .parse_to_end("calc(calc(100% - 10px) + calc(20px * 2))")
@@ -340,3 +339,74 @@ mod test_css_type_calc {
     assert!(Calc::parser().parse_to_end("calc(10+ 5 )").is_err());
   }
 }
+
+#[cfg(test)]
+mod test_css_type_calc_simplify {
+  use super::*;
+
+  fn simplify(input: &str) -> String {
+    Calc::parser()
+      .parse_to_end(input)
+      .unwrap()
+      .simplify()
+      .to_string()
+  }
+
+  #[test]
+  fn respects_operator_precedence_and_associativity() {
+    assert_eq!(simplify("calc(10px - 2px + 3px)"), "calc(11px)");
+    assert_eq!(simplify("calc(1px + 2px * 3)"), "calc(7px)");
+    assert_eq!(simplify("calc(12px / 2 * 3)"), "calc(18px)");
+    assert_eq!(simplify("calc(20 - 10 / 2)"), "calc(15)");
+  }
+
+  #[test]
+  fn folds_same_unit_arithmetic() {
+    assert_eq!(simplify("calc(8px * 2)"), "calc(16px)");
+    assert_eq!(simplify("calc(2 * 8px)"), "calc(16px)");
+    assert_eq!(simplify("calc(8px + 4px)"), "calc(12px)");
+    assert_eq!(simplify("calc(1.1rem + 2.2rem)"), "calc(3.3rem)");
+    assert_eq!(simplify("calc(100% / 4)"), "calc(25%)");
+    assert_eq!(simplify("calc(100px / 8)"), "calc(12.5px)");
+    assert_eq!(simplify("calc(0.1px + 0.2px)"), "calc(0.3px)");
+    assert_eq!(simplify("calc(4px - 10px)"), "calc(-6px)");
+  }
+
+  #[test]
+  fn folds_nested_calc_and_groups() {
+    assert_eq!(simplify("calc(calc(4px * 2) + 4px)"), "calc(12px)");
+    assert_eq!(simplify("calc((8px + 2px) * 2)"), "calc(20px)");
+    assert_eq!(
+      simplify("calc(calc(100% - 10px) + calc(20px * 2))"),
+      "calc(100% + 30px)"
+    );
+  }
+
+  #[test]
+  fn keeps_mixed_units_intact() {
+    assert_eq!(simplify("calc(100% - 20px)"), "calc(100% - 20px)");
+    assert_eq!(simplify("calc(10px + 5em)"), "calc(10px + 5em)");
+    assert_eq!(
+      simplify("calc((100% - 50px) * 0.5)"),
+      "calc((100% - 50px) * 0.5)"
+    );
+    assert_eq!(simplify("calc(100% - 10px + 4px - 50%)"), "calc(50% - 6px)");
+    assert_eq!(simplify("calc(2px - (1em - 3px))"), "calc(5px - 1em)");
+  }
+
+  #[test]
+  fn keeps_results_that_cannot_be_written_exactly() {
+    assert_eq!(simplify("calc(100% / 3)"), "calc(100% / 3)");
+    assert_eq!(simplify("calc(10px * 0.123456)"), "calc(10px * 0.123456)");
+    assert_eq!(
+      simplify("calc(0.00001px + 0.00002px)"),
+      "calc(0.00001px + 0.00002px)"
+    );
+  }
+
+  #[test]
+  fn keeps_constants_intact() {
+    assert_eq!(simplify("calc(pi * 2)"), "calc(pi * 2)");
+    assert_eq!(simplify("calc(10px / (pi * 2))"), "calc(10px / (pi * 2))");
+  }
+}
@@ -570,3 +570,91 @@ mod test_css_type_color {
     }
   }
 }
+
+#[cfg(test)]
+mod test_css_type_color_minify {
+  use super::*;
+
+  fn minify(input: &str) -> Option<String> {
+    Color::parse()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {}", input, error))
+      .minify()
+  }
+
+  #[test]
+  fn equivalent_colors_minify_to_the_same_value() {
+    for input in [
+      "#FFFFFF",
+      "#fff",
+      "white",
+      "rgb(255 255 255)",
+      "rgb(255, 255, 255)",
+    ] {
+      assert_eq!(minify(input).as_deref(), Some("#fff"), "input: {}", input);
+    }
+  }
+
+  #[test]
+  fn picks_the_shortest_spelling() {
+    assert_eq!(minify("#FF0000").as_deref(), Some("red"));
+    assert_eq!(minify("#000080").as_deref(), Some("navy"));
+    assert_eq!(minify("#808080").as_deref(), Some("gray"));
+    assert_eq!(minify("#00ffff").as_deref(), Some("#0ff"));
+    assert_eq!(minify("#123456").as_deref(), Some("#123456"));
+    assert_eq!(minify("RED").as_deref(), Some("red"));
+  }
+
+  #[test]
+  fn converts_hsl_to_rgb() {
+    assert_eq!(minify("hsl(0, 100%, 50%)").as_deref(), Some("red"));
+    assert_eq!(minify("hsl(120deg, 100%, 25%)").as_deref(), Some("green"));
+    assert_eq!(minify("hsl(0.5turn, 100%, 50%)").as_deref(), Some("#0ff"));
+    assert_eq!(
+      minify("hsla(240, 100%, 50%, 0.5)").as_deref(),
+      Some("rgba(0,0,255,.5)")
+    );
+  }
+
+  #[test]
+  fn keeps_alpha() {
+    assert_eq!(
+      minify("rgba(0, 0, 0, 0.5)").as_deref(),
+      Some("rgba(0,0,0,.5)")
+    );
+    assert_eq!(minify("#ff000080").as_deref(), Some("rgba(255,0,0,.502)"));
+    assert_eq!(minify("#ff0000ff").as_deref(), Some("red"));
+    assert_eq!(minify("rgba(0, 0, 0, 0)").as_deref(), Some("transparent"));
+    assert_eq!(minify("transparent").as_deref(), Some("transparent"));
+  }
+
+  #[test]
+  fn keeps_zero_and_near_zero_alpha() {
+    for input in [
+      "rgba(255,0,0,0)",
+      "rgb(255 0 0 / 0)",
+      "hsla(0,100%,50%,0)",
+      "#ff000000",
+      "rgba(255,0,0,0.0004)",
+    ] {
+      assert_eq!(
+        minify(input).as_deref(),
+        Some("rgba(255,0,0,0)"),
+        "input: {}",
+        input
+      );
+    }
+
+    assert_eq!(
+      minify("rgba(255,0,0,0.0006)").as_deref(),
+      Some("rgba(255,0,0,.001)")
+    );
+  }
+
+  #[test]
+  fn keywords_and_non_srgb_colors() {
+    assert_eq!(minify("currentColor").as_deref(), Some("currentcolor"));
+    assert_eq!(minify("oklch(0.5 0.1 270deg)"), None);
+    assert_eq!(minify("lch(50% 30 270)"), None);
+  }
+}
@@ -0,0 +1,164 @@
+/*!
+Gradient tests.
+*/
+
+use crate::css_types::{
+  ColorStopListItem, Gradient, LinearGradientDirection, RadialGradientShape,
+  position::HorizontalKeyword,
+};
+
+#[cfg(test)]
+mod gradient_parse {
+  use super::*;
+
+  fn parse(input: &str) -> Gradient {
+    Gradient::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|err| panic!("Failed to parse `{}`: {}", input, err))
+  }
+
+  #[test]
+  fn parses_linear_gradient_without_direction() {
+    let Gradient::Linear(gradient) = parse("linear-gradient(red, blue)") else {
+      panic!("Expected a linear gradient");
+    };
+
+    assert!(!gradient.repeating);
+    assert!(gradient.direction.is_none());
+    assert_eq!(gradient.stops.len(), 2);
+  }
+
+  #[test]
+  fn parses_linear_gradient_with_angle() {
+    let Gradient::Linear(gradient) = parse("linear-gradient(45deg, red 0%, blue 100%)") else {
+      panic!("Expected a linear gradient");
+    };
+
+    assert!(matches!(
+      gradient.direction,
+      Some(LinearGradientDirection::Angle(_))
+    ));
+  }
+
+  #[test]
+  fn parses_linear_gradient_to_side_and_corner() {
+    let Gradient::Linear(gradient) = parse("linear-gradient(to left, red, blue)") else {
+      panic!("Expected a linear gradient");
+    };
+
+    assert_eq!(
+      gradient.direction,
+      Some(LinearGradientDirection::ToSide(
+        Some(HorizontalKeyword::Left),
+        None
+      ))
+    );
+
+    assert_eq!(
+      parse("linear-gradient(to top right, red, blue)").to_string(),
+      "linear-gradient(to right top, red, blue)"
+    );
+  }
+
+  #[test]
+  fn parses_color_stops_with_two_positions_and_hints() {
+    let Gradient::Linear(gradient) = parse("linear-gradient(red 10% 20%, 30%, blue 50px, green)")
+    else {
+      panic!("Expected a linear gradient");
+    };
+
+    assert_eq!(gradient.stops.len(), 4);
+    assert!(matches!(
+      &gradient.stops[0],
+      ColorStopListItem::ColorStop { positions, .. } if positions.len() == 2
+    ));
+    assert!(matches!(gradient.stops[1], ColorStopListItem::Hint(_)));
+  }
+
+  #[test]
+  fn parses_interpolation_methods() {
+    assert_eq!(
+      parse("linear-gradient(in oklch longer hue, red, blue)").to_string(),
+      "linear-gradient(in oklch longer hue, red, blue)"
+    );
+    assert_eq!(
+      parse("linear-gradient(to right in srgb-linear, red, blue)").to_string(),
+      "linear-gradient(to right in srgb-linear, red, blue)"
+    );
+    assert_eq!(
+      parse("linear-gradient(in display-p3 90deg, red, blue)").to_string(),
+      "linear-gradient(90deg in display-p3, red, blue)"
+    );
+  }
+
+  #[test]
+  fn parses_radial_gradients() {
+    let Gradient::Radial(gradient) = parse("radial-gradient(circle at left, red, blue)") else {
+      panic!("Expected a radial gradient");
+    };
+
+    assert_eq!(gradient.shape, Some(RadialGradientShape::Circle));
+    assert!(gradient.position.is_some());
+
+    assert_eq!(
+      parse("radial-gradient(ellipse farthest-corner, red, blue)").to_string(),
+      "radial-gradient(ellipse farthest-corner, red, blue)"
+    );
+    assert_eq!(
+      parse("radial-gradient(10px, red, blue)").to_string(),
+      "radial-gradient(10px, red, blue)"
+    );
+    assert_eq!(
+      parse("radial-gradient(20% 30px, red, blue)").to_string(),
+      "radial-gradient(20% 30px, red, blue)"
+    );
+  }
+
+  #[test]
+  fn parses_conic_gradients() {
+    let Gradient::Conic(gradient) =
+      parse("conic-gradient(from 90deg at left top, red 0deg, blue 50%)")
+    else {
+      panic!("Expected a conic gradient");
+    };
+
+    assert!(gradient.from.is_some());
+    assert!(gradient.position.is_some());
+    assert_eq!(gradient.stops.len(), 2);
+  }
+
+  #[test]
+  fn parses_repeating_gradients() {
+    let gradient = parse("repeating-linear-gradient(red 0px, blue 10px)");
+
+    assert!(gradient.is_repeating());
+    assert_eq!(
+      gradient.to_string(),
+      "repeating-linear-gradient(red 0px, blue 10px)"
+    );
+    assert!(parse("repeating-radial-gradient(red, blue)").is_repeating());
+    assert!(parse("repeating-conic-gradient(red, blue)").is_repeating());
+  }
+
+  #[test]
+  fn rejects_invalid_gradients() {
+    for input in [
+      "linear-gradient(red)",
+      "linear-gradient(to center, red, blue)",
+      "linear-gradient(45, red, blue)",
+      "linear-gradient(red, 10%)",
+      "linear-gradient(10%, red, blue)",
+      "linear-gradient(in rgb, red, blue)",
+      "linear-gradient(in srgb longer hue, red, blue)",
+      "radial-gradient(circle 10px 20px, red, blue)",
+      "conic-gradient(red 10px, blue)",
+      "cubic-gradient(red, blue)",
+    ] {
+      assert!(
+        Gradient::parser().parse_to_end(input).is_err(),
+        "Expected `{}` to be invalid",
+        input
+      );
+    }
+  }
+}
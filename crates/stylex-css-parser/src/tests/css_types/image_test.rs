@@ -0,0 +1,80 @@
+/*!
+Image tests.
+*/
+
+use crate::css_types::{Image, ImageSetSource};
+
+#[cfg(test)]
+mod image_parse {
+  use super::*;
+
+  #[test]
+  fn parses_urls() {
+    assert_eq!(
+      Image::parser().parse_to_end("url(image.png)").unwrap(),
+      Image::Url("image.png".to_string())
+    );
+    assert_eq!(
+      Image::parser().parse_to_end("url(\"image.png\")").unwrap(),
+      Image::Url("image.png".to_string())
+    );
+    assert_eq!(
+      Image::parser()
+        .parse_to_end("url('my image.png')")
+        .unwrap()
+        .to_string(),
+      "url(\"my image.png\")"
+    );
+  }
+
+  #[test]
+  fn parses_gradients() {
+    let image = Image::parser()
+      .parse_to_end("linear-gradient(to right, red, blue)")
+      .unwrap();
+
+    assert!(matches!(image, Image::Gradient(_)));
+  }
+
+  #[test]
+  fn parses_image_sets() {
+    let image = Image::parser()
+      .parse_to_end("image-set(\"a.png\" 1x, url(b.png) 2x type(\"image/png\"))")
+      .unwrap();
+
+    let Image::ImageSet(options) = &image else {
+      panic!("Expected an image set");
+    };
+
+    assert_eq!(options.len(), 2);
+    assert_eq!(
+      options[0].image,
+      ImageSetSource::String("a.png".to_string())
+    );
+    assert_eq!(options[1].mime_type.as_deref(), Some("image/png"));
+    assert_eq!(
+      image.to_string(),
+      "image-set(\"a.png\" 1x, url(b.png) 2x type(\"image/png\"))"
+    );
+  }
+
+  #[test]
+  fn parses_image_lists() {
+    let images = Image::list_parser()
+      .parse_to_end("url(a.png), linear-gradient(red, blue)")
+      .unwrap();
+
+    assert_eq!(images.len(), 2);
+  }
+
+  #[test]
+  fn rejects_invalid_images() {
+    for input in ["red", "\"a.png\"", "image-set(a.png 1x)", "image-set()"] {
+      assert!(
+        Image::parser().parse_to_end(input).is_err(),
+        "Expected `{}` to be invalid",
+        input
+      );
+    }
+  }
+}
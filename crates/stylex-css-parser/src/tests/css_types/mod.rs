@@ -17,6 +17,8 @@ pub mod easing_function_test;
 pub mod filter_function_test;
 pub mod flex_test;
 pub mod frequency_test;
+pub mod gradient_test;
+pub mod image_test;
 pub mod length_percentage_test;
 pub mod length_test;
 pub mod position_test;
@@ -0,0 +1,142 @@
+/*!
+Font property tests.
+*/
+
+use crate::properties::font::{FamilyName, Font, FontFamily, FontStyle, FontWeight, LineHeight};
+
+#[cfg(test)]
+mod test_css_property_font_family {
+  use super::*;
+
+  fn normalize(input: &str) -> String {
+    FontFamily::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {}", input, error))
+      .to_string()
+  }
+
+  #[test]
+  fn valid_family_lists() {
+    let family = FontFamily::parser()
+      .parse_to_end("\"Helvetica Neue\", Arial, sans-serif")
+      .unwrap();
+
+    assert_eq!(
+      family.families,
+      vec![
+        FamilyName::Named("Helvetica Neue".to_string()),
+        FamilyName::Named("Arial".to_string()),
+        FamilyName::Generic("sans-serif".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn quoting_is_canonicalized() {
+    assert_eq!(normalize("\"Inter\", sans-serif"), "Inter, sans-serif");
+    assert_eq!(normalize("Inter,sans-serif"), "Inter, sans-serif");
+    assert_eq!(normalize("'Helvetica Neue'"), "Helvetica Neue");
+    assert_eq!(normalize("Helvetica   Neue"), "Helvetica Neue");
+  }
+
+  #[test]
+  fn names_that_need_quotes_keep_them() {
+    assert_eq!(normalize("\"sans-serif\""), "\"sans-serif\"");
+    assert_eq!(normalize("'inherit'"), "\"inherit\"");
+    assert_eq!(normalize("\"Font  Name\""), "\"Font  Name\"");
+    assert_eq!(normalize("\"Font 3\""), "\"Font 3\"");
+    assert_eq!(normalize("'Font, Inc'"), "\"Font, Inc\"");
+  }
+
+  #[test]
+  fn invalid_family_lists() {
+    let parser = FontFamily::parser();
+
+    assert!(parser.parse_to_end("").is_err());
+    assert!(parser.parse_to_end("Arial,").is_err());
+    assert!(parser.parse_to_end("inherit Sans").is_err());
+    assert!(parser.parse_to_end("Font 3").is_err());
+  }
+}
+
+#[cfg(test)]
+mod test_css_property_font {
+  use super::*;
+
+  fn normalize(input: &str) -> String {
+    Font::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {}", input, error))
+      .to_string()
+  }
+
+  #[test]
+  fn valid_size_and_family() {
+    let font = Font::parser().parse_to_end("16px Arial").unwrap();
+
+    assert_eq!(font.style, None);
+    assert_eq!(font.weight, None);
+    assert_eq!(font.line_height, None);
+    assert_eq!(font.size.to_string(), "16px");
+    assert_eq!(font.family.to_string(), "Arial");
+  }
+
+  #[test]
+  fn valid_prefix_longhands() {
+    let font = Font::parser()
+      .parse_to_end("italic small-caps bold condensed 1rem/1.5 \"Inter\", sans-serif")
+      .unwrap();
+
+    assert_eq!(font.style, Some(FontStyle::Italic));
+    assert_eq!(font.variant.as_deref(), Some("small-caps"));
+    assert_eq!(font.weight, Some(FontWeight::Bold));
+    assert_eq!(font.stretch.as_deref(), Some("condensed"));
+    assert_eq!(font.line_height, Some(LineHeight::Number(1.5)));
+    assert_eq!(
+      font.to_string(),
+      "italic small-caps bold condensed 1rem/1.5 Inter, sans-serif"
+    );
+  }
+
+  #[test]
+  fn normal_fills_remaining_longhands() {
+    let font = Font::parser()
+      .parse_to_end("normal 700 normal 12px serif")
+      .unwrap();
+
+    assert_eq!(font.style, Some(FontStyle::Normal));
+    assert_eq!(font.variant.as_deref(), Some("normal"));
+    assert_eq!(font.weight, Some(FontWeight::Number(700.0)));
+    assert_eq!(font.stretch, None);
+  }
+
+  #[test]
+  fn whitespace_is_canonicalized() {
+    assert_eq!(
+      normalize("bold   16px / 20px  Arial"),
+      "bold 16px/20px Arial"
+    );
+    assert_eq!(
+      normalize("oblique 10deg large serif"),
+      "oblique 10deg large serif"
+    );
+    assert_eq!(normalize("oblique large serif"), "oblique large serif");
+  }
+
+  #[test]
+  fn invalid_fonts() {
+    let parser = Font::parser();
+
+    assert!(parser.parse_to_end("").is_err());
+    assert!(parser.parse_to_end("Arial").is_err());
+    assert!(parser.parse_to_end("16px").is_err());
+    assert!(parser.parse_to_end("caption").is_err());
+    assert!(parser.parse_to_end("bold bold 16px Arial").is_err());
+    assert!(parser.parse_to_end("16px/ Arial").is_err());
+    assert!(
+      parser
+        .parse_to_end("normal normal normal normal normal 16px Arial")
+        .is_err()
+    );
+  }
+}
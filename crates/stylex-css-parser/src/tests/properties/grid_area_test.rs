@@ -0,0 +1,81 @@
+/*!
+Grid placement property tests.
+*/
+
+use crate::properties::grid_area::{GridArea, GridLine};
+
+#[cfg(test)]
+mod test_css_property_grid_area {
+  use super::*;
+
+  fn normalize(input: &str) -> String {
+    GridArea::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {}", input, error))
+      .to_string()
+  }
+
+  #[test]
+  fn valid_grid_lines() {
+    assert_eq!(
+      GridLine::parser().parse_to_end("auto").unwrap(),
+      GridLine::Auto
+    );
+    assert_eq!(
+      GridLine::parser().parse_to_end("-1").unwrap(),
+      GridLine::Line {
+        index: Some(-1),
+        name: None
+      }
+    );
+    assert_eq!(
+      GridLine::parser().parse_to_end("span 2").unwrap(),
+      GridLine::Span {
+        count: Some(2),
+        name: None
+      }
+    );
+  }
+
+  #[test]
+  fn grid_lines_are_printed_in_canonical_order() {
+    assert_eq!(normalize("header"), "header");
+    assert_eq!(normalize("a 2"), "2 a");
+    assert_eq!(normalize("2 span"), "span 2");
+    assert_eq!(normalize("a span 3"), "span 3 a");
+  }
+
+  #[test]
+  fn valid_grid_areas() {
+    assert_eq!(normalize("1/3"), "1 / 3");
+    assert_eq!(
+      normalize("1  /  span 2 / auto / main"),
+      "1 / span 2 / auto / main"
+    );
+    assert_eq!(
+      GridArea::line_pair_parser()
+        .parse_to_end("2/-1")
+        .unwrap()
+        .to_string(),
+      "2 / -1"
+    );
+  }
+
+  #[test]
+  fn invalid_grid_areas() {
+    let parser = GridArea::parser();
+
+    assert!(parser.parse_to_end("").is_err());
+    assert!(parser.parse_to_end("0").is_err());
+    assert!(parser.parse_to_end("span").is_err());
+    assert!(parser.parse_to_end("span -1").is_err());
+    assert!(parser.parse_to_end("1.5").is_err());
+    assert!(parser.parse_to_end("1 / 2 / 3 / 4 / 5").is_err());
+    assert!(parser.parse_to_end("1 /").is_err());
+    assert!(
+      GridArea::line_pair_parser()
+        .parse_to_end("1 / 2 / 3")
+        .is_err()
+    );
+  }
+}
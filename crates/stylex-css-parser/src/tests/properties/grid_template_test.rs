@@ -0,0 +1,167 @@
+/*!
+Grid template property tests.
+*/
+
+use crate::properties::grid_template::{
+  GridTemplateAreas, GridTemplateTracks, RepeatCount, TrackListItem,
+};
+
+#[cfg(test)]
+mod test_css_property_grid_template_tracks {
+  use super::*;
+
+  fn normalize(input: &str) -> String {
+    GridTemplateTracks::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {}", input, error))
+      .to_string()
+  }
+
+  #[test]
+  fn valid_keywords() {
+    assert_eq!(
+      GridTemplateTracks::parser().parse_to_end("none").unwrap(),
+      GridTemplateTracks::None
+    );
+    assert_eq!(normalize("subgrid"), "subgrid");
+    assert_eq!(normalize("subgrid [a]  [ b c ]"), "subgrid [a] [b c]");
+  }
+
+  #[test]
+  fn valid_track_sizes() {
+    assert_eq!(normalize("1fr 2fr"), "1fr 2fr");
+    assert_eq!(normalize("100px   auto 20%"), "100px auto 20%");
+    assert_eq!(
+      normalize("min-content max-content"),
+      "min-content max-content"
+    );
+    assert_eq!(normalize("minmax( 0 ,1fr )"), "minmax(0, 1fr)");
+    assert_eq!(
+      normalize("minmax(100px, max-content)"),
+      "minmax(100px, max-content)"
+    );
+    assert_eq!(normalize("fit-content( 40% )"), "fit-content(40%)");
+  }
+
+  #[test]
+  fn valid_line_names() {
+    assert_eq!(
+      normalize("[full-start]   1fr [  full-end ]"),
+      "[full-start] 1fr [full-end]"
+    );
+    assert_eq!(normalize("[a b] 100px [c]"), "[a b] 100px [c]");
+    assert_eq!(normalize("[] 1fr"), "[] 1fr");
+  }
+
+  #[test]
+  fn valid_repeat() {
+    assert_eq!(normalize("repeat(3,1fr)"), "repeat(3, 1fr)");
+    assert_eq!(
+      normalize("repeat( auto-fill , minmax(200px,1fr) )"),
+      "repeat(auto-fill, minmax(200px, 1fr))"
+    );
+    assert_eq!(
+      normalize("[start] repeat(2, [col] 10px) [end]"),
+      "[start] repeat(2, [col] 10px) [end]"
+    );
+
+    let tracks = GridTemplateTracks::parser()
+      .parse_to_end("repeat(auto-fit, 100px)")
+      .unwrap();
+
+    match tracks {
+      GridTemplateTracks::Tracks(items) => match &items[0] {
+        TrackListItem::Repeat(repeat) => assert_eq!(repeat.count, RepeatCount::AutoFit),
+        item => panic!("Expected a repeat, got {:?}", item),
+      },
+      tracks => panic!("Expected tracks, got {:?}", tracks),
+    }
+  }
+
+  #[test]
+  fn equivalent_declarations_normalize_to_the_same_value() {
+    assert_eq!(
+      normalize("[a   b]  repeat(2,minmax(0,1fr))"),
+      normalize("[a b] repeat(2, minmax(0, 1fr))")
+    );
+  }
+
+  #[test]
+  fn invalid_track_lists() {
+    let parser = GridTemplateTracks::parser();
+
+    assert!(parser.parse_to_end("").is_err());
+    assert!(parser.parse_to_end("[a]").is_err());
+    assert!(parser.parse_to_end("[a] [b] 1fr").is_err());
+    assert!(parser.parse_to_end("minmax(1fr, 100px)").is_err());
+    assert!(parser.parse_to_end("minmax(100px)").is_err());
+    assert!(parser.parse_to_end("repeat(0, 1fr)").is_err());
+    assert!(parser.parse_to_end("repeat(2, repeat(2, 1fr))").is_err());
+    assert!(parser.parse_to_end("fit-content(1fr)").is_err());
+    assert!(parser.parse_to_end("red").is_err());
+    assert!(parser.parse_to_end("[span] 1fr").is_err());
+  }
+}
+
+#[cfg(test)]
+mod test_css_property_grid_template_areas {
+  use super::*;
+
+  fn normalize(input: &str) -> String {
+    GridTemplateAreas::parser()
+      .parse_to_end(input)
+      .unwrap_or_else(|error| panic!("Failed to parse '{}': {}", input, error))
+      .to_string()
+  }
+
+  #[test]
+  fn valid_areas() {
+    assert_eq!(normalize("none"), "none");
+    assert_eq!(normalize("\"a\""), "\"a\"");
+    assert_eq!(
+      normalize("\"header  header\"   \"sidebar main\""),
+      "\"header header\" \"sidebar main\""
+    );
+    assert_eq!(normalize("'a a' 'b b'"), "\"a a\" \"b b\"");
+  }
+
+  #[test]
+  fn null_cells_are_collapsed() {
+    assert_eq!(normalize("\"... a\" \". a\""), "\". a\" \". a\"");
+    assert_eq!(normalize("\"a.b\""), "\"a . b\"");
+  }
+
+  #[test]
+  fn rows_must_have_the_same_number_of_cells() {
+    let error = GridTemplateAreas::from_rows(&["a b".to_string(), "c".to_string()]).unwrap_err();
+
+    assert_eq!(error, "Every row must have 2 cells, found a row with 1");
+    assert!(
+      GridTemplateAreas::parser()
+        .parse_to_end("\"a b\" \"c\"")
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn areas_must_be_rectangles() {
+    let error = GridTemplateAreas::from_rows(&["a a".to_string(), "a b".to_string()]).unwrap_err();
+
+    assert_eq!(error, "Area \"a\" is not a rectangle");
+    assert!(
+      GridTemplateAreas::parser()
+        .parse_to_end("\"a b a\"")
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn invalid_areas() {
+    let parser = GridTemplateAreas::parser();
+
+    assert!(parser.parse_to_end("").is_err());
+    assert!(parser.parse_to_end("\"\"").is_err());
+    assert!(parser.parse_to_end("\"a $\"").is_err());
+    assert!(parser.parse_to_end("a b").is_err());
+  }
+}
@@ -4,4 +4,7 @@ Properties module tests.
 
 pub mod border_radius_test;
 pub mod box_shadow_test;
+pub mod font_test;
+pub mod grid_area_test;
+pub mod grid_template_test;
 pub mod transform_test;
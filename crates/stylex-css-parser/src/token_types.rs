@@ -61,6 +61,64 @@ impl SimpleToken {
       _ => None,
     }
   }
+
+  /// Serialize the token back to CSS text
+  pub fn to_css(&self) -> String {
+    match self {
+      SimpleToken::Ident(value) => value.clone(),
+      SimpleToken::AtKeyword(value) => format!("@{}", value),
+      SimpleToken::Hash(value) => format!("#{}", value),
+      SimpleToken::String(value) => format!("\"{}\"", value),
+      SimpleToken::Number(value) => value.to_string(),
+      SimpleToken::Dimension { value, unit } => format!("{}{}", value, unit),
+      SimpleToken::Percentage(value) => format!("{}%", value * 100.0),
+      SimpleToken::Url(value) => format!("url({})", value),
+      SimpleToken::Function(name) => format!("{}(", name),
+      SimpleToken::Delim(ch) => ch.to_string(),
+      SimpleToken::LeftParen => "(".to_string(),
+      SimpleToken::RightParen => ")".to_string(),
+      SimpleToken::LeftBracket => "[".to_string(),
+      SimpleToken::RightBracket => "]".to_string(),
+      SimpleToken::LeftBrace => "{".to_string(),
+      SimpleToken::RightBrace => "}".to_string(),
+      SimpleToken::Comma => ",".to_string(),
+      SimpleToken::Semicolon => ";".to_string(),
+      SimpleToken::Colon => ":".to_string(),
+      SimpleToken::Whitespace => " ".to_string(),
+      SimpleToken::Comment(_) => String::new(),
+      SimpleToken::Unknown(value) => value.clone(),
+    }
+  }
+}
+
+/// Serialize tokens back to CSS text with normalized whitespace: runs of
+/// whitespace collapse to a single space, none is kept inside parentheses
+/// edges, and commas are always followed by one space.
+pub fn stringify_tokens(tokens: &[SimpleToken]) -> String {
+  let mut result = String::new();
+  let mut pending_space = false;
+
+  for token in tokens {
+    match token {
+      SimpleToken::Whitespace | SimpleToken::Comment(_) => {
+        pending_space = !result.is_empty();
+      }
+      SimpleToken::RightParen | SimpleToken::RightBracket | SimpleToken::Comma => {
+        result.push_str(&token.to_css());
+        pending_space = matches!(token, SimpleToken::Comma);
+      }
+      _ => {
+        if pending_space && !result.ends_with('(') && !result.ends_with('[') {
+          result.push(' ');
+        }
+
+        result.push_str(&token.to_css());
+        pending_space = false;
+      }
+    }
+  }
+
+  result
 }
 
 fn map_css_token(token: &CssToken) -> Option<SimpleToken> {
@@ -86,10 +144,10 @@ fn map_css_token(token: &CssToken) -> Option<SimpleToken> {
     CssToken::Colon => Some(T::Colon),
     CssToken::Semicolon => Some(T::Semicolon),
     CssToken::BadUrl(_) | CssToken::BadString(_) => Some(T::Unknown(format!("{:?}", token))),
-    CssToken::UnquotedUrl(url) => Some(T::String(url.as_ref().to_string())),
+    CssToken::UnquotedUrl(url) => Some(T::Url(url.as_ref().to_string())),
     CssToken::CloseParenthesis => Some(T::RightParen),
-    CssToken::SquareBracketBlock => Some(T::Delim('[')),
-    CssToken::CloseSquareBracket => Some(T::Delim(']')),
+    CssToken::SquareBracketBlock => Some(T::LeftBracket),
+    CssToken::CloseSquareBracket => Some(T::RightBracket),
     CssToken::CurlyBracketBlock => Some(T::Delim('{')),
     CssToken::CloseCurlyBracket => Some(T::Delim('}')),
     CssToken::CDC => Some(T::Delim('>')), // --> CSS comment close
@@ -121,6 +179,20 @@ fn tokenize_nested_content(parser: &mut Parser, tokens: &mut Vec<SimpleToken>) {
         // Add closing parenthesis
         tokens.push(SimpleToken::RightParen);
       }
+      // Handle nested SquareBracketBlock recursively, e.g. grid line names
+      CssToken::SquareBracketBlock => {
+        tokens.push(SimpleToken::LeftBracket);
+
+        if let Err(e) = parser.parse_nested_block(|nested_parser| {
+          tokenize_nested_content(nested_parser, tokens);
+          Ok::<(), cssparser::ParseError<()>>(())
+        }) {
+          error!("Error parsing nested content: {:?}", e);
+          panic!("Error parsing nested content: {:?}", e); // Exit on error
+        }
+
+        tokens.push(SimpleToken::RightBracket);
+      }
       // Handle nested Function tokens
       CssToken::Function(func_name) => {
         // Add the function name token
@@ -173,6 +245,20 @@ fn tokenize_all(input: &str) -> Vec<SimpleToken> {
         // Add closing paren token (cssparser consumes it automatically)
         tokens.push(SimpleToken::RightParen);
       }
+      // Handle SquareBracketBlock tokens by expanding their content, e.g. grid line names
+      CssToken::SquareBracketBlock => {
+        tokens.push(SimpleToken::LeftBracket);
+
+        if let Err(e) = parser.parse_nested_block(|nested_parser| {
+          tokenize_nested_content(nested_parser, &mut tokens);
+          Ok::<(), cssparser::ParseError<()>>(())
+        }) {
+          error!("Error parsing nested content: {:?}", e);
+          panic!("Error parsing nested content: {:?}", e); // Exit on error
+        }
+
+        tokens.push(SimpleToken::RightBracket);
+      }
       // ENHANCED: Handle ParenthesisBlock tokens by expanding their content
       CssToken::ParenthesisBlock => {
         // Add opening parenthesis
@@ -279,6 +365,34 @@ impl TokenList {
     self.tokens.clone()
   }
 
+  /// Consume tokens up to the `)` closing the current function or
+  /// parenthesized block. The closing parenthesis is consumed but not
+  /// returned.
+  pub fn consume_enclosed(&mut self) -> CssResult<Vec<SimpleToken>> {
+    let mut enclosed = Vec::new();
+    let mut depth = 0;
+
+    loop {
+      match self.consume_next_token()? {
+        Some(SimpleToken::RightParen) if depth == 0 => return Ok(enclosed),
+        Some(token) => {
+          match token {
+            SimpleToken::Function(_) | SimpleToken::LeftParen => depth += 1,
+            SimpleToken::RightParen => depth -= 1,
+            _ => {}
+          }
+
+          enclosed.push(token);
+        }
+        None => {
+          return Err(crate::CssParseError::ParseError {
+            message: "Unclosed parenthesis".to_string(),
+          });
+        }
+      }
+    }
+  }
+
   /// Get a slice of tokens from start to end index
   pub fn slice(&mut self, start: usize, end: Option<usize>) -> Vec<SimpleToken> {
     let end = end.unwrap_or(self.current_index);
@@ -0,0 +1,59 @@
+import test from 'ava';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+
+const cssRules: Array<{ cssText: string }> = [];
+
+const styleElement = {
+  sheet: {
+    cssRules,
+    insertRule(rule: string, index: number) {
+      cssRules.splice(index, 0, { cssText: rule });
+      return index;
+    },
+    deleteRule(index: number) {
+      cssRules.splice(index, 1);
+    },
+  },
+  setAttribute() {},
+};
+
+Object.assign(globalThis, {
+  document: {
+    createElement: () => styleElement,
+    head: { appendChild() {} },
+  },
+});
+
+const inject: typeof import('../hmr-inject') = require('../hmr-inject');
+
+const stylesheet = () => cssRules.map(rule => rule.cssText).join('\n');
+
+test.serial('hmr inject disposes the rules of a module', t => {
+  inject('.b{color:blue}', 3000, null, 'b');
+  inject('.a{margin:0}', 1000, null, 'a');
+  inject('.a{color:red}', 3000, null, 'a');
+  inject('.b{color:blue}', 3000, null, 'a');
+
+  t.is(stylesheet(), '.a{margin:0}\n.b{color:blue}\n.a{color:red}');
+
+  inject.dispose('a');
+
+  t.is(stylesheet(), '.b{color:blue}');
+
+  inject.dispose('b');
+
+  t.is(stylesheet(), '');
+});
+
+test.serial('hmr inject scopes rtl rules', t => {
+  inject('.c{margin-left:1px}', 3000, '.c{margin-right:1px}', 'c');
+
+  t.is(
+    stylesheet(),
+    "html:not([dir='rtl']) .c{margin-left:1px}\nhtml[dir='rtl'] .c{margin-right:1px}"
+  );
+
+  inject.dispose('c');
+});
@@ -1,6 +1,12 @@
 import test from 'ava';
 
-import { transform } from '../dist/index';
+import {
+  createShortClassNames,
+  processStylexRules,
+  renameClassNames,
+  transform,
+  transformMany,
+} from '../dist/index';
 
 test('sync function from native code', t => {
   const fixture = `
@@ -73,7 +79,170 @@ export const styles = {
       ],
     },
     map: '{"version":3,"sources":["page.tsx"],"names":[],"mappings":"AACI;AAEA;;;;;;;;EAOG"}',
+    diagnostics: [],
+    warnings: [],
   };
 
   t.deepEqual(result, expected);
 });
+
+test('processes collected rules into a stylesheet', t => {
+  const css = processStylexRules(
+    [
+      ['xju2f9n', { ltr: '.xju2f9n{color:var(--x1ha7wq9)}', rtl: null }, 3000],
+      ['x1ha7wq9', { ltr: '', rtl: null, constKey: 'x1ha7wq9', constVal: 'blue' }, 0],
+      ['xrbpyxo', { ltr: '.xrbpyxo{float:left}', rtl: '.xrbpyxo{float:right}' }, 3000],
+      ['x1ifmvib', { ltr: '.x1ifmvib{background-position:right}', rtl: null }, 2000],
+    ],
+    { useLayers: false },
+  );
+
+  t.is(
+    css,
+    [
+      '.x1ifmvib{background-position:right}',
+      '.xju2f9n:not(#\\#){color:blue}',
+      "html:not([dir='rtl']) .xrbpyxo:not(#\\#){float:left}",
+      "html[dir='rtl'] .xrbpyxo:not(#\\#){float:right}",
+    ].join('\n'),
+  );
+});
+
+test('replaces class names with short sequential ids', t => {
+  const rules: Parameters<typeof processStylexRules>[0] = [
+    ['xrbpyxo', { ltr: '.xrbpyxo{animation-name:xekv6nw-B}', rtl: null }, 3000],
+    ['xekv6nw-B', { ltr: '@keyframes xekv6nw-B{from{opacity:0;}}', rtl: null }, 0],
+    ['xju2f9n', { ltr: '.xju2f9n{color:red}', rtl: null }, 3000],
+  ];
+
+  const classNames = createShortClassNames(rules, 'x');
+
+  t.deepEqual(classNames, { 'xekv6nw-B': 'x0', xju2f9n: 'x1', xrbpyxo: 'x2' });
+  t.deepEqual(createShortClassNames([...rules].reverse(), 'x'), classNames);
+
+  t.is(
+    processStylexRules(rules, { useLayers: false, shortClassNamePrefix: 'x' }),
+    [
+      '@keyframes x0{from{opacity:0;}}',
+      '.x2:not(#\\#){animation-name:x0}',
+      '.x1:not(#\\#){color:red}',
+    ].join('\n'),
+  );
+  t.is(renameClassNames('"xrbpyxo xju2f9n"', classNames), '"x2 x1"');
+});
+
+test('transforms many files in one call', t => {
+  const options = {
+    dev: false,
+    treeshakeCompensation: true,
+    unstable_moduleResolution: {
+      type: 'commonJS',
+    },
+  };
+
+  const files = [
+    {
+      filename: 'first.tsx',
+      code: `
+        import stylex from "@stylexjs/stylex";
+        export const styles = stylex.create({ default: { color: "blue" } });
+      `,
+    },
+    {
+      filename: 'second.tsx',
+      code: `
+        import stylex from "@stylexjs/stylex";
+        export const styles = stylex.create({ default: { backgroundColor: "red" } });
+      `,
+    },
+  ];
+
+  const results = transformMany(files, options);
+
+  t.is(results.length, files.length);
+
+  results.forEach((result, index) => {
+    const { filename, code } = files[index];
+
+    t.deepEqual(result, transform(filename, code, options));
+  });
+});
+
+test('transformMany reports the failing file', t => {
+  const error = t.throws(() =>
+    transformMany([{ filename: 'broken.tsx', code: 'const = ;' }], {
+      unstable_moduleResolution: {
+        type: 'commonJS',
+      },
+    }),
+  );
+
+  t.regex(error?.message ?? '', /broken\.tsx/);
+});
+
+test('transform collects diagnostics when throwOnError is disabled', t => {
+  const code = `import * as stylex from '@stylexjs/stylex';
+export const a = stylex.create(null);
+export const b = stylex.create(42);
+`;
+
+  const result = transform('broken.tsx', code, {
+    throwOnError: false,
+    unstable_moduleResolution: {
+      type: 'commonJS',
+    },
+  });
+
+  t.is(result.code, code);
+  t.is(result.diagnostics.length, 2);
+
+  result.diagnostics.forEach(diagnostic => {
+    t.is(diagnostic.code, 'stylex/transform-error');
+    t.is(diagnostic.severity, 'error');
+    t.is(diagnostic.message, 'create() can only accept an object.');
+    t.truthy(diagnostic.span);
+    t.truthy(diagnostic.codeFrame);
+  });
+
+  t.deepEqual(
+    result.diagnostics.map(diagnostic => diagnostic.line),
+    [2, 3],
+  );
+});
+
+test('transform reports configurable warnings', t => {
+  const code = `import * as stylex from '@stylexjs/stylex';
+export const styles = stylex.create({
+  root: { marginHorizontal: 10 },
+});
+`;
+
+  const options = {
+    unstable_moduleResolution: {
+      type: 'commonJS',
+    },
+  };
+
+  const result = transform('page.tsx', code, options);
+
+  t.deepEqual(
+    result.warnings.map(warning => [warning.code, warning.severity]),
+    [['stylex/legacy-shorthand', 'warning']],
+  );
+
+  const silenced = transform('page.tsx', code, {
+    ...options,
+    warnings: { 'stylex/legacy-shorthand': 'off' },
+  });
+
+  t.deepEqual(silenced.warnings, []);
+
+  t.throws(
+    () =>
+      transform('page.tsx', code, {
+        ...options,
+        warnings: { 'stylex/legacy-shorthand': 'error' },
+      }),
+    { message: /legacy shorthand/ },
+  );
+});
@@ -0,0 +1,12 @@
+declare function inject(
+  ltr: string,
+  priority: number,
+  rtl?: string | null,
+  moduleId?: string
+): string;
+
+declare namespace inject {
+  function dispose(moduleId: string): void;
+}
+
+export = inject;
@@ -0,0 +1,172 @@
+'use strict';
+
+const stylexInject = require('@stylexjs/stylex/lib/stylex-inject');
+
+const baseInject = stylexInject.default ?? stylexInject;
+
+/**
+ * Rules injected by every hot reloadable module, keyed by the module id the
+ * compiler passes to `inject`.
+ *
+ * @type {Map<string, string[]>}
+ */
+const moduleRules = new Map();
+
+/**
+ * Priority of every injected rule and the number of modules using it, so a
+ * rule shared by several modules survives until all of them are disposed.
+ *
+ * @type {Map<string, { priority: number, count: number }>}
+ */
+const ruleRefs = new Map();
+
+/**
+ * Rules in the order of the style sheet, which is sorted by priority.
+ *
+ * @type {Array<{ rule: string, priority: number }>}
+ */
+const insertedRules = [];
+
+/** @type {HTMLStyleElement | null} */
+let styleElement = null;
+
+function getStyleSheet() {
+  if (styleElement == null) {
+    styleElement = document.createElement('style');
+    styleElement.setAttribute('data-stylex-hmr', '');
+    document.head.appendChild(styleElement);
+  }
+
+  return styleElement.sheet;
+}
+
+function addAncestorSelector(rule, ancestor) {
+  if (!rule.startsWith('@')) {
+    return `${ancestor} ${rule}`;
+  }
+
+  const blockStart = rule.indexOf('{') + 1;
+
+  return `${rule.slice(0, blockStart)}${ancestor} ${rule.slice(blockStart)}`;
+}
+
+/** Inserts a rule after every rule with the same or a lower priority. */
+function insertRule(rule, priority) {
+  let low = 0;
+  let high = insertedRules.length;
+
+  while (low < high) {
+    const middle = (low + high) >>> 1;
+
+    if (insertedRules[middle].priority <= priority) {
+      low = middle + 1;
+    } else {
+      high = middle;
+    }
+  }
+
+  try {
+    getStyleSheet().insertRule(rule, low);
+    insertedRules.splice(low, 0, { rule, priority });
+  } catch {
+    // Rules the browser does not support are skipped, like StyleX does
+  }
+}
+
+/** Rebuilds the style sheet from the rules that are still in use. */
+function rebuild() {
+  const styleSheet = getStyleSheet();
+
+  for (let index = styleSheet.cssRules.length - 1; index >= 0; index--) {
+    styleSheet.deleteRule(index);
+  }
+
+  insertedRules.length = 0;
+
+  for (const [rule, { priority }] of ruleRefs) {
+    insertRule(rule, priority);
+  }
+}
+
+/**
+ * Drop-in replacement of `@stylexjs/stylex/lib/stylex-inject` for modules
+ * compiled with `hotModuleReload`. Rules tagged with a module id are kept in
+ * their own style element, so that they can be removed with `dispose` when
+ * the module is replaced. Untagged rules and rules injected outside of a
+ * browser are forwarded to StyleX.
+ *
+ * @param {string} ltr
+ * @param {number} priority
+ * @param {string | null} [rtl]
+ * @param {string} [moduleId]
+ * @returns {string}
+ */
+function inject(ltr, priority, rtl = null, moduleId) {
+  if (moduleId == null || typeof document === 'undefined') {
+    return baseInject(ltr, priority, rtl);
+  }
+
+  const rules =
+    rtl == null
+      ? [ltr]
+      : [
+          addAncestorSelector(ltr, "html:not([dir='rtl'])"),
+          addAncestorSelector(rtl, "html[dir='rtl']"),
+        ];
+
+  const injectedRules = moduleRules.get(moduleId) ?? [];
+
+  for (const rule of rules) {
+    injectedRules.push(rule);
+
+    const refs = ruleRefs.get(rule);
+
+    if (refs != null) {
+      refs.count += 1;
+      continue;
+    }
+
+    ruleRefs.set(rule, { priority, count: 1 });
+    insertRule(rule, priority);
+  }
+
+  moduleRules.set(moduleId, injectedRules);
+
+  return ltr;
+}
+
+/**
+ * Removes the rules injected by a module, called from its hot module reload
+ * dispose handler.
+ *
+ * @param {string} moduleId
+ */
+inject.dispose = function dispose(moduleId) {
+  const rules = moduleRules.get(moduleId);
+
+  if (rules == null) {
+    return;
+  }
+
+  moduleRules.delete(moduleId);
+
+  let removed = false;
+
+  for (const rule of rules) {
+    const refs = ruleRefs.get(rule);
+
+    refs.count -= 1;
+
+    if (refs.count === 0) {
+      ruleRefs.delete(rule);
+      removed = true;
+    }
+  }
+
+  if (removed) {
+    rebuild();
+  }
+};
+
+module.exports = inject;
+module.exports.default = inject;
@@ -2,17 +2,29 @@ mod enums;
 mod structs;
 mod utils;
 use log::info;
-use napi::{Env, Result};
+use napi::{Env, JsObject, Result};
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
 use std::panic;
+use std::panic::AssertUnwindSafe;
 use std::{env, sync::Arc};
-use structs::{StyleXMetadata, StyleXOptions, StyleXTransformResult};
+use structs::{
+  ProcessStylexRulesOptions, StyleXDiagnostic, StyleXMetadata, StyleXOptions, StyleXTransformFile,
+  StyleXTransformResult,
+};
 use swc_compiler_base::{PrintArgs, SourceMapsConfig, print};
 
 use stylex_shared::{
   StyleXTransform,
   shared::{
-    structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
-    utils::log::logger,
+    structures::{
+      diagnostic::{Diagnostic, INTERNAL_ERROR_CODE, PARSE_ERROR_CODE, panic_message},
+      meta_data::MetaData,
+      plugin_pass::PluginPass,
+      state_manager::{CanonicalPathCache, CompiledModuleCache},
+      stylex_options::StyleXOptionsParams,
+    },
+    utils::{core::process_stylex_rules, log::logger},
   },
 };
 use swc_ecma_parser::{Parser, StringInput, Syntax, TsSyntax, lexer::Lexer};
@@ -28,109 +40,295 @@ use swc_core::{
 };
 
 use napi_derive::napi;
-use utils::extract_stylex_metadata;
+use utils::{extract_stylex_metadata, parse_stylex_metadata};
 
 use crate::enums::{ImportSourceUnion, SourceMaps, StyleXModuleResolution};
 
-#[napi]
-pub fn transform(
-  env: Env,
-  filename: String,
+/// Output of a single file transform, kept free of napi values so it can be
+/// produced on worker threads.
+struct TransformOutput {
   code: String,
-  options: StyleXOptions,
-) -> Result<StyleXTransformResult> {
-  color_backtrace::install();
-  logger::initialize();
+  map: Option<String>,
+  metadata: Vec<MetaData>,
+  diagnostics: Vec<Diagnostic>,
+}
 
-  info!("Transforming source file: {}", filename);
+struct TransformFailure {
+  message: String,
+  diagnostics: Vec<Diagnostic>,
+}
 
-  let result = panic::catch_unwind(|| {
-    let cm: Arc<SourceMap> = Default::default();
-    let filename = FileName::Real(filename.into());
+impl TransformFailure {
+  fn new(code: &str, message: String, file: &FileName) -> Self {
+    Self {
+      diagnostics: vec![Diagnostic::error(code, &message, Some(file.to_string()))],
+      message,
+    }
+  }
+}
 
-    let fm = cm.new_source_file(filename.clone().into(), code);
+fn transform_file(
+  filename: String,
+  code: String,
+  mut config: StyleXOptionsParams,
+  source_map: SourceMapsConfig,
+  canonical_path_cache: Option<CanonicalPathCache>,
+  compiled_module_cache: Option<CompiledModuleCache>,
+) -> std::result::Result<TransformOutput, TransformFailure> {
+  let cm: Arc<SourceMap> = Default::default();
+  let filename = FileName::Real(filename.into());
 
-    let cwd = env::current_dir()?;
+  let fm = cm.new_source_file(filename.clone().into(), code);
 
-    let plugin_pass = PluginPass {
-      cwd: Some(cwd),
-      filename: filename.clone(),
-    };
+  let cwd = env::current_dir()
+    .map_err(|error| TransformFailure::new(INTERNAL_ERROR_CODE, error.to_string(), &filename))?;
 
-    let source_map = match options.source_map.as_ref() {
-      Some(SourceMaps::True) => SourceMapsConfig::Bool(true),
-      Some(SourceMaps::False) => SourceMapsConfig::Bool(false),
-      Some(SourceMaps::Inline) => SourceMapsConfig::Str("inline".to_string()),
-      None => SourceMapsConfig::Bool(true),
-    };
+  let plugin_pass = PluginPass {
+    cwd: Some(cwd),
+    filename: filename.clone(),
+  };
 
-    let mut config: StyleXOptionsParams = options.try_into()?;
+  let mut stylex: StyleXTransform<PluginCommentsProxy> =
+    StyleXTransform::new(PluginCommentsProxy, plugin_pass, &mut config);
 
-    let mut stylex: StyleXTransform<PluginCommentsProxy> =
-      StyleXTransform::new(PluginCommentsProxy, plugin_pass, &mut config);
+  if let Some(canonical_path_cache) = canonical_path_cache {
+    stylex.state.set_canonical_path_cache(canonical_path_cache);
+  }
 
-    let mut parser = Parser::new_from(Lexer::new(
-      Syntax::Typescript(TsSyntax {
-        tsx: true,
-        ..Default::default()
-      }),
-      EsVersion::latest(),
-      StringInput::from(&*fm),
-      None,
-    ));
-
-    let program = match parser.parse_program() {
-      Ok(program) => program,
-      Err(err) => {
-        let error_message = format!("Failed to parse file `{}`: {:?}", filename, err);
-        return Err(napi::Error::from_reason(error_message));
-      }
-    };
+  if let Some(compiled_module_cache) = compiled_module_cache {
+    stylex
+      .state
+      .set_compiled_module_cache(compiled_module_cache);
+  }
+
+  let mut parser = Parser::new_from(Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    EsVersion::latest(),
+    StringInput::from(&*fm),
+    None,
+  ));
 
+  let program = parser.parse_program().map_err(|err| {
+    TransformFailure::new(
+      PARSE_ERROR_CODE,
+      format!("Failed to parse file `{}`: {:?}", filename, err),
+      &filename,
+    )
+  })?;
+
+  let result = panic::catch_unwind(AssertUnwindSafe(|| {
     let program = program
       .apply(&mut fold_pass(&mut stylex))
       .apply(&mut visit_mut_pass(fixer(None)));
 
-    let stylex_metadata = extract_stylex_metadata(env, &stylex)?;
-
-    let transformed_code = print(
+    print(
       cm,
       &program,
       PrintArgs {
         source_map,
         ..Default::default()
       },
-    );
-
-    let result = transformed_code.unwrap();
+    )
+    .unwrap()
+  }));
 
-    let js_result = StyleXTransformResult {
+  match result {
+    Ok(result) => Ok(TransformOutput {
       code: result.code,
-      metadata: StyleXMetadata {
-        stylex: stylex_metadata,
-      },
       map: result.map,
-    };
+      metadata: stylex.state.metadata.values().flatten().cloned().collect(),
+      diagnostics: stylex.state.diagnostics,
+    }),
+    Err(error) => {
+      let message = panic_message(error.as_ref())
+        .unwrap_or_else(|| "Unknown panic occurred during transformation".to_string());
 
-    Ok(js_result)
-  });
+      let mut diagnostics = stylex.state.diagnostics.clone();
 
-  match result {
-    Ok(res) => res,
-    Err(error) => {
-      let error_msg = match error.downcast_ref::<String>() {
-        Some(s) => format!("Panic occurred during transformation: {}", s),
-        None => match error.downcast_ref::<&str>() {
-          Some(s) => format!("Panic occurred during transformation: {}", s),
-          None => "Unknown panic occurred during transformation".to_string(),
-        },
-      };
-
-      Err(napi::Error::from_reason(error_msg))
+      if !stylex.state.has_errors() {
+        diagnostics.push(Diagnostic::error(
+          INTERNAL_ERROR_CODE,
+          &message,
+          Some(filename.to_string()),
+        ));
+      }
+
+      Err(TransformFailure {
+        message: format!("Panic occurred during transformation: {}", message),
+        diagnostics,
+      })
     }
   }
 }
 
+/// Turns a failed transform into the thrown error, or into a result that
+/// keeps the source untouched and only carries the diagnostics.
+fn recover_transform_failure(
+  code: String,
+  failure: TransformFailure,
+  throw_on_error: bool,
+) -> std::result::Result<TransformOutput, String> {
+  if throw_on_error {
+    return Err(failure.message);
+  }
+
+  Ok(TransformOutput {
+    code,
+    map: None,
+    metadata: vec![],
+    diagnostics: failure.diagnostics,
+  })
+}
+
+fn get_source_maps_config(options: &StyleXOptions) -> SourceMapsConfig {
+  match options.source_map.as_ref() {
+    Some(SourceMaps::True) => SourceMapsConfig::Bool(true),
+    Some(SourceMaps::False) => SourceMapsConfig::Bool(false),
+    Some(SourceMaps::Inline) => SourceMapsConfig::Str("inline".to_string()),
+    None => SourceMapsConfig::Bool(true),
+  }
+}
+
+fn into_transform_result(env: Env, output: TransformOutput) -> Result<StyleXTransformResult> {
+  let (errors, warnings): (Vec<Diagnostic>, Vec<Diagnostic>) = output
+    .diagnostics
+    .into_iter()
+    .partition(Diagnostic::is_error);
+
+  Ok(StyleXTransformResult {
+    code: output.code,
+    metadata: StyleXMetadata {
+      stylex: extract_stylex_metadata(env, &output.metadata)?,
+    },
+    map: output.map,
+    diagnostics: errors.into_iter().map(StyleXDiagnostic::from).collect(),
+    warnings: warnings.into_iter().map(StyleXDiagnostic::from).collect(),
+  })
+}
+
+#[napi]
+pub fn transform(
+  env: Env,
+  filename: String,
+  code: String,
+  options: StyleXOptions,
+) -> Result<StyleXTransformResult> {
+  color_backtrace::install();
+  logger::initialize();
+
+  info!("Transforming source file: {}", filename);
+
+  let source_map = get_source_maps_config(&options);
+  let throw_on_error = options.throw_on_error.unwrap_or(true);
+  let config: StyleXOptionsParams = options.try_into()?;
+
+  let output = transform_file(filename, code.clone(), config, source_map, None, None)
+    .or_else(|failure| recover_transform_failure(code, failure, throw_on_error))
+    .map_err(napi::Error::from_reason)?;
+
+  into_transform_result(env, output)
+}
+
+/// Transforms many files in parallel with shared options, returning the
+/// results in the same order as the input files.
+#[napi]
+pub fn transform_many(
+  env: Env,
+  files: Vec<StyleXTransformFile>,
+  options: StyleXOptions,
+) -> Result<Vec<StyleXTransformResult>> {
+  color_backtrace::install();
+  logger::initialize();
+
+  info!("Transforming {} source files", files.len());
+
+  let source_map = get_source_maps_config(&options);
+  let throw_on_error = options.throw_on_error.unwrap_or(true);
+  let config: StyleXOptionsParams = options.try_into()?;
+  let canonical_path_cache = CanonicalPathCache::default();
+  let compiled_module_cache = CompiledModuleCache::default();
+
+  let outputs = files
+    .into_par_iter()
+    .map(|file| {
+      let filename = file.filename.clone();
+
+      transform_file(
+        file.filename,
+        file.code.clone(),
+        config.clone(),
+        source_map.clone(),
+        Some(canonical_path_cache.clone()),
+        Some(compiled_module_cache.clone()),
+      )
+      .or_else(|failure| recover_transform_failure(file.code, failure, throw_on_error))
+      .map_err(|error| format!("Failed to transform `{}`: {}", filename, error))
+    })
+    .collect::<std::result::Result<Vec<TransformOutput>, String>>()
+    .map_err(napi::Error::from_reason)?;
+
+  outputs
+    .into_iter()
+    .map(|output| into_transform_result(env, output))
+    .collect()
+}
+
+#[napi]
+pub fn process_stylex_rules(
+  #[napi(
+    ts_arg_type = "([string, { ltr: string; rtl?: null | string; constKey?: string; constVal?: string | number }, number])[]"
+  )]
+  rules: Vec<JsObject>,
+  options: Option<ProcessStylexRulesOptions>,
+) -> Result<String> {
+  let metadata = parse_stylex_metadata(rules)?;
+
+  let options = options
+    .map(TryInto::try_into)
+    .transpose()?
+    .unwrap_or_default();
+
+  let result =
+    panic::catch_unwind(|| process_stylex_rules::process_stylex_rules(&metadata, &options));
+
+  result.map_err(|error| {
+    let error_msg = match error.downcast_ref::<String>() {
+      Some(s) => format!("Panic occurred during processing rules: {}", s),
+      None => match error.downcast_ref::<&str>() {
+        Some(s) => format!("Panic occurred during processing rules: {}", s),
+        None => "Unknown panic occurred during processing rules".to_string(),
+      },
+    };
+
+    napi::Error::from_reason(error_msg)
+  })
+}
+
+#[napi]
+pub fn create_short_class_names(
+  #[napi(
+    ts_arg_type = "([string, { ltr: string; rtl?: null | string; constKey?: string; constVal?: string | number }, number])[]"
+  )]
+  rules: Vec<JsObject>,
+  prefix: Option<String>,
+) -> Result<FxHashMap<String, String>> {
+  let metadata = parse_stylex_metadata(rules)?;
+
+  Ok(
+    process_stylex_rules::create_short_class_names(&metadata, prefix.as_deref().unwrap_or("x"))
+      .into_iter()
+      .collect(),
+  )
+}
+
+#[napi]
+pub fn rename_class_names(code: String, class_names: FxHashMap<String, String>) -> String {
+  process_stylex_rules::rename_class_names(&code, &class_names.into_iter().collect())
+}
+
 #[napi]
 pub fn normalize_rs_options(options: StyleXOptions) -> Result<StyleXOptions> {
   let normalized_options = StyleXOptions {
@@ -2,8 +2,12 @@ use napi::JsObject;
 use napi_derive::napi;
 use rustc_hash::FxHashMap;
 use stylex_shared::shared::structures::{
+  diagnostic::{Diagnostic, DiagnosticSeverity, WARNING_CODES, WarningLevel},
   named_import_source::{ImportSources, NamedImportSource},
-  stylex_options::{ModuleResolution, StyleResolution, StyleXOptionsParams},
+  stylex_options::{
+    self, DirectionSelector, HotModuleReload, ModuleResolution, StyleResolution,
+    StyleXOptionsParams,
+  },
 };
 
 use crate::enums::{ImportSourceUnion, SourceMaps, StyleXModuleResolution};
@@ -22,11 +26,19 @@ pub struct StyleXOptions {
   pub import_sources: Option<Vec<ImportSourceUnion>>,
   pub treeshake_compensation: Option<bool>,
   pub enable_inlined_conditional_merge: Option<bool>,
+  pub inlined_conditional_merge_limit: Option<u32>,
   pub enable_media_query_order: Option<bool>,
   pub enable_logical_styles_polyfill: Option<bool>,
   pub enable_legacy_value_flipping: Option<bool>,
   #[napi(js_name = "enableLTRRTLComments")]
   pub enable_ltr_rtl_comments: Option<bool>,
+  pub enable_value_validation: Option<bool>,
+  pub enable_color_normalization: Option<bool>,
+  pub enable_css_source_locations: Option<bool>,
+  #[napi(ts_type = "'import-meta' | 'module'")]
+  pub hot_module_reload: Option<String>,
+  pub class_name_hashing: Option<ClassNameHashing>,
+  pub module_cache_dir: Option<String>,
   pub dev: Option<bool>,
   pub test: Option<bool>,
   pub debug: Option<bool>,
@@ -39,19 +51,123 @@ pub struct StyleXOptions {
   #[napi(js_name = "unstable_moduleResolution")]
   pub unstable_module_resolution: Option<StyleXModuleResolution>,
   pub source_map: Option<SourceMaps>,
+  pub throw_on_error: Option<bool>,
+  #[napi(ts_type = "Record<string, 'off' | 'warn' | 'error'>")]
+  pub warnings: Option<FxHashMap<String, String>>,
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct ClassNameHashing {
+  pub length: Option<u32>,
+  pub alphabet: Option<String>,
+  pub salt: Option<String>,
 }
 
 #[napi(object)]
 pub struct StyleXMetadata {
-  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string }, number])[]")]
+  #[napi(
+    ts_type = "([string, { ltr: string; rtl?: null | string; source?: { file: string; line: number; column: number } }, number])[]"
+  )]
   pub stylex: Vec<JsObject>,
 }
 
+#[napi(object)]
+pub struct StyleXTransformFile {
+  pub filename: String,
+  pub code: String,
+}
+
+#[napi(object)]
+pub struct StyleXDiagnosticSpan {
+  pub start: u32,
+  pub end: u32,
+}
+
+#[napi(object)]
+pub struct StyleXDiagnostic {
+  pub code: String,
+  pub message: String,
+  #[napi(ts_type = "'error' | 'warning'")]
+  pub severity: String,
+  pub file: Option<String>,
+  pub span: Option<StyleXDiagnosticSpan>,
+  pub line: Option<u32>,
+  pub column: Option<u32>,
+  pub code_frame: Option<String>,
+  pub help: Option<String>,
+}
+
+impl From<Diagnostic> for StyleXDiagnostic {
+  fn from(diagnostic: Diagnostic) -> Self {
+    StyleXDiagnostic {
+      code: diagnostic.code,
+      message: diagnostic.message,
+      severity: match diagnostic.severity {
+        DiagnosticSeverity::Error => "error".to_string(),
+        DiagnosticSeverity::Warning => "warning".to_string(),
+      },
+      file: diagnostic.file,
+      span: diagnostic.span.map(|span| StyleXDiagnosticSpan {
+        start: span.start,
+        end: span.end,
+      }),
+      line: diagnostic.line.map(|line| line as u32),
+      column: diagnostic.column.map(|column| column as u32),
+      code_frame: diagnostic.code_frame,
+      help: diagnostic.help,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct StyleXTransformResult {
   pub code: String,
   pub metadata: StyleXMetadata,
   pub map: Option<String>,
+  pub diagnostics: Vec<StyleXDiagnostic>,
+  pub warnings: Vec<StyleXDiagnostic>,
+}
+
+#[napi(object)]
+pub struct ProcessStylexRulesOptions {
+  pub use_layers: Option<bool>,
+  #[napi(js_name = "enableLTRRTLComments")]
+  pub enable_ltr_rtl_comments: Option<bool>,
+  #[napi(ts_type = "'ancestor' | 'pseudo-class' | 'logical-only'")]
+  pub direction_selector: Option<String>,
+  pub short_class_name_prefix: Option<String>,
+}
+
+impl TryFrom<ProcessStylexRulesOptions> for stylex_options::ProcessStylexRulesOptions {
+  type Error = napi::Error;
+  fn try_from(val: ProcessStylexRulesOptions) -> Result<Self, Self::Error> {
+    let direction_selector: Option<DirectionSelector> = val
+      .direction_selector
+      .map(|ds| {
+        serde_plain::from_str(&ds).map_err(|e| {
+          napi::Error::from_reason(format!("Failed to parse direction selector: {}", e))
+        })
+      })
+      .transpose()?;
+
+    Ok(stylex_options::ProcessStylexRulesOptions {
+      use_layers: val.use_layers.unwrap_or(false),
+      enable_ltr_rtl_comments: val.enable_ltr_rtl_comments.unwrap_or(false),
+      direction_selector: direction_selector.unwrap_or_default(),
+      short_class_name_prefix: val.short_class_name_prefix,
+    })
+  }
+}
+
+impl From<ClassNameHashing> for stylex_options::ClassNameHashing {
+  fn from(val: ClassNameHashing) -> Self {
+    stylex_options::ClassNameHashing {
+      length: val.length.map(|length| length as usize),
+      alphabet: val.alphabet,
+      salt: val.salt,
+    }
+  }
 }
 
 impl TryFrom<StyleXOptions> for StyleXOptionsParams {
@@ -65,6 +181,15 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
       })
       .transpose()?;
 
+    let hot_module_reload: Option<HotModuleReload> = val
+      .hot_module_reload
+      .map(|hmr| {
+        serde_plain::from_str(&hmr).map_err(|e| {
+          napi::Error::from_reason(format!("Failed to parse hot module reload: {}", e))
+        })
+      })
+      .transpose()?;
+
     let import_sources: Option<Vec<ImportSources>> = val.import_sources.map(|import_sources| {
       import_sources
         .into_iter()
@@ -78,6 +203,33 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
         .collect()
     });
 
+    let warnings: Option<FxHashMap<String, WarningLevel>> = val
+      .warnings
+      .map(|warnings| {
+        warnings
+          .into_iter()
+          .map(|(code, level)| {
+            if !WARNING_CODES.contains(&code.as_str()) {
+              return Err(napi::Error::from_reason(format!(
+                "Unknown warning code `{}`. Expected one of: {}",
+                code,
+                WARNING_CODES.join(", ")
+              )));
+            }
+
+            let level = serde_plain::from_str(&level).map_err(|e| {
+              napi::Error::from_reason(format!(
+                "Failed to parse warning level of `{}`: {}",
+                code, e
+              ))
+            })?;
+
+            Ok((code, level))
+          })
+          .collect()
+      })
+      .transpose()?;
+
     let unstable_module_resolution = val.unstable_module_resolution.map(|res| ModuleResolution {
       r#type: res.r#type,
       root_dir: res.root_dir,
@@ -93,10 +245,19 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
       import_sources,
       treeshake_compensation: val.treeshake_compensation,
       enable_inlined_conditional_merge: val.enable_inlined_conditional_merge,
+      inlined_conditional_merge_limit: val
+        .inlined_conditional_merge_limit
+        .map(|limit| limit as usize),
       enable_media_query_order: val.enable_media_query_order,
       enable_logical_styles_polyfill: val.enable_logical_styles_polyfill,
       enable_legacy_value_flipping: val.enable_legacy_value_flipping,
       enable_ltr_rtl_comments: val.enable_ltr_rtl_comments,
+      enable_value_validation: val.enable_value_validation,
+      enable_color_normalization: val.enable_color_normalization,
+      enable_css_source_locations: val.enable_css_source_locations,
+      hot_module_reload,
+      class_name_hashing: val.class_name_hashing.map(Into::into),
+      module_cache_dir: val.module_cache_dir,
       dev: val.dev,
       test: val.test,
       debug: val.debug.or(val.dev),
@@ -105,6 +266,7 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
       enable_dev_class_names: val.enable_dev_class_names,
       enable_minified_keys: val.enable_minified_keys,
       aliases: val.aliases,
+      warnings,
       unstable_module_resolution,
     })
   }
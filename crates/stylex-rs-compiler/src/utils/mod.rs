@@ -1,45 +1,56 @@
-use napi::{Env, Error, JsObject};
-use stylex_shared::{
-  StyleXTransform, shared::enums::data_structures::injectable_style::InjectableStyleBaseKind,
+use napi::{Env, Error, JsNumber, JsObject, JsString, JsUnknown, ValueType};
+use stylex_shared::shared::{
+  enums::data_structures::injectable_style::InjectableStyleBaseKind,
+  structures::{
+    injectable_style::{InjectableStyleBase, InjectableStyleConstBase},
+    meta_data::MetaData,
+  },
 };
-use swc_core::plugin::proxies::PluginCommentsProxy;
 
 pub(crate) fn extract_stylex_metadata(
   env: Env,
-  stylex: &StyleXTransform<PluginCommentsProxy>,
+  metadata: &[MetaData],
 ) -> Result<Vec<JsObject>, Error> {
-  let mut stylex_metadata = Vec::with_capacity(stylex.state.metadata.len());
-
-  for value in stylex.state.metadata.values() {
-    for meta in value {
-      let mut metadata_value = env.create_array_with_length(3)?;
-
-      metadata_value.set_element(0, env.create_string(meta.get_class_name())?)?;
-
-      let mut style_value = env.create_object()?;
-      let styles = meta.get_style();
-
-      match styles {
-        InjectableStyleBaseKind::Regular(styles) => {
-          set_metadata_ltr_and_rtl(env, &mut style_value, &styles.ltr, &styles.rtl, None, None)?;
-        }
-        InjectableStyleBaseKind::Const(styles) => {
-          set_metadata_ltr_and_rtl(
-            env,
-            &mut style_value,
-            &styles.ltr,
-            &styles.rtl,
-            Some(&styles.const_key),
-            Some(&styles.const_value),
-          )?;
-        }
+  let mut stylex_metadata = Vec::with_capacity(metadata.len());
+
+  for meta in metadata {
+    let mut metadata_value = env.create_array_with_length(3)?;
+
+    metadata_value.set_element(0, env.create_string(meta.get_class_name())?)?;
+
+    let mut style_value = env.create_object()?;
+    let styles = meta.get_style();
+
+    match styles {
+      InjectableStyleBaseKind::Regular(styles) => {
+        set_metadata_ltr_and_rtl(env, &mut style_value, &styles.ltr, &styles.rtl, None, None)?;
+      }
+      InjectableStyleBaseKind::Const(styles) => {
+        set_metadata_ltr_and_rtl(
+          env,
+          &mut style_value,
+          &styles.ltr,
+          &styles.rtl,
+          Some(&styles.const_key),
+          Some(&styles.const_value),
+        )?;
       }
+    }
+
+    if let Some(source) = meta.get_source() {
+      let mut source_value = env.create_object()?;
 
-      metadata_value.set_element(1, style_value)?;
-      metadata_value.set_element(2, env.create_double(*meta.get_priority())?)?;
+      source_value.set_named_property("file", env.create_string(&source.file)?)?;
+      source_value.set_named_property("line", env.create_uint32(source.line as u32)?)?;
+      source_value.set_named_property("column", env.create_uint32(source.column as u32)?)?;
 
-      stylex_metadata.push(metadata_value);
+      style_value.set_named_property("source", source_value)?;
     }
+
+    metadata_value.set_element(1, style_value)?;
+    metadata_value.set_element(2, env.create_double(*meta.get_priority())?)?;
+
+    stylex_metadata.push(metadata_value);
   }
 
   Ok(stylex_metadata)
@@ -74,3 +85,44 @@ fn set_metadata_ltr_and_rtl(
 
   Ok(())
 }
+
+pub(crate) fn parse_stylex_metadata(rules: Vec<JsObject>) -> Result<Vec<MetaData>, Error> {
+  let mut metadata = Vec::with_capacity(rules.len());
+
+  for rule in rules {
+    let class_name = rule
+      .get_element::<JsString>(0)?
+      .into_utf8()?
+      .as_str()?
+      .to_owned();
+    let style_value = rule.get_element::<JsObject>(1)?;
+    let priority = rule.get_element::<JsNumber>(2)?.get_double()?;
+
+    let ltr = style_value.get_named_property::<String>("ltr")?;
+    let rtl = style_value.get_named_property::<Option<String>>("rtl")?;
+    let const_key = style_value.get_named_property::<Option<String>>("constKey")?;
+    let const_value = style_value.get_named_property::<JsUnknown>("constVal")?;
+
+    let style = match (const_key, const_value.get_type()?) {
+      (Some(const_key), value_type)
+        if value_type != ValueType::Undefined && value_type != ValueType::Null =>
+      {
+        InjectableStyleBaseKind::Const(InjectableStyleConstBase {
+          ltr,
+          rtl,
+          const_key,
+          const_value: const_value
+            .coerce_to_string()?
+            .into_utf8()?
+            .as_str()?
+            .to_owned(),
+        })
+      }
+      _ => InjectableStyleBaseKind::Regular(InjectableStyleBase { ltr, rtl }),
+    };
+
+    metadata.push(MetaData::from_style(class_name, style, priority));
+  }
+
+  Ok(metadata)
+}
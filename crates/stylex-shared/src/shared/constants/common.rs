@@ -1,6 +1,12 @@
 pub(crate) static DEFAULT_INJECT_PATH: &str = "@stylexjs/stylex/lib/stylex-inject";
+/// Inject wrapper shipped with the compiler that can dispose the rules of a
+/// hot reloaded module.
+pub(crate) static HOT_MODULE_RELOAD_INJECT_PATH: &str = "@toss/stylexswc-rs-compiler/hmr-inject";
+/// Maximum number of runtime conditions a `props()` call may depend on before
+/// falling back to runtime merging; each condition doubles the precomputed entries.
+pub(crate) const DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT: usize = 4;
 use once_cell::sync::Lazy;
-use phf::phf_set;
+use phf::{phf_map, phf_set};
 
 // Using MDN data as a source of truth to populate the above sets
 // by group in alphabetical order:
@@ -74,8 +80,8 @@ pub(crate) static CSS_CONTENT_KEYWORDS: Lazy<[&str; 11]> = Lazy::new(|| {
   ]
 });
 
-// TODO: Once we have a reliable validator, these property checks should be replaced with
-// validators that can also validate the values.
+// Only restricts which properties are allowed. Their values are checked by
+// `validators::property_values` when value validation is enabled.
 pub(crate) static VALID_POSITION_TRY_PROPERTIES: Lazy<[&str; 40]> = Lazy::new(|| {
   [
     // anchor Properties
@@ -130,3 +136,34 @@ pub(crate) static VALID_POSITION_TRY_PROPERTIES: Lazy<[&str; 40]> = Lazy::new(||
 // Validation of `stylex.viewTransitionClass` function call
 pub(crate) static VALID_VIEW_TRANSITION_CLASS_PROPERTIES: Lazy<[&str; 4]> =
   Lazy::new(|| ["group", "imagePair", "old", "new"]);
+
+/// Non-standard property aliases that are still accepted under
+/// `property-specificity`, with the standard property to use instead.
+pub(crate) static LEGACY_SHORTHAND_REPLACEMENTS: phf::Map<&'static str, &'static str> = phf_map! {
+  "borderHorizontalWidth" => "borderInlineWidth",
+  "borderHorizontalStyle" => "borderInlineStyle",
+  "borderHorizontalColor" => "borderInlineColor",
+  "borderVerticalWidth" => "borderBlockWidth",
+  "borderVerticalStyle" => "borderBlockStyle",
+  "borderVerticalColor" => "borderBlockColor",
+  "borderStartColor" => "borderInlineStartColor",
+  "borderEndColor" => "borderInlineEndColor",
+  "borderStartStyle" => "borderInlineStartStyle",
+  "borderEndStyle" => "borderInlineEndStyle",
+  "borderStartWidth" => "borderInlineStartWidth",
+  "borderEndWidth" => "borderInlineEndWidth",
+  "borderTopStartRadius" => "borderStartStartRadius",
+  "borderTopEndRadius" => "borderStartEndRadius",
+  "borderBottomStartRadius" => "borderEndStartRadius",
+  "borderBottomEndRadius" => "borderEndEndRadius",
+  "marginStart" => "marginInlineStart",
+  "marginEnd" => "marginInlineEnd",
+  "marginHorizontal" => "marginInline",
+  "marginVertical" => "marginBlock",
+  "paddingStart" => "paddingInlineStart",
+  "paddingEnd" => "paddingInlineEnd",
+  "paddingHorizontal" => "paddingInline",
+  "paddingVertical" => "paddingBlock",
+  "start" => "insetInlineStart",
+  "end" => "insetInlineEnd",
+};
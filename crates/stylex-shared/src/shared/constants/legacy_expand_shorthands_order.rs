@@ -1,3 +1,5 @@
+use stylex_css_parser::properties::Font;
+
 use crate::shared::{structures::order_pair::OrderPair, utils::css::common::split_value_required};
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -150,6 +152,49 @@ impl Shorthands {
     ]
   }
 
+  fn font(raw_value: Option<String>) -> Vec<OrderPair> {
+    let longhands = [
+      "fontStyle",
+      "fontVariant",
+      "fontWeight",
+      "fontStretch",
+      "fontSize",
+      "lineHeight",
+      "fontFamily",
+    ];
+
+    let Some(value) = raw_value.as_deref() else {
+      return longhands
+        .into_iter()
+        .map(|longhand| OrderPair(longhand.into(), None))
+        .collect();
+    };
+
+    // System fonts, variables and CSS-wide keywords can't be expanded
+    let Ok(font) = Font::parser().parse_to_end(value) else {
+      return vec![OrderPair("font".into(), raw_value)];
+    };
+
+    // Longhands omitted from the shorthand are reset to their initial value
+    let or_normal = |value: Option<String>| Some(value.unwrap_or_else(|| "normal".into()));
+
+    let values = [
+      or_normal(font.style.map(|style| style.to_string())),
+      or_normal(font.variant),
+      or_normal(font.weight.map(|weight| weight.to_string())),
+      or_normal(font.stretch),
+      Some(font.size.to_string()),
+      or_normal(font.line_height.map(|line_height| line_height.to_string())),
+      Some(font.family.to_string()),
+    ];
+
+    longhands
+      .into_iter()
+      .zip(values)
+      .map(|(longhand, value)| OrderPair(longhand.into(), value))
+      .collect()
+  }
+
   fn inset(raw_value: Option<String>) -> Vec<OrderPair> {
     let (top, right, bottom, left) = split_value_required(raw_value.as_deref());
 
@@ -355,6 +400,7 @@ impl Shorthands {
       "borderVerticalWidth" => Some(Shorthands::border_vertical_width),
       "borderRadius" => Some(Shorthands::border_radius),
       "containIntrinsicSize" => Some(Shorthands::contain_intrinsic_size),
+      "font" => Some(Shorthands::font),
       "inset" => Some(Shorthands::inset),
       "insetInline" => Some(Shorthands::inset_inline),
       "insetBlock" => Some(Shorthands::inset_block),
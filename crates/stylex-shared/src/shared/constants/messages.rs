@@ -25,6 +25,24 @@ pub fn non_export_named_declaration(fn_name: &str) -> String {
   )
 }
 
+pub fn invalid_css_type_value(key: &str, syntax: &str, value: &str) -> String {
+  format!(
+    r#"Value "{}" of "{}" does not match the syntax "{}"."#,
+    value, key, syntax
+  )
+}
+
+pub fn invalid_property_value(property: &str, value: &str) -> String {
+  format!(r#"Invalid value "{}" for property "{}"."#, value, property)
+}
+
+pub fn invalid_grid_template_areas(value: &str) -> String {
+  format!(
+    r#"Invalid gridTemplateAreas value "{}". Every row must have the same number of cells and every named area must be a rectangle."#,
+    value
+  )
+}
+
 pub fn unbound_call_value(fn_name: &str) -> String {
   format!("{}() calls must be bound to a bare variable.", fn_name)
 }
@@ -36,6 +54,27 @@ pub fn cannot_generate_hash(fn_name: &str) -> String {
   )
 }
 
+pub fn legacy_shorthand(property: &str, replacement: &str) -> String {
+  format!(
+    "`{}` is a legacy shorthand. Use the standard `{}` property instead.",
+    property, replacement
+  )
+}
+
+pub fn font_size_px_to_rem(value: &str, converted: &str) -> String {
+  format!(
+    "fontSize value `{}` was converted to `{}`.",
+    value, converted
+  )
+}
+
+pub fn unused_namespace(var_name: &str, namespace: &str) -> String {
+  format!(
+    "Namespace `{}.{}` is never used and was removed from the output.",
+    var_name, namespace
+  )
+}
+
 // Static constants
 pub static DUPLICATE_CONDITIONAL: &str =
   "The same pseudo selector or at-rule cannot be used more than once.";
@@ -89,3 +128,12 @@ pub(crate) static POSITION_TRY_INVALID_PROPERTY: &str = "Invalid property in `po
 pub(crate) static VIEW_TRANSITION_CLASS_INVALID_PROPERTY: &str = "Invalid property in `viewTransitionClass()` call. It may only contain group, imagePair, old, and new properties";
 
 pub(crate) static INVALID_MEDIA_QUERY_SYNTAX: &str = "Invalid media query syntax.";
+
+pub(crate) static INVALID_CONTAINER_QUERY_SYNTAX: &str = "Invalid container query syntax.";
+
+pub(crate) static INVALID_SUPPORTS_CONDITION_SYNTAX: &str = "Invalid @supports condition syntax.";
+
+pub(crate) static CIRCULAR_CONST_REFERENCE: &str = "Circular reference detected in constants.";
+
+pub(crate) static INVALID_WHEN_PSEUDO: &str =
+  "Pseudo selector passed to when.* must start with \":\" and cannot be a pseudo-element.";
@@ -71,6 +71,14 @@ pub(crate) static AT_RULE_PRIORITIES: phf::Map<&'static str, &'static f64> = phf
 
 pub(crate) static PSEUDO_ELEMENT_PRIORITY: f64 = 5000.0;
 
+pub(crate) static RELATIONAL_SELECTOR_PRIORITIES: phf::Map<&'static str, &'static f64> = phf_map! {
+  "ancestor" => &10.0,
+  "descendant" => &15.0,
+  "anySibling" => &20.0,
+  "siblingBefore" => &30.0,
+  "siblingAfter" => &40.0,
+};
+
 pub(crate) static CAMEL_CASE_PRIORITIES: phf::Map<&'static str, &'static str> = phf_map! {
   "translatex" => "translateX",
   "translatey" => "translateY",
@@ -1,6 +1,16 @@
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone, Hash, Copy)]
+use stylex_css_parser::{
+  css_types::{
+    Angle, Color, CssVariable, CustomIdentifier, Length, LengthPercentage, Number, Percentage,
+    Resolution, Time, TransformFunction,
+  },
+  token_parser::tokens,
+};
+
+use crate::shared::utils::css::validators::property_values::split_top_level;
+
+#[derive(Debug, PartialEq, Clone, Hash)]
 pub enum CSSSyntax {
   Length,
   Number,
@@ -15,11 +25,88 @@ pub enum CSSSyntax {
   Resolution,
   TransformFunction,
   TransformList,
+  CustomIdent,
+  String,
+  /// `*`, any valid token sequence
+  Universal,
+  /// `<syntax>+`, a space separated list of one or more values
+  List(Box<CSSSyntax>),
+  /// `<syntax> | <syntax>`, any one of the alternatives
+  Union(Vec<CSSSyntax>),
+}
+
+impl CSSSyntax {
+  /// Syntaxes that can be written as a single `<name>` component
+  fn is_component(&self) -> bool {
+    !matches!(
+      self,
+      CSSSyntax::Universal | CSSSyntax::List(_) | CSSSyntax::Union(_)
+    )
+  }
+
+  /// The syntax as written in an `@property` rule, using the CSS names of
+  /// the data types rather than the `stylex.types` ones.
+  pub(crate) fn to_property_syntax(&self) -> String {
+    match self {
+      CSSSyntax::LengthPercentage => "<length-percentage>".to_string(),
+      CSSSyntax::TransformFunction => "<transform-function>".to_string(),
+      CSSSyntax::TransformList => "<transform-list>".to_string(),
+      CSSSyntax::List(syntax) => format!("{}+", syntax.to_property_syntax()),
+      CSSSyntax::Union(syntaxes) => syntaxes
+        .iter()
+        .map(CSSSyntax::to_property_syntax)
+        .collect::<Vec<_>>()
+        .join(" | "),
+      _ => self.to_string(),
+    }
+  }
+
+  /// Checks that `value` matches the syntax using the `stylex-css-parser` types.
+  /// References to other variables are accepted for every syntax.
+  pub(crate) fn is_valid_value(&self, value: &str) -> bool {
+    let value = value.trim();
+
+    if CssVariable::parser().parse_to_end(value).is_ok() {
+      return true;
+    }
+
+    match self {
+      CSSSyntax::Universal => !value.is_empty(),
+      CSSSyntax::List(syntax) => {
+        let items = split_top_level(value, char::is_whitespace);
+
+        !items.is_empty() && items.iter().all(|item| syntax.is_valid_value(item))
+      }
+      CSSSyntax::Union(syntaxes) => syntaxes.iter().any(|syntax| syntax.is_valid_value(value)),
+      CSSSyntax::Length => Length::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::Number => Number::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::Integer => value.parse::<i64>().is_ok(),
+      CSSSyntax::Percentage => Percentage::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::LengthPercentage => LengthPercentage::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::Color => Color::parse().parse_to_end(value).is_ok(),
+      CSSSyntax::Angle => Angle::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::Time => Time::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::Resolution => Resolution::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::TransformFunction => TransformFunction::parse().parse_to_end(value).is_ok(),
+      CSSSyntax::TransformList => {
+        let items = split_top_level(value, char::is_whitespace);
+
+        !items.is_empty()
+          && items
+            .iter()
+            .all(|item| TransformFunction::parse().parse_to_end(item).is_ok())
+      }
+      CSSSyntax::CustomIdent => CustomIdentifier::parser().parse_to_end(value).is_ok(),
+      CSSSyntax::String => tokens::string().parse_to_end(value).is_ok(),
+      CSSSyntax::Url => value.starts_with("url(") && value.ends_with(')'),
+      CSSSyntax::Image => !value.is_empty(),
+    }
+  }
 }
 
 impl fmt::Display for CSSSyntax {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    match *self {
+    match self {
       CSSSyntax::Angle => write!(f, "<angle>"),
       CSSSyntax::Color => write!(f, "<color>"),
       CSSSyntax::Image => write!(f, "<image>"),
@@ -33,26 +120,76 @@ impl fmt::Display for CSSSyntax {
       CSSSyntax::TransformFunction => write!(f, "<transformFunction>"),
       CSSSyntax::TransformList => write!(f, "<transformList>"),
       CSSSyntax::Url => write!(f, "<url>"),
+      CSSSyntax::CustomIdent => write!(f, "<custom-ident>"),
+      CSSSyntax::String => write!(f, "<string>"),
+      CSSSyntax::Universal => write!(f, "*"),
+      CSSSyntax::List(syntax) => write!(f, "{}+", syntax),
+      CSSSyntax::Union(syntaxes) => write!(
+        f,
+        "{}",
+        syntaxes
+          .iter()
+          .map(|syntax| syntax.to_string())
+          .collect::<Vec<_>>()
+          .join(" | ")
+      ),
     }
   }
 }
 
 impl From<String> for CSSSyntax {
   fn from(value: String) -> Self {
-    match value.as_str() {
+    let value = value.trim();
+
+    if value == "*" {
+      return CSSSyntax::Universal;
+    }
+
+    if value.contains('|') {
+      let syntaxes = value
+        .split('|')
+        .map(|syntax| CSSSyntax::from(syntax.to_string()))
+        .collect::<Vec<_>>();
+
+      assert!(
+        syntaxes
+          .iter()
+          .all(|syntax| !matches!(syntax, CSSSyntax::Universal | CSSSyntax::Union(_))),
+        r#"CSSSyntax "{}" cannot combine "*" with other syntaxes"#,
+        value
+      );
+
+      return CSSSyntax::Union(syntaxes);
+    }
+
+    if let Some(syntax) = value.strip_suffix('+') {
+      let syntax = CSSSyntax::from(syntax.to_string());
+
+      assert!(
+        syntax.is_component(),
+        r#"CSSSyntax "{}" can only repeat a single component"#,
+        value
+      );
+
+      return CSSSyntax::List(Box::new(syntax));
+    }
+
+    match value {
       "<angle>" => CSSSyntax::Angle,
       "<color>" => CSSSyntax::Color,
       "<image>" => CSSSyntax::Image,
       "<integer>" => CSSSyntax::Integer,
       "<length>" => CSSSyntax::Length,
-      "<lengthPercentage>" => CSSSyntax::LengthPercentage,
+      "<lengthPercentage>" | "<length-percentage>" => CSSSyntax::LengthPercentage,
       "<number>" => CSSSyntax::Number,
       "<percentage>" => CSSSyntax::Percentage,
       "<resolution>" => CSSSyntax::Resolution,
       "<time>" => CSSSyntax::Time,
-      "<transformFunction>" => CSSSyntax::TransformFunction,
-      "<transformList>" => CSSSyntax::TransformList,
+      "<transformFunction>" | "<transform-function>" => CSSSyntax::TransformFunction,
+      "<transformList>" | "<transform-list>" => CSSSyntax::TransformList,
       "<url>" => CSSSyntax::Url,
+      "<custom-ident>" => CSSSyntax::CustomIdent,
+      "<string>" => CSSSyntax::String,
       _ => panic!(r#"CSSSyntax "{}" not found"#, value),
     }
   }
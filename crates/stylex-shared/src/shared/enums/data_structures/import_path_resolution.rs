@@ -7,5 +7,5 @@ pub(crate) enum ImportPathResolution {
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum ImportPathResolutionType {
   ThemeNameRef,
-  // FilePath,
+  FilePath,
 }
@@ -60,3 +60,11 @@ pub(crate) static JSON_REGEX: Lazy<Regex> =
 
 pub static NPM_NAME_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"^(?:@[a-z0-9][a-z0-9._-]*\/)?[a-z0-9][a-z0-9._-]*$").unwrap());
+
+pub(crate) static CONST_VAR_REFERENCE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"var\((--[A-Za-z0-9_-]+)\)").unwrap());
+
+pub(crate) static THEME_ROOT_SELECTOR_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\.([a-zA-Z0-9]+), \.([a-zA-Z0-9]+):root").unwrap());
+
+pub(crate) static CSS_IDENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w-]+").unwrap());
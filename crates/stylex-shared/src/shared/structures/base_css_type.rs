@@ -26,6 +26,44 @@ pub(crate) struct BaseCSSType {
 }
 
 impl BaseCSSType {
+  /// Finds the first value that does not parse as the declared syntax,
+  /// together with the at-rules it is defined for.
+  pub(crate) fn find_invalid_value(&self) -> Option<(Vec<String>, String)> {
+    fn find(
+      syntax: &CSSSyntax,
+      value: &ValueWithDefault,
+      at_rules: &mut Vec<String>,
+    ) -> Option<(Vec<String>, String)> {
+      match value {
+        ValueWithDefault::Map(map) => map.iter().find_map(|(key, value)| {
+          let is_at_rule = key != "default";
+
+          if is_at_rule {
+            at_rules.push(key.clone());
+          }
+
+          let invalid_value = find(syntax, value, at_rules);
+
+          if is_at_rule {
+            at_rules.pop();
+          }
+
+          invalid_value
+        }),
+        ValueWithDefault::String(value) => {
+          (!syntax.is_valid_value(value)).then(|| (at_rules.clone(), value.clone()))
+        }
+        ValueWithDefault::Number(number) => {
+          let value = number.to_string();
+
+          (!syntax.is_valid_value(&value)).then(|| (at_rules.clone(), value))
+        }
+      }
+    }
+
+    find(&self.syntax, &self.value, &mut vec![])
+  }
+
   pub(crate) fn value_to_props(
     value: ValueWithDefault,
     top_key: Option<String>,
@@ -0,0 +1,128 @@
+use std::any::Any;
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::regex::URL_REGEX;
+
+/// Code of errors raised while transforming StyleX calls
+pub const TRANSFORM_ERROR_CODE: &str = "stylex/transform-error";
+/// Code of errors raised without source location
+pub const INTERNAL_ERROR_CODE: &str = "stylex/internal-error";
+/// Code of errors raised when the source file cannot be parsed
+pub const PARSE_ERROR_CODE: &str = "stylex/parse-error";
+
+/// Code of warnings for non-standard property aliases such as `marginHorizontal`
+pub const LEGACY_SHORTHAND_WARNING_CODE: &str = "stylex/legacy-shorthand";
+/// Code of warnings for `px` font sizes converted to `rem`
+pub const FONT_SIZE_PX_TO_REM_WARNING_CODE: &str = "stylex/font-size-px-to-rem";
+/// Code of warnings for `create()` namespaces that are never used
+pub const UNUSED_NAMESPACE_WARNING_CODE: &str = "stylex/unused-namespace";
+
+pub const WARNING_CODES: [&str; 3] = [
+  LEGACY_SHORTHAND_WARNING_CODE,
+  FONT_SIZE_PX_TO_REM_WARNING_CODE,
+  UNUSED_NAMESPACE_WARNING_CODE,
+];
+
+/// How a warning code is reported
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WarningLevel {
+  Off,
+  Warn,
+  Error,
+}
+
+impl WarningLevel {
+  /// Level used when the code is not configured in the options
+  pub fn default_for(code: &str) -> Self {
+    match code {
+      FONT_SIZE_PX_TO_REM_WARNING_CODE => WarningLevel::Off,
+      _ => WarningLevel::Warn,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticSeverity {
+  Error,
+  Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DiagnosticSpan {
+  pub start: u32,
+  pub end: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+  pub code: String,
+  pub message: String,
+  pub severity: DiagnosticSeverity,
+  pub file: Option<String>,
+  pub span: Option<DiagnosticSpan>,
+  pub line: Option<usize>,
+  pub column: Option<usize>,
+  pub code_frame: Option<String>,
+  pub help: Option<String>,
+}
+
+impl Diagnostic {
+  pub fn error(code: &str, message: &str, file: Option<String>) -> Self {
+    Self {
+      code: code.to_string(),
+      message: message.to_string(),
+      severity: DiagnosticSeverity::Error,
+      file,
+      span: None,
+      line: None,
+      column: None,
+      code_frame: None,
+      help: help_from_message(message),
+    }
+  }
+
+  pub fn warning(code: &str, message: &str, file: Option<String>) -> Self {
+    Self {
+      severity: DiagnosticSeverity::Warning,
+      ..Self::error(code, message, file)
+    }
+  }
+
+  pub fn is_error(&self) -> bool {
+    self.severity == DiagnosticSeverity::Error
+  }
+
+  /// Whether both entries describe the same error at the same place
+  pub(crate) fn is_same_as(&self, other: &Diagnostic) -> bool {
+    self.code == other.code
+      && self.message == other.message
+      && self.file == other.file
+      && self.span == other.span
+  }
+}
+
+/// Documentation links found in a message are surfaced as the help text
+fn help_from_message(message: &str) -> Option<String> {
+  let urls = URL_REGEX
+    .find_iter(message)
+    .map(|m| m.as_str())
+    .collect::<Vec<_>>();
+
+  if urls.is_empty() {
+    None
+  } else {
+    Some(urls.join("\n"))
+  }
+}
+
+/// Extracts the message of a caught panic
+pub fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+  payload
+    .downcast_ref::<String>()
+    .cloned()
+    .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+}
@@ -29,6 +29,7 @@ pub enum FunctionType {
   StylexExprFn(fn(Expr, &mut StateManager) -> Expr),
   StylexTypeFn(StylexTypeFn),
   StylexFnsFactory(fn(input: String) -> StylexTypeFn),
+  StylexExprFnsFactory(fn(input: String) -> fn(Expr, &mut StateManager) -> Expr),
 
   Mapper(Rc<dyn Fn() -> Expr + 'static>),
   Callback(Box<CallbackType>),
@@ -41,6 +42,7 @@ impl Clone for FunctionType {
       Self::StylexExprFn(e) => Self::StylexExprFn(*e),
       Self::StylexTypeFn(e) => Self::StylexTypeFn(e.clone()),
       Self::StylexFnsFactory(e) => Self::StylexFnsFactory(*e),
+      Self::StylexExprFnsFactory(e) => Self::StylexExprFnsFactory(*e),
       Self::Callback(v) => Self::Callback(v.clone()),
       Self::Mapper(c) => Self::Mapper(Rc::clone(c)),
     }
@@ -54,6 +56,7 @@ impl std::fmt::Debug for FunctionType {
       FunctionType::StylexExprFn(_) => write!(f, "StylexExprWithStateFn"),
       FunctionType::StylexTypeFn(_) => write!(f, "StylexExprFn"),
       FunctionType::StylexFnsFactory(_) => write!(f, "StylexFnsFactory"),
+      FunctionType::StylexExprFnsFactory(_) => write!(f, "StylexExprFnsFactory"),
       FunctionType::Mapper(_) => write!(f, "Mapper"),
       FunctionType::Callback(_) => write!(f, "Callback"),
     }
@@ -67,6 +70,7 @@ impl PartialEq for FunctionType {
       (FunctionType::StylexExprFn(_), FunctionType::StylexExprFn(_)) => false,
       (FunctionType::StylexTypeFn(_), FunctionType::StylexTypeFn(_)) => false,
       (FunctionType::StylexFnsFactory(_), FunctionType::StylexFnsFactory(_)) => false,
+      (FunctionType::StylexExprFnsFactory(_), FunctionType::StylexExprFnsFactory(_)) => false,
       (FunctionType::Mapper(_), FunctionType::StylexExprFn(_)) => false,
       (FunctionType::Callback(_), FunctionType::Callback(_)) => false,
       _ => false,
@@ -89,6 +93,9 @@ impl std::hash::Hash for FunctionType {
       FunctionType::StylexFnsFactory(_) => {
         std::mem::discriminant(self).hash(state);
       }
+      FunctionType::StylexExprFnsFactory(_) => {
+        std::mem::discriminant(self).hash(state);
+      }
       FunctionType::Mapper(_) => {
         std::mem::discriminant(self).hash(state);
       }
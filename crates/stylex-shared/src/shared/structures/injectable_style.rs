@@ -18,11 +18,22 @@ pub struct InjectableStyleConstBase {
   pub const_value: String,
 }
 
+/// Location of the style key that produced a rule. Lines are 1-based and
+/// columns are 0-based, like Babel locations.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq)]
+pub struct SourceLocation {
+  pub file: String,
+  pub line: usize,
+  pub column: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub(crate) struct InjectableStyle {
   pub(crate) ltr: String,
   pub(crate) rtl: Option<String>,
   pub(crate) priority: Option<f64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) source: Option<SourceLocation>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -58,6 +69,7 @@ impl Default for InjectableStyle {
       ltr: "".to_string(),
       rtl: None,
       priority: Some(0.0),
+      source: None,
     }
   }
 }
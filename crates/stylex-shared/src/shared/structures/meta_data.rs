@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use crate::shared::{
   enums::data_structures::injectable_style::{InjectableStyleBaseKind, InjectableStyleKind},
+  structures::injectable_style::SourceLocation,
   utils::common::hash_f64,
 };
 
@@ -22,13 +23,25 @@ where
   serializer.serialize_f64(*priority)
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 
 pub struct MetaData {
   class_name: String,
   style: InjectableStyleBaseKind,
   #[serde(serialize_with = "f64_to_int")]
   priority: f64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  source: Option<SourceLocation>,
+}
+
+// The source location is left out so that a rule produced by several style
+// keys is only collected once
+impl PartialEq for MetaData {
+  fn eq(&self, other: &Self) -> bool {
+    self.class_name == other.class_name
+      && self.style == other.style
+      && self.priority == other.priority
+  }
 }
 
 impl Hash for MetaData {
@@ -49,9 +62,23 @@ impl MetaData {
         InjectableStyleKind::Regular(style) => style.priority.unwrap_or(0.0),
         InjectableStyleKind::Const(style) => style.priority.unwrap_or(0.0),
       },
+      source: match &injectable_style {
+        InjectableStyleKind::Regular(style) => style.source.clone(),
+        InjectableStyleKind::Const(_) => None,
+      },
       style: InjectableStyleBaseKind::from(injectable_style),
     }
   }
+
+  pub fn from_style(class_name: String, style: InjectableStyleBaseKind, priority: f64) -> Self {
+    Self {
+      class_name,
+      style,
+      priority,
+      source: None,
+    }
+  }
+
   pub fn get_style(&self) -> &InjectableStyleBaseKind {
     &self.style
   }
@@ -78,6 +105,10 @@ impl MetaData {
     &self.priority
   }
 
+  pub fn get_source(&self) -> Option<&SourceLocation> {
+    self.source.as_ref()
+  }
+
   pub(crate) fn convert_from_injected_styles_map(
     injected_styles_map: &IndexMap<String, Rc<InjectableStyleKind>>,
   ) -> Vec<MetaData> {
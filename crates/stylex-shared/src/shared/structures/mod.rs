@@ -1,9 +1,10 @@
 pub(crate) mod application_order;
 pub(crate) mod base_css_type;
+pub mod diagnostic;
 pub(crate) mod dynamic_style;
 pub mod evaluate_result;
 pub mod functions;
-pub(crate) mod injectable_style;
+pub mod injectable_style;
 pub(crate) mod inline_style;
 pub(crate) mod legacy_expand_shorthands_order;
 pub(crate) mod member_transform;
@@ -7,6 +7,7 @@ pub struct NamedImportSource {
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(untagged)]
 pub enum ImportSources {
   Regular(String),
   Named(NamedImportSource),
@@ -2,6 +2,7 @@ use core::panic;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::hash::Hash;
 use std::path::Path;
+use std::sync::{Arc, RwLock};
 use std::{option::Option, rc::Rc};
 
 use indexmap::{IndexMap, IndexSet};
@@ -23,17 +24,22 @@ use swc_core::{
 use swc_core::{
   common::SyntaxContext,
   ecma::ast::{
-    CallExpr, Callee, Decl, Expr, ExprStmt, Ident, ImportDecl, ImportDefaultSpecifier,
-    ImportNamedSpecifier, ImportPhase, ImportSpecifier, ModuleDecl, ModuleExportName, ModuleItem,
-    Pat, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
+    ArrowExpr, BinExpr, BinaryOp, BlockStmtOrExpr, CallExpr, Callee, Decl, Expr, ExprOrSpread,
+    ExprStmt, Ident, IdentName, IfStmt, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier,
+    ImportPhase, ImportSpecifier, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, ModuleDecl,
+    ModuleExportName, ModuleItem, OptCall, OptChainBase, OptChainExpr, Pat, Stmt, Str, UnaryExpr,
+    UnaryOp, VarDecl, VarDeclKind, VarDeclarator,
   },
 };
 
 use crate::shared::{
-  constants::common::DEFAULT_INJECT_PATH,
+  constants::common::{DEFAULT_INJECT_PATH, HOT_MODULE_RELOAD_INJECT_PATH},
   structures::uid_generator::CounterMode,
-  utils::ast::factories::{
-    expr_or_spread_number_expression_factory, expr_or_spread_string_expression_factory,
+  utils::ast::{
+    convertors::{ident_to_expression, null_to_expression, string_to_expression},
+    factories::{
+      expr_or_spread_number_expression_factory, expr_or_spread_string_expression_factory,
+    },
   },
 };
 use crate::shared::{
@@ -41,7 +47,8 @@ use crate::shared::{
   utils::{
     ast::factories::binding_ident_factory,
     common::{
-      extract_filename_from_path, extract_filename_with_ext_from_path, extract_path, round_f64,
+      create_hash, extract_filename_from_path, extract_filename_with_ext_from_path, extract_path,
+      round_f64,
     },
   },
 };
@@ -57,12 +64,16 @@ use crate::shared::{
   utils::common::stable_hash,
 };
 
+use super::diagnostic::{Diagnostic, WarningLevel};
 use super::plugin_pass::PluginPass;
 use super::stylex_options::ModuleResolution;
-use super::stylex_options::{CheckModuleResolution, StyleXOptions};
+use super::stylex_options::{CheckModuleResolution, HotModuleReload, StyleXOptions};
 use super::stylex_state_options::StyleXStateOptions;
 use super::uid_generator::UidGenerator;
-use super::{meta_data::MetaData, types::StylesObjectMap};
+use super::{
+  meta_data::MetaData,
+  types::{ExportedStylesMap, StylesObjectMap},
+};
 use super::{
   named_import_source::{ImportSources, NamedImportSource, RuntimeInjectionState},
   seen_value::SeenValue,
@@ -73,6 +84,15 @@ static TRANSFORMED_VARS_FILE_EXTENSION: Lazy<&'static str> = Lazy::new(|| ".tran
 type AtomHashMap = FxHashMap<Atom, i16>;
 type AtomHashSet = FxHashSet<Atom>;
 
+/// Resolved file path to the canonical path used for hashing theme and
+/// constant files. It can be shared by transforms running on other threads.
+pub type CanonicalPathCache = Arc<RwLock<FxHashMap<String, String>>>;
+
+/// `stylex.create` exports compiled from imported `.stylex` modules, keyed by
+/// a hash of the module path, its content and the options. It can be shared
+/// by transforms running on other threads.
+pub type CompiledModuleCache = Arc<RwLock<FxHashMap<String, serde_json::Value>>>;
+
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) struct SeenValueWithVarDeclCount {
   pub(crate) seen_value: SeenValue,
@@ -88,6 +108,7 @@ pub struct StateManager {
   pub(crate) stylex_import: FxHashSet<ImportSources>,
   pub(crate) import_specifiers: Vec<String>,
   pub(crate) stylex_props_import: AtomHashSet,
+  pub(crate) stylex_attrs_import: AtomHashSet,
   pub(crate) stylex_create_import: AtomHashSet,
   pub(crate) stylex_first_that_works_import: AtomHashSet,
   pub(crate) stylex_keyframes_import: AtomHashSet,
@@ -97,6 +118,8 @@ pub struct StateManager {
   pub(crate) stylex_position_try_import: AtomHashSet,
   pub(crate) stylex_view_transition_class_import: AtomHashSet,
   pub(crate) stylex_types_import: AtomHashSet,
+  pub(crate) stylex_when_import: AtomHashSet,
+  pub(crate) stylex_default_marker_import: AtomHashSet,
   pub(crate) inject_import_inserted: Option<(Ident, Ident)>,
   pub(crate) export_id: Option<String>,
 
@@ -112,10 +135,15 @@ pub struct StateManager {
   pub(crate) css_property_seen: FxHashMap<String, String>,
   pub(crate) seen_source_code_by_path: FxHashMap<FileName, String>,
   pub(crate) jsx_spread_attr_exprs_map: FxHashMap<Expr, Vec<JSXAttrOrSpread>>,
+  pub(crate) canonical_path_cache: CanonicalPathCache,
+  pub(crate) compiled_module_cache: CompiledModuleCache,
 
   // `stylex.create` calls
   pub(crate) style_map: FxHashMap<String, Rc<StylesObjectMap>>,
   pub(crate) style_vars: FxHashMap<String, VarDeclarator>,
+  // `stylex.create` results exported from other modules, keyed by resolved file path.
+  // `None` marks modules that could not be resolved or are being resolved.
+  pub(crate) imported_style_maps: FxHashMap<String, Option<Rc<ExportedStylesMap>>>,
 
   // results of `stylex.create` calls that should be kept
   pub(crate) style_vars_to_keep: IndexSet<StyleVarsToKeep>,
@@ -125,6 +153,7 @@ pub struct StateManager {
 
   pub(crate) options: StyleXStateOptions,
   pub metadata: IndexMap<String, IndexSet<MetaData>>,
+  pub diagnostics: Vec<Diagnostic>,
   pub(crate) styles_to_inject: IndexMap<u64, Vec<ModuleItem>>,
   pub(crate) prepend_include_module_items: Vec<ModuleItem>,
   pub(crate) hoisted_module_items: Vec<ModuleItem>,
@@ -152,6 +181,7 @@ impl StateManager {
       stylex_import: FxHashSet::default(),
       import_specifiers: vec![],
       stylex_props_import: FxHashSet::default(),
+      stylex_attrs_import: FxHashSet::default(),
       stylex_create_import: FxHashSet::default(),
       stylex_first_that_works_import: FxHashSet::default(),
       stylex_keyframes_import: FxHashSet::default(),
@@ -159,11 +189,14 @@ impl StateManager {
       stylex_define_consts_import: FxHashSet::default(),
       stylex_create_theme_import: FxHashSet::default(),
       stylex_types_import: FxHashSet::default(),
+      stylex_when_import: FxHashSet::default(),
+      stylex_default_marker_import: FxHashSet::default(),
       stylex_position_try_import: FxHashSet::default(),
       stylex_view_transition_class_import: FxHashSet::default(),
       inject_import_inserted: None,
       style_map: FxHashMap::default(),
       style_vars: FxHashMap::default(),
+      imported_style_maps: FxHashMap::default(),
       style_vars_to_keep: IndexSet::default(),
       member_object_ident_count_map: FxHashMap::default(),
       export_id: None,
@@ -183,11 +216,14 @@ impl StateManager {
       all_call_expressions: FxHashMap::default(),
       var_decl_count_map: FxHashMap::default(),
       jsx_spread_attr_exprs_map: FxHashMap::default(),
+      canonical_path_cache: CanonicalPathCache::default(),
+      compiled_module_cache: CompiledModuleCache::default(),
 
       in_stylex_create: false,
       options,
 
       metadata: IndexMap::new(),
+      diagnostics: vec![],
       styles_to_inject: IndexMap::new(),
       prepend_include_module_items: vec![],
       prepend_import_module_items: vec![],
@@ -273,6 +309,18 @@ impl StateManager {
     self.options.enable_inlined_conditional_merge
   }
 
+  pub(crate) fn inlined_conditional_merge_limit(&self) -> usize {
+    self.options.inlined_conditional_merge_limit
+  }
+
+  pub(crate) fn enable_value_validation(&self) -> bool {
+    self.options.enable_value_validation
+  }
+
+  pub(crate) fn enable_color_normalization(&self) -> bool {
+    self.options.enable_color_normalization
+  }
+
   pub(crate) fn get_short_filename(&self) -> String {
     extract_filename_from_path(&self._state.filename)
   }
@@ -342,10 +390,77 @@ impl StateManager {
       }
     }
   }
+  pub(crate) fn add_diagnostic(&mut self, diagnostic: Diagnostic) {
+    if !self
+      .diagnostics
+      .iter()
+      .any(|existing| existing.is_same_as(&diagnostic))
+    {
+      self.diagnostics.push(diagnostic);
+    }
+  }
+
+  /// Records a warning at the level configured for its code
+  pub(crate) fn add_warning(&mut self, code: &str, message: &str) {
+    let level = self
+      .options
+      .warnings
+      .get(code)
+      .copied()
+      .unwrap_or_else(|| WarningLevel::default_for(code));
+
+    let file = Some(self.get_filename().to_string());
+
+    match level {
+      WarningLevel::Off => {}
+      WarningLevel::Warn => self.add_diagnostic(Diagnostic::warning(code, message, file)),
+      WarningLevel::Error => self.add_diagnostic(Diagnostic::error(code, message, file)),
+    }
+  }
+
+  pub fn has_errors(&self) -> bool {
+    self.diagnostics.iter().any(Diagnostic::is_error)
+  }
+
+  pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+    self.diagnostics.iter().filter(|d| !d.is_error())
+  }
+
+  pub fn set_canonical_path_cache(&mut self, cache: CanonicalPathCache) {
+    self.canonical_path_cache = cache;
+  }
+
+  pub fn set_compiled_module_cache(&mut self, cache: CompiledModuleCache) {
+    self.compiled_module_cache = cache;
+  }
+
   pub(crate) fn get_canonical_file_path(
     &self,
     file_path: &str,
     package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+  ) -> String {
+    if let Some(canonical_path) = self
+      .canonical_path_cache
+      .read()
+      .ok()
+      .and_then(|cache| cache.get(file_path).cloned())
+    {
+      return canonical_path;
+    }
+
+    let canonical_path = self.resolve_canonical_file_path(file_path, package_json_seen);
+
+    if let Ok(mut cache) = self.canonical_path_cache.write() {
+      cache.insert(file_path.to_string(), canonical_path.clone());
+    }
+
+    canonical_path
+  }
+
+  fn resolve_canonical_file_path(
+    &self,
+    file_path: &str,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
   ) -> String {
     if let Some(pkg_info) = StateManager::get_package_name_and_path(file_path, package_json_seen) {
       let (package_name, package_dir) = pkg_info;
@@ -397,20 +512,7 @@ impl StateManager {
       return ImportPathResolution::False;
     }
 
-    let theme_file_extension = (match &self.options.unstable_module_resolution {
-      CheckModuleResolution::CommonJS(module_resolution) => module_resolution,
-      CheckModuleResolution::Haste(module_resolution) => module_resolution,
-      CheckModuleResolution::CrossFileParsing(module_resolution) => module_resolution,
-    })
-    .theme_file_extension
-    .as_deref()
-    .unwrap_or(".stylex");
-
-    let is_valid_stylex_file = matches_file_suffix(theme_file_extension, import_path);
-    let is_valid_transformed_vars_file =
-      matches_file_suffix(&TRANSFORMED_VARS_FILE_EXTENSION, import_path);
-
-    if !is_valid_stylex_file && !is_valid_transformed_vars_file {
+    if !self.is_stylex_file_import(import_path) {
       return ImportPathResolution::False;
     }
 
@@ -435,6 +537,45 @@ impl StateManager {
     }
   }
 
+  /// Resolves a `.stylex` import to the file on disk, so that its exports can
+  /// be compiled. Only available with `commonJS` module resolution.
+  pub(crate) fn import_file_path_resolver(&self, import_path: &str) -> ImportPathResolution {
+    let source_file_path = self.get_filename();
+
+    if source_file_path.is_empty() || !self.is_stylex_file_import(import_path) {
+      return ImportPathResolution::False;
+    }
+
+    match &self.options.unstable_module_resolution {
+      CheckModuleResolution::CommonJS(_) => {
+        let aliases = self.options.aliases.as_ref().cloned().unwrap_or_default();
+
+        match resolve_file_path(import_path, source_file_path, &aliases) {
+          Ok(resolved_path) => ImportPathResolution::Tuple(
+            ImportPathResolutionType::FilePath,
+            resolved_path.display().to_string(),
+          ),
+          Err(_) => ImportPathResolution::False,
+        }
+      }
+      _ => ImportPathResolution::False,
+    }
+  }
+
+  fn is_stylex_file_import(&self, import_path: &str) -> bool {
+    let theme_file_extension = (match &self.options.unstable_module_resolution {
+      CheckModuleResolution::CommonJS(module_resolution) => module_resolution,
+      CheckModuleResolution::Haste(module_resolution) => module_resolution,
+      CheckModuleResolution::CrossFileParsing(module_resolution) => module_resolution,
+    })
+    .theme_file_extension
+    .as_deref()
+    .unwrap_or(".stylex");
+
+    matches_file_suffix(theme_file_extension, import_path)
+      || matches_file_suffix(&TRANSFORMED_VARS_FILE_EXTENSION, import_path)
+  }
+
   pub(crate) fn find_top_level_expr(
     &self,
     call: &CallExpr,
@@ -530,7 +671,7 @@ impl StateManager {
 
     let module_items = match &runtime_injection {
       RuntimeInjectionState::Regular(_) => vec![
-        add_inject_default_import_expression(&inject_module_ident),
+        add_inject_default_import_expression(&inject_module_ident, self.get_inject_path()),
         add_inject_var_decl_expression(&inject_var_ident, &inject_module_ident),
       ],
       RuntimeInjectionState::Named(_) => vec![
@@ -543,6 +684,16 @@ impl StateManager {
     inject_var_ident
   }
 
+  /// Hot reloadable modules tag their rules with a module id, which only the
+  /// compiler's inject wrapper understands.
+  fn get_inject_path(&self) -> &'static str {
+    if self.options.hot_module_reload.is_some() {
+      HOT_MODULE_RELOAD_INJECT_PATH
+    } else {
+      DEFAULT_INJECT_PATH
+    }
+  }
+
   fn update_references(&mut self, call: &CallExpr, ast: &Expr) {
     if let Some(item) = self.declarations.iter_mut().find(|decl| {
       decl.init.as_ref().is_some_and(
@@ -597,11 +748,27 @@ impl StateManager {
 
     let mut stylex_inject_args = vec![
       expr_or_spread_string_expression_factory(css),
-      expr_or_spread_number_expression_factory(round_f64(*priority, 1)),
+      // Only float noise is rounded away, `@container` priorities carry a
+      // small offset that orders them by their inline-size threshold
+      expr_or_spread_number_expression_factory(round_f64(*priority, 6)),
     ];
 
+    let hot_module_id = self
+      .options
+      .hot_module_reload
+      .map(|_| self.get_hot_module_id());
+
     if let Some(rtl) = css_rtl {
       stylex_inject_args.push(expr_or_spread_string_expression_factory(rtl));
+    } else if hot_module_id.is_some() {
+      stylex_inject_args.push(ExprOrSpread {
+        spread: None,
+        expr: Box::new(null_to_expression()),
+      });
+    }
+
+    if let Some(hot_module_id) = hot_module_id {
+      stylex_inject_args.push(expr_or_spread_string_expression_factory(&hot_module_id));
     }
 
     let stylex_call_expr = CallExpr {
@@ -628,6 +795,98 @@ impl StateManager {
       .push(drop_span(module));
   }
 
+  /// Identifies the rules injected by this module, so that the runtime can
+  /// replace them when the module is hot reloaded
+  fn get_hot_module_id(&self) -> String {
+    create_hash(self.get_filename())
+  }
+
+  /// Builds the statement that disposes the rules injected by this module
+  /// when it is replaced through the hot module reload API:
+  ///
+  /// `if (import.meta.hot) import.meta.hot.dispose(() => _inject2.dispose?.(id));`
+  pub(crate) fn get_hot_module_dispose_item(&self) -> Option<ModuleItem> {
+    let hot_module_reload = self.options.hot_module_reload?;
+    let (_, inject_var_ident) = self.inject_import_inserted.as_ref()?;
+
+    let hot_api = match hot_module_reload {
+      HotModuleReload::ImportMeta => member_expr_factory(
+        Expr::MetaProp(MetaPropExpr {
+          span: DUMMY_SP,
+          kind: MetaPropKind::ImportMeta,
+        }),
+        "hot",
+      ),
+      HotModuleReload::Module => member_expr_factory(ident_to_expression("module"), "hot"),
+    };
+
+    let test = match hot_module_reload {
+      HotModuleReload::ImportMeta => hot_api.clone(),
+      HotModuleReload::Module => Expr::from(BinExpr {
+        span: DUMMY_SP,
+        op: BinaryOp::LogicalAnd,
+        left: Box::new(Expr::from(BinExpr {
+          span: DUMMY_SP,
+          op: BinaryOp::NotEqEq,
+          left: Box::new(Expr::from(UnaryExpr {
+            span: DUMMY_SP,
+            op: UnaryOp::TypeOf,
+            arg: Box::new(ident_to_expression("module")),
+          })),
+          right: Box::new(string_to_expression("undefined")),
+        })),
+        right: Box::new(hot_api.clone()),
+      }),
+    };
+
+    let dispose_rules = Expr::OptChain(OptChainExpr {
+      span: DUMMY_SP,
+      optional: true,
+      base: Box::new(OptChainBase::Call(OptCall {
+        span: DUMMY_SP,
+        callee: Box::new(member_expr_factory(
+          Expr::Ident(inject_var_ident.clone()),
+          "dispose",
+        )),
+        args: vec![expr_or_spread_string_expression_factory(
+          &self.get_hot_module_id(),
+        )],
+        type_args: None,
+        ctxt: SyntaxContext::empty(),
+      })),
+    });
+
+    let dispose_call = Expr::Call(CallExpr {
+      span: DUMMY_SP,
+      callee: Callee::Expr(Box::new(member_expr_factory(hot_api, "dispose"))),
+      args: vec![ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Arrow(ArrowExpr {
+          span: DUMMY_SP,
+          params: vec![],
+          body: Box::new(BlockStmtOrExpr::Expr(Box::new(dispose_rules))),
+          is_async: false,
+          is_generator: false,
+          type_params: None,
+          return_type: None,
+          ctxt: SyntaxContext::empty(),
+        })),
+      }],
+      type_args: None,
+      ctxt: SyntaxContext::empty(),
+    });
+
+    Some(ModuleItem::Stmt(Stmt::If(IfStmt {
+      span: DUMMY_SP,
+      test: Box::new(test),
+      cons: Box::new(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(dispose_call),
+      })),
+      alt: None,
+    })))
+  }
+
   // pub(crate) fn _get_css_vars(&self) -> FxHashMap<String, String> {
   //   self.options.defined_stylex_css_variables.clone()
   // }
@@ -642,6 +901,8 @@ impl StateManager {
     self.stylex_import = union_hash_set(&self.stylex_import, &other.stylex_import);
     self.stylex_props_import =
       union_hash_set(&self.stylex_props_import, &other.stylex_props_import);
+    self.stylex_attrs_import =
+      union_hash_set(&self.stylex_attrs_import, &other.stylex_attrs_import);
     self.stylex_create_import =
       union_hash_set(&self.stylex_create_import, &other.stylex_create_import);
     self.stylex_first_that_works_import = union_hash_set(
@@ -662,6 +923,11 @@ impl StateManager {
     );
     self.stylex_types_import =
       union_hash_set(&self.stylex_types_import, &other.stylex_types_import);
+    self.stylex_when_import = union_hash_set(&self.stylex_when_import, &other.stylex_when_import);
+    self.stylex_default_marker_import = union_hash_set(
+      &self.stylex_default_marker_import,
+      &other.stylex_default_marker_import,
+    );
     self.inject_import_inserted = self
       .inject_import_inserted
       .clone()
@@ -683,6 +949,10 @@ impl StateManager {
     );
     self.style_map = chain_collect_hash_map(self.style_map.clone(), other.style_map.clone());
     self.style_vars = chain_collect_hash_map(self.style_vars.clone(), other.style_vars.clone());
+    self.imported_style_maps = chain_collect_hash_map(
+      self.imported_style_maps.clone(),
+      other.imported_style_maps.clone(),
+    );
     self.style_vars_to_keep =
       union_index_set(&self.style_vars_to_keep.clone(), &other.style_vars_to_keep);
     self.member_object_ident_count_map = chain_collect_hash_map(
@@ -692,6 +962,7 @@ impl StateManager {
     self.in_stylex_create = self.in_stylex_create || other.in_stylex_create;
 
     self.metadata = chain_collect_index_map(self.metadata.clone(), other.metadata.clone());
+    self.diagnostics = chain_collect(self.diagnostics.clone(), other.diagnostics.clone());
     self.seen = chain_collect_hash_map(self.seen.clone(), other.seen.clone());
     self.styles_to_inject = chain_collect_index_map(
       self.styles_to_inject.clone(),
@@ -713,7 +984,7 @@ impl StateManager {
   }
 }
 
-fn add_inject_default_import_expression(ident: &Ident) -> ModuleItem {
+fn add_inject_default_import_expression(ident: &Ident, path: &str) -> ModuleItem {
   ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
     span: DUMMY_SP,
     specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
@@ -723,7 +994,7 @@ fn add_inject_default_import_expression(ident: &Ident) -> ModuleItem {
     src: Box::new(Str {
       span: DUMMY_SP,
       raw: None,
-      value: DEFAULT_INJECT_PATH.into(),
+      value: path.into(),
     }),
     type_only: false,
     with: None,
@@ -766,6 +1037,14 @@ fn add_inject_named_import_expression(ident: &Ident, imported_ident: &Ident) ->
   }))
 }
 
+fn member_expr_factory(object: Expr, property: &str) -> Expr {
+  Expr::Member(MemberExpr {
+    span: DUMMY_SP,
+    obj: Box::new(object),
+    prop: MemberProp::Ident(IdentName::new(property.into(), DUMMY_SP)),
+  })
+}
+
 fn add_inject_var_decl_expression(decl_ident: &Ident, value_ident: &Ident) -> ModuleItem {
   ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
     declare: false,
@@ -1,9 +1,14 @@
 use rustc_hash::FxHashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
-use crate::shared::constants::common::DEFAULT_INJECT_PATH;
+use crate::shared::constants::common::{
+  DEFAULT_INJECT_PATH, DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT,
+};
 
-use super::named_import_source::{ImportSources, RuntimeInjection};
+use super::{
+  diagnostic::WarningLevel,
+  named_import_source::{ImportSources, RuntimeInjection},
+};
 
 #[derive(Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -16,10 +21,18 @@ pub struct StyleXOptionsParams {
   pub import_sources: Option<Vec<ImportSources>>,
   pub treeshake_compensation: Option<bool>,
   pub enable_inlined_conditional_merge: Option<bool>,
+  pub inlined_conditional_merge_limit: Option<usize>,
   pub enable_media_query_order: Option<bool>,
   pub enable_logical_styles_polyfill: Option<bool>,
   pub enable_legacy_value_flipping: Option<bool>,
+  #[serde(alias = "enableLTRRTLComments")]
   pub enable_ltr_rtl_comments: Option<bool>,
+  pub enable_value_validation: Option<bool>,
+  pub enable_color_normalization: Option<bool>,
+  pub enable_css_source_locations: Option<bool>,
+  pub hot_module_reload: Option<HotModuleReload>,
+  pub class_name_hashing: Option<ClassNameHashing>,
+  pub module_cache_dir: Option<String>,
   pub dev: Option<bool>,
   pub test: Option<bool>,
   pub debug: Option<bool>,
@@ -27,11 +40,41 @@ pub struct StyleXOptionsParams {
   pub enable_debug_data_prop: Option<bool>,
   pub enable_dev_class_names: Option<bool>,
   pub enable_minified_keys: Option<bool>,
+  #[serde(default, deserialize_with = "deserialize_aliases")]
   pub aliases: Option<FxHashMap<String, Vec<String>>>,
+  pub warnings: Option<FxHashMap<String, WarningLevel>>,
   #[serde(rename = "unstable_moduleResolution")]
   pub unstable_module_resolution: Option<ModuleResolution>,
 }
 
+/// Accepts both `{ "@/*": "src/*" }` and `{ "@/*": ["src/*"] }`, like the
+/// Babel plugin does
+fn deserialize_aliases<'de, D>(
+  deserializer: D,
+) -> Result<Option<FxHashMap<String, Vec<String>>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum AliasPaths {
+    Single(String),
+    Many(Vec<String>),
+  }
+
+  let aliases = Option::<FxHashMap<String, AliasPaths>>::deserialize(deserializer)?;
+
+  Ok(aliases.map(|aliases| {
+    aliases
+      .into_iter()
+      .map(|(alias, paths)| match paths {
+        AliasPaths::Single(path) => (alias, vec![path]),
+        AliasPaths::Many(paths) => (alias, paths),
+      })
+      .collect()
+  }))
+}
+
 impl Default for StyleXOptionsParams {
   fn default() -> Self {
     StyleXOptionsParams {
@@ -43,10 +86,17 @@ impl Default for StyleXOptionsParams {
       import_sources: None,
       treeshake_compensation: Some(true),
       enable_inlined_conditional_merge: Some(true),
+      inlined_conditional_merge_limit: Some(DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT),
       enable_media_query_order: Some(false),
       enable_logical_styles_polyfill: Some(false),
       enable_ltr_rtl_comments: Some(false),
       enable_legacy_value_flipping: Some(false),
+      enable_value_validation: Some(false),
+      enable_color_normalization: Some(false),
+      enable_css_source_locations: Some(false),
+      hot_module_reload: None,
+      class_name_hashing: None,
+      module_cache_dir: None,
       dev: Some(false),
       test: Some(false),
       debug: None,
@@ -55,11 +105,40 @@ impl Default for StyleXOptionsParams {
       enable_dev_class_names: Some(false),
       enable_minified_keys: Some(true),
       aliases: None,
+      warnings: None,
       unstable_module_resolution: None,
     }
   }
 }
 
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessStylexRulesOptions {
+  pub use_layers: bool,
+  #[serde(rename = "enableLTRRTLComments")]
+  pub enable_ltr_rtl_comments: bool,
+  #[serde(default)]
+  pub direction_selector: DirectionSelector,
+  /// Replaces the hashed class names with short sequential ids starting with
+  /// this prefix, see `create_short_class_names`
+  #[serde(default)]
+  pub short_class_name_prefix: Option<String>,
+}
+
+/// How rules that differ between LTR and RTL are scoped in the final stylesheet
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DirectionSelector {
+  /// Prefixes rules with `html:not([dir='rtl'])` and `html[dir='rtl']`
+  #[default]
+  Ancestor,
+  /// Appends `:dir(ltr)` and `:dir(rtl)` to the rule selectors, so the
+  /// direction is taken from the closest element instead of the document
+  PseudoClass,
+  /// Emits the LTR rules only, for apps that rely on logical properties
+  LogicalOnly,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all(deserialize = "kebab-case", serialize = "PascalCase"))]
 pub enum StyleResolution {
@@ -68,6 +147,61 @@ pub enum StyleResolution {
   LegacyExpandShorthands,
 }
 
+/// Hot module reload API used to dispose the rules injected at runtime by a
+/// module before its updated version is executed
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum HotModuleReload {
+  /// `import.meta.hot`, as exposed by Vite and other ESM dev servers
+  ImportMeta,
+  /// `module.hot`, as exposed by webpack
+  Module,
+}
+
+/// Controls how the hashes of generated class names, variables, keyframes and
+/// position-try rules are produced
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassNameHashing {
+  /// Maximum number of characters of a hash
+  pub length: Option<usize>,
+  /// Characters used to encode a hash, base36 by default
+  pub alphabet: Option<String>,
+  /// Mixed into every hashed value, e.g. to keep the class names of
+  /// separately built packages apart
+  pub salt: Option<String>,
+}
+
+impl ClassNameHashing {
+  pub(crate) fn is_default(&self) -> bool {
+    self == &ClassNameHashing::default()
+  }
+
+  fn validate(&self) {
+    if self.length == Some(0) {
+      panic!("classNameHashing.length must be greater than 0");
+    }
+
+    if let Some(alphabet) = &self.alphabet {
+      let chars = alphabet.chars().collect::<Vec<_>>();
+
+      if chars.len() < 2
+        || chars
+          .iter()
+          .enumerate()
+          .any(|(i, c)| chars[..i].contains(c))
+        || !chars
+          .iter()
+          .all(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+      {
+        panic!(
+          "classNameHashing.alphabet must contain at least two unique letters, digits, '-' or '_'"
+        );
+      }
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 
 pub enum Aliases {
@@ -100,10 +234,17 @@ pub struct StyleXOptions {
   pub enable_debug_data_prop: bool,
   pub enable_dev_class_names: bool,
   pub enable_inlined_conditional_merge: bool,
+  pub inlined_conditional_merge_limit: usize,
   pub enable_media_query_order: bool,
   pub enable_logical_styles_polyfill: bool,
   pub enable_legacy_value_flipping: bool,
   pub enable_ltr_rtl_comments: bool,
+  pub enable_value_validation: bool,
+  pub enable_color_normalization: bool,
+  pub enable_css_source_locations: bool,
+  pub hot_module_reload: Option<HotModuleReload>,
+  pub class_name_hashing: ClassNameHashing,
+  pub module_cache_dir: Option<String>,
   pub enable_minified_keys: bool,
   pub enable_font_size_px_to_rem: bool,
   pub class_name_prefix: String,
@@ -113,6 +254,7 @@ pub struct StyleXOptions {
   pub import_sources: Vec<ImportSources>,
   pub treeshake_compensation: bool,
   pub aliases: Option<FxHashMap<String, Vec<String>>>,
+  pub warnings: FxHashMap<String, WarningLevel>,
   pub unstable_module_resolution: CheckModuleResolution,
 }
 
@@ -150,13 +292,21 @@ impl Default for StyleXOptions {
       enable_debug_data_prop: true,
       enable_dev_class_names: false,
       enable_inlined_conditional_merge: true,
+      inlined_conditional_merge_limit: DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT,
       enable_media_query_order: false,
       enable_logical_styles_polyfill: false,
       enable_legacy_value_flipping: false,
       enable_ltr_rtl_comments: false,
+      enable_value_validation: false,
+      enable_color_normalization: false,
+      enable_css_source_locations: false,
+      hot_module_reload: None,
+      class_name_hashing: ClassNameHashing::default(),
+      module_cache_dir: None,
       enable_minified_keys: true,
       treeshake_compensation: false,
       aliases: None,
+      warnings: FxHashMap::default(),
       unstable_module_resolution: CheckModuleResolution::CommonJS(
         StyleXOptions::get_common_js_module_resolution(None),
       ),
@@ -184,6 +334,10 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       None => RuntimeInjection::Boolean(false),
     };
 
+    let class_name_hashing = options.class_name_hashing.unwrap_or_default();
+
+    class_name_hashing.validate();
+
     StyleXOptions {
       style_resolution: options
         .style_resolution
@@ -207,11 +361,21 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       enable_minified_keys: options.enable_minified_keys.unwrap_or(true),
       treeshake_compensation: options.treeshake_compensation.unwrap_or(false),
       enable_inlined_conditional_merge: options.enable_inlined_conditional_merge.unwrap_or(true),
+      inlined_conditional_merge_limit: options
+        .inlined_conditional_merge_limit
+        .unwrap_or(DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT),
       enable_media_query_order: options.enable_media_query_order.unwrap_or(false),
       enable_logical_styles_polyfill: options.enable_logical_styles_polyfill.unwrap_or(false),
       enable_legacy_value_flipping: options.enable_legacy_value_flipping.unwrap_or(false),
       enable_ltr_rtl_comments: options.enable_ltr_rtl_comments.unwrap_or(false),
+      enable_value_validation: options.enable_value_validation.unwrap_or(false),
+      enable_color_normalization: options.enable_color_normalization.unwrap_or(false),
+      enable_css_source_locations: options.enable_css_source_locations.unwrap_or(false),
+      hot_module_reload: options.hot_module_reload,
+      class_name_hashing,
+      module_cache_dir: options.module_cache_dir,
       aliases: options.aliases,
+      warnings: options.warnings.unwrap_or_default(),
       unstable_module_resolution,
     }
   }
@@ -1,11 +1,16 @@
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
-use crate::shared::constants::common::DEFAULT_INJECT_PATH;
+use crate::shared::constants::common::{
+  DEFAULT_INJECT_PATH, DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT,
+};
 
 use super::{
+  diagnostic::WarningLevel,
   named_import_source::{ImportSources, RuntimeInjection, RuntimeInjectionState},
-  stylex_options::{CheckModuleResolution, StyleResolution, StyleXOptions},
+  stylex_options::{
+    CheckModuleResolution, ClassNameHashing, HotModuleReload, StyleResolution, StyleXOptions,
+  },
 };
 
 #[derive(Deserialize, Clone, Debug)]
@@ -20,17 +25,25 @@ pub struct StyleXStateOptions {
   pub enable_dev_class_names: bool,
   pub enable_minified_keys: bool,
   pub enable_inlined_conditional_merge: bool,
+  pub inlined_conditional_merge_limit: usize,
   pub enable_media_query_order: bool,
   pub enable_logical_styles_polyfill: bool,
   pub enable_legacy_value_flipping: bool,
   #[allow(dead_code)]
   pub enable_ltr_rtl_comments: bool,
+  pub enable_value_validation: bool,
+  pub enable_color_normalization: bool,
+  pub enable_css_source_locations: bool,
+  pub hot_module_reload: Option<HotModuleReload>,
+  pub class_name_hashing: ClassNameHashing,
+  pub module_cache_dir: Option<String>,
   // pub defined_stylex_css_variables: FxHashMap<String, String>,
   pub style_resolution: StyleResolution,
   pub import_sources: Vec<ImportSources>,
   pub runtime_injection: Option<RuntimeInjectionState>,
   pub treeshake_compensation: bool,
   pub aliases: Option<FxHashMap<String, Vec<String>>>,
+  pub warnings: FxHashMap<String, WarningLevel>,
   pub unstable_module_resolution: CheckModuleResolution,
 }
 
@@ -47,7 +60,14 @@ impl Default for StyleXStateOptions {
       enable_logical_styles_polyfill: false,
       enable_legacy_value_flipping: false,
       enable_ltr_rtl_comments: false,
+      enable_value_validation: false,
+      enable_color_normalization: false,
+      enable_css_source_locations: false,
+      hot_module_reload: None,
+      class_name_hashing: ClassNameHashing::default(),
+      module_cache_dir: None,
       enable_inlined_conditional_merge: true,
+      inlined_conditional_merge_limit: DEFAULT_INLINED_CONDITIONAL_MERGE_LIMIT,
       enable_media_query_order: false,
       enable_font_size_px_to_rem: false,
       enable_minified_keys: true,
@@ -56,6 +76,7 @@ impl Default for StyleXStateOptions {
       treeshake_compensation: false,
       runtime_injection: None,
       aliases: None,
+      warnings: FxHashMap::default(),
       unstable_module_resolution: CheckModuleResolution::CommonJS(
         StyleXOptions::get_common_js_module_resolution(None),
       ),
@@ -99,12 +120,20 @@ impl From<StyleXOptions> for StyleXStateOptions {
       enable_dev_class_names: options.enable_dev_class_names,
       enable_media_query_order: options.enable_media_query_order,
       enable_inlined_conditional_merge: options.enable_inlined_conditional_merge,
+      inlined_conditional_merge_limit: options.inlined_conditional_merge_limit,
       enable_ltr_rtl_comments: options.enable_ltr_rtl_comments,
+      enable_value_validation: options.enable_value_validation,
+      enable_color_normalization: options.enable_color_normalization,
+      enable_css_source_locations: options.enable_css_source_locations,
+      hot_module_reload: options.hot_module_reload,
+      class_name_hashing: options.class_name_hashing,
+      module_cache_dir: options.module_cache_dir,
       enable_logical_styles_polyfill: options.enable_logical_styles_polyfill,
       enable_legacy_value_flipping: options.enable_legacy_value_flipping,
       enable_minified_keys: options.enable_minified_keys,
       treeshake_compensation: options.treeshake_compensation,
       aliases: options.aliases,
+      warnings: options.warnings,
       unstable_module_resolution: options.unstable_module_resolution,
     }
   }
@@ -47,7 +47,8 @@ mod converting_pre_rule_to_css {
         InjectableStyle {
           ltr: ".x1e2nbdu{color:red}".to_string(),
           rtl: None,
-          priority: Some(3000.0)
+          priority: Some(3000.0),
+          source: None,
         },
         classes_to_original_paths
       )])
@@ -2,7 +2,7 @@ use rustc_hash::FxHashMap;
 
 use crate::shared::{
   constants::common::VAR_GROUP_HASH_KEY,
-  utils::common::{create_hash, gen_file_based_identifier},
+  utils::common::{create_class_name_hash, gen_file_based_identifier},
 };
 
 use super::state_manager::StateManager;
@@ -66,10 +66,14 @@ impl ThemeRef {
           "{}{}{}",
           var_safe_key,
           self.class_name_prefix,
-          create_hash(&str_to_hash)
+          create_class_name_hash(&str_to_hash, &state.options.class_name_hashing)
         )
       } else {
-        format!("{}{}", self.class_name_prefix, create_hash(&str_to_hash))
+        format!(
+          "{}{}",
+          self.class_name_prefix,
+          create_class_name_hash(&str_to_hash, &state.options.class_name_hashing)
+        )
       };
 
       if key == VAR_GROUP_HASH_KEY {
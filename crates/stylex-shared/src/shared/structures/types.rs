@@ -24,6 +24,8 @@ pub(crate) type FunctionMapMemberExpression =
 pub(crate) type FunctionMapIdentifiers = FxHashMap<Atom, Box<FunctionConfigType>>;
 pub(crate) type StylesObjectMap =
   IndexMap<String, Rc<IndexMap<String, Rc<FlatCompiledStylesValue>>>>;
+/// `stylex.create` results exported from a module, keyed by export name
+pub(crate) type ExportedStylesMap = FxHashMap<String, Rc<StylesObjectMap>>;
 pub(crate) type ClassesToOriginalPaths = IndexMap<String, Vec<String>>;
 pub(crate) type ClassPathsInNamespace = ClassesToOriginalPaths;
 pub(crate) type TInlineStyles = IndexMap<String, Box<InlineStyle>>;
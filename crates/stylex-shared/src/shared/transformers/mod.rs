@@ -1,5 +1,6 @@
 pub(crate) mod stylex_create;
 pub(crate) mod stylex_create_theme;
+pub(crate) mod stylex_default_marker;
 pub(crate) mod stylex_define_consts;
 pub(crate) mod stylex_define_vars;
 pub(crate) mod stylex_first_that_works;
@@ -7,4 +8,5 @@ pub(crate) mod stylex_keyframes;
 pub(crate) mod stylex_position_try;
 pub(crate) mod stylex_types;
 pub(crate) mod stylex_view_transition_class;
+pub(crate) mod stylex_when;
 pub(crate) mod tests;
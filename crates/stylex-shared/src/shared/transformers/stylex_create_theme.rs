@@ -14,7 +14,10 @@ use crate::shared::{
   },
   utils::{
     ast::convertors::{expr_to_str, key_value_to_str},
-    common::{create_hash, find_and_swap_remove, get_css_value, get_key_values_from_object},
+    common::{
+      create_class_name_hash, create_hash, find_and_swap_remove, get_css_value,
+      get_key_values_from_object,
+    },
     core::define_vars_utils::{collect_vars_by_at_rules, priority_for_at_rule, wrap_with_at_rules},
     validators::validate_theme_variables,
   },
@@ -113,7 +116,7 @@ pub(crate) fn stylex_create_theme(
   let override_class_name = format!(
     "{}{}",
     state.options.class_name_prefix,
-    create_hash(at_rules_string_for_hash.as_str())
+    create_class_name_hash(&at_rules_string_for_hash, &state.options.class_name_hashing)
   );
 
   let mut resolved_theme_vars = IndexMap::new();
@@ -130,6 +133,7 @@ pub(crate) fn stylex_create_theme(
           ltr: rule,
           rtl: None,
           priority: Some(0.5),
+          source: None,
         })),
       );
     } else {
@@ -143,6 +147,7 @@ pub(crate) fn stylex_create_theme(
           ltr,
           rtl: None,
           priority: Some(priority),
+          source: None,
         })),
       );
     }
@@ -0,0 +1,22 @@
+use swc_core::ecma::ast::Expr;
+
+use crate::shared::{
+  constants::common::COMPILED_KEY,
+  structures::stylex_state_options::StyleXStateOptions,
+  utils::ast::factories::{
+    object_expression_factory, prop_or_spread_boolean_factory, prop_or_spread_string_factory,
+  },
+};
+
+pub(crate) fn default_marker_class_name(options: &StyleXStateOptions) -> String {
+  format!("{}-default-marker", options.class_name_prefix)
+}
+
+pub(crate) fn stylex_default_marker(options: &StyleXStateOptions) -> Expr {
+  let class_name = default_marker_class_name(options);
+
+  object_expression_factory(vec![
+    prop_or_spread_string_factory(&class_name, &class_name),
+    prop_or_spread_boolean_factory(COMPILED_KEY, Some(true)),
+  ])
+}
@@ -11,7 +11,7 @@ use crate::shared::{
   },
   structures::{injectable_style::InjectableConstStyle, state_manager::StateManager},
   utils::{
-    common::{create_hash, serialize_value_to_json_string},
+    common::{create_class_name_hash, serialize_value_to_json_string},
     object::obj_map,
   },
 };
@@ -31,6 +31,7 @@ pub(crate) fn stylex_define_consts(
   let debug = state.options.debug;
   let enable_debug_class_names = state.options.enable_debug_class_names;
   let export_id = state.export_id.clone().expect("Export ID must be set");
+  let class_name_hashing = state.options.class_name_hashing.clone();
 
   let js_output = obj_map(
     ObjMapType::Object(constants.clone()),
@@ -77,13 +78,13 @@ pub(crate) fn stylex_define_consts(
             "{}-{}{}",
             var_safe_key,
             class_name_prefix,
-            create_hash(&format!("{}.{}", export_id, key))
+            create_class_name_hash(&format!("{}.{}", export_id, key), &class_name_hashing)
           )
         } else {
           format!(
             "{}{}",
             class_name_prefix,
-            create_hash(&format!("{}.{}", export_id, key))
+            create_class_name_hash(&format!("{}.{}", export_id, key), &class_name_hashing)
           )
         };
 
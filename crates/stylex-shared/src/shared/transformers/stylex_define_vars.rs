@@ -12,7 +12,7 @@ use crate::shared::{
   },
   structures::{injectable_style::InjectableStyle, state_manager::StateManager},
   utils::{
-    common::{create_hash, get_css_value},
+    common::{create_class_name_hash, get_css_value},
     core::define_vars_utils::construct_css_variables_string,
     object::obj_map,
   },
@@ -28,7 +28,10 @@ pub(crate) fn stylex_define_vars(
   let var_group_hash = format!(
     "{}{}",
     state.options.class_name_prefix,
-    create_hash(state.export_id.as_ref().unwrap())
+    create_class_name_hash(
+      state.export_id.as_ref().unwrap(),
+      &state.options.class_name_hashing
+    )
   );
 
   let mut typed_variables: IndexMap<String, Rc<FlatCompiledStylesValue>> = IndexMap::new();
@@ -70,13 +73,13 @@ pub(crate) fn stylex_define_vars(
               "{}-{}{}",
               var_safe_key,
               &state.options.class_name_prefix,
-              create_hash(str_to_hash.as_str())
+              create_class_name_hash(&str_to_hash, &state.options.class_name_hashing)
             )
           } else {
             &format!(
               "{}{}",
               &state.options.class_name_prefix,
-              create_hash(str_to_hash.as_str())
+              create_class_name_hash(&str_to_hash, &state.options.class_name_hashing)
             )
           };
 
@@ -119,7 +122,9 @@ pub(crate) fn stylex_define_vars(
         FlatCompiledStylesValue::CSSType(name_hash, syntax, initial_value) => {
           let property = format!(
             "@property --{} {{ syntax: \"{}\"; inherits: true; initial-value: {} }}",
-            name_hash, syntax, initial_value
+            name_hash,
+            syntax.to_property_syntax(),
+            initial_value
           );
 
           FlatCompiledStylesValue::InjectableStyle(InjectableStyle {
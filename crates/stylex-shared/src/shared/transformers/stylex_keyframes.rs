@@ -19,7 +19,7 @@ use crate::shared::{
   },
   utils::{
     ast::convertors::{expr_to_str, key_value_to_str, string_to_expression},
-    common::{create_hash, dashify},
+    common::{create_class_name_hash, dashify},
     core::flat_map_expanded_shorthands::flat_map_expanded_shorthands,
     css::{common::transform_value_cached, generate_ltr::generate_ltr, generate_rtl::generate_rtl},
     object::{Pipe, obj_entries, obj_from_entries, obj_map, obj_map_keys_string},
@@ -117,7 +117,10 @@ pub(crate) fn stylex_keyframes(
   let animation_name = format!(
     "{}{}-B",
     class_name_prefix,
-    create_hash(&format!("<>{}", ltr_string))
+    create_class_name_hash(
+      &format!("<>{}", ltr_string),
+      &state.options.class_name_hashing
+    )
   );
 
   let ltr = format!("@keyframes {}{{{}}}", animation_name, ltr_string);
@@ -133,6 +136,7 @@ pub(crate) fn stylex_keyframes(
       ltr,
       rtl,
       priority: Some(0.0),
+      source: None,
     }),
   )
 }
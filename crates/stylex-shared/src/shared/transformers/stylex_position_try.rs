@@ -21,7 +21,7 @@ use crate::shared::{
       convertors::{lit_to_string, string_to_expression},
       factories::{object_lit_factory, prop_or_spread_string_factory},
     },
-    common::{create_hash, dashify},
+    common::{create_class_name_hash, dashify},
     css::{common::transform_value_cached, generate_ltr::generate_ltr, generate_rtl::generate_rtl},
     object::{Pipe, obj_map, obj_map_keys_string, preprocess_object_properties},
   },
@@ -137,7 +137,11 @@ pub(crate) fn stylex_position_try(
   let ltr_string = construct_position_try_obj(ltr_styles);
   let rtl_string = construct_position_try_obj(rtl_styles);
 
-  let position_try_name = format!("--{}{}", class_name_prefix, create_hash(&ltr_string));
+  let position_try_name = format!(
+    "--{}{}",
+    class_name_prefix,
+    create_class_name_hash(&ltr_string, &state.options.class_name_hashing)
+  );
 
   let ltr = format!("@position-try {}{{{}}}", position_try_name, ltr_string);
   let rtl = if ltr_string == rtl_string {
@@ -155,6 +159,7 @@ pub(crate) fn stylex_position_try(
       ltr,
       rtl,
       priority: Some(0.0),
+      source: None,
     }),
   )
 }
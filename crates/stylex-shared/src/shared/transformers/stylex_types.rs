@@ -214,6 +214,36 @@ impl HasBase for TransformList {
     }
   }
 }
+pub struct CustomIdent {
+  base: BaseCSSType,
+}
+
+impl HasBase for CustomIdent {
+  fn new(value: ValueWithDefault) -> Self {
+    CustomIdent {
+      base: BaseCSSType {
+        value,
+        syntax: CSSSyntax::CustomIdent,
+      },
+    }
+  }
+}
+
+pub struct CSSString {
+  base: BaseCSSType,
+}
+
+impl HasBase for CSSString {
+  fn new(value: ValueWithDefault) -> Self {
+    CSSString {
+      base: BaseCSSType {
+        value,
+        syntax: CSSSyntax::String,
+      },
+    }
+  }
+}
+
 fn convert_number_to_string_using(
   transform_number: fn(f64) -> ValueWithDefault,
   default_str: String,
@@ -350,6 +380,18 @@ impl From<Url> for BaseCSSType {
   }
 }
 
+impl From<CustomIdent> for BaseCSSType {
+  fn from(instance: CustomIdent) -> Self {
+    instance.base
+  }
+}
+
+impl From<CSSString> for BaseCSSType {
+  fn from(instance: CSSString) -> Self {
+    instance.base
+  }
+}
+
 impl From<BaseCSSType> for Expr {
   fn from(instance: BaseCSSType) -> Self {
     let syntax_prop =
@@ -434,6 +476,18 @@ fn transform_list(value: ValueWithDefault) -> Expr {
   base_css_type.into()
 }
 
+fn custom_ident(value: ValueWithDefault) -> Expr {
+  let base_css_type: BaseCSSType = CustomIdent::new(value).into();
+
+  base_css_type.into()
+}
+
+fn string(value: ValueWithDefault) -> Expr {
+  let base_css_type: BaseCSSType = CSSString::new(value).into();
+
+  base_css_type.into()
+}
+
 fn url(value: ValueWithDefault) -> Expr {
   let base_css_type: BaseCSSType = Url::new(value).into();
 
@@ -443,6 +497,7 @@ fn url(value: ValueWithDefault) -> Expr {
 pub(crate) static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) -> Expr> = phf_map! {
   "angle" => angle,
   "color" => color,
+  "customIdent" => custom_ident,
   "image" => image,
   "integer" => integer,
   "length" => length,
@@ -450,6 +505,7 @@ pub(crate) static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) ->
   "number" => num,
   "percentage" => percentage,
   "resolution" => resolution,
+  "string" => string,
   "time" => time,
   "transformFunction" => transform_function,
   "transformList" => transform_list,
@@ -11,7 +11,7 @@ use crate::shared::{
   },
   structures::{injectable_style::InjectableStyle, pair::Pair, state_manager::StateManager},
   utils::{
-    common::{create_hash, dashify},
+    common::{create_class_name_hash, dashify},
     css::common::transform_value_cached,
     object::{
       Pipe, obj_map, obj_map_keys_key_value, obj_map_keys_string, preprocess_object_properties,
@@ -74,7 +74,8 @@ pub(crate) fn stylex_view_transition_class(
 
   let string_to_hash = &concat_view_transition_class_style_str(&style_strings, state);
 
-  let view_transition_class_name = class_name_prefix + create_hash(string_to_hash).as_str();
+  let view_transition_class_name = class_name_prefix
+    + create_class_name_hash(string_to_hash, &state.options.class_name_hashing).as_str();
 
   let style = construct_final_view_transition_css_str(style_strings, &view_transition_class_name);
 
@@ -84,6 +85,7 @@ pub(crate) fn stylex_view_transition_class(
       ltr: style,
       rtl: None,
       priority: Some(1.0),
+      source: None,
     }),
   )
 }
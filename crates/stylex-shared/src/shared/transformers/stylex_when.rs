@@ -0,0 +1,79 @@
+use swc_core::ecma::ast::Expr;
+
+use crate::shared::{
+  constants::messages::INVALID_WHEN_PSEUDO,
+  structures::{
+    functions::{FunctionConfig, FunctionType},
+    state_manager::StateManager,
+  },
+  transformers::stylex_default_marker::default_marker_class_name,
+  utils::ast::convertors::{lit_to_string, string_to_expression},
+};
+
+fn validate_pseudo(pseudo: &Expr) -> String {
+  let pseudo = pseudo
+    .as_lit()
+    .and_then(lit_to_string)
+    .unwrap_or_else(|| panic!("{}", INVALID_WHEN_PSEUDO));
+
+  if !pseudo.starts_with(':') || pseudo.starts_with("::") {
+    panic!("{}", INVALID_WHEN_PSEUDO);
+  }
+
+  pseudo
+}
+
+fn marker_selector(pseudo: &Expr, state: &StateManager) -> String {
+  format!(
+    ".{}{}",
+    default_marker_class_name(&state.options),
+    validate_pseudo(pseudo)
+  )
+}
+
+/// Matches when an ancestor carrying the default marker is in the given state
+fn ancestor(pseudo: Expr, state: &mut StateManager) -> Expr {
+  string_to_expression(&format!(":where({} *)", marker_selector(&pseudo, state)))
+}
+
+/// Matches when a descendant carrying the default marker is in the given state
+fn descendant(pseudo: Expr, state: &mut StateManager) -> Expr {
+  string_to_expression(&format!(
+    ":where(:has({}))",
+    marker_selector(&pseudo, state)
+  ))
+}
+
+/// Matches when a preceding sibling carrying the default marker is in the given state
+fn sibling_before(pseudo: Expr, state: &mut StateManager) -> Expr {
+  string_to_expression(&format!(":where({} ~ *)", marker_selector(&pseudo, state)))
+}
+
+/// Matches when a following sibling carrying the default marker is in the given state
+fn sibling_after(pseudo: Expr, state: &mut StateManager) -> Expr {
+  string_to_expression(&format!(
+    ":where(:has(~ {}))",
+    marker_selector(&pseudo, state)
+  ))
+}
+
+/// Matches when any sibling carrying the default marker is in the given state
+fn any_sibling(pseudo: Expr, state: &mut StateManager) -> Expr {
+  let selector = marker_selector(&pseudo, state);
+
+  string_to_expression(&format!(":where({} ~ *, :has(~ {}))", selector, selector))
+}
+
+pub(crate) fn get_when_fn() -> FunctionConfig {
+  FunctionConfig {
+    fn_ptr: FunctionType::StylexExprFnsFactory(|prop_name| match prop_name.as_str() {
+      "ancestor" => ancestor,
+      "descendant" => descendant,
+      "siblingBefore" => sibling_before,
+      "siblingAfter" => sibling_after,
+      "anySibling" => any_sibling,
+      _ => panic!(r#"Function "{}" not found"#, prop_name),
+    }),
+    takes_path: false,
+  }
+}
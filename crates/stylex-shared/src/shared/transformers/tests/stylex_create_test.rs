@@ -250,6 +250,7 @@ mod stylex_create {
             ltr: value.to_string(),
             rtl: None,
             priority: Some(*priority),
+            source: None,
           })),
         );
       }
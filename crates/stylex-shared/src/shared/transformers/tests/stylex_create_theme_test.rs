@@ -42,6 +42,7 @@ mod stylex_create_theme {
           ltr: value.0.to_string(),
           rtl: None,
           priority: Some(value.1),
+          source: None,
         })),
       );
     }
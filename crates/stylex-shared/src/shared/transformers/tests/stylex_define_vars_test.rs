@@ -96,6 +96,7 @@ mod stylex_define_vars {
           ltr: value.0.to_string(),
           rtl: None,
           priority: Some(value.1),
+          source: None,
         })),
       );
     }
@@ -46,6 +46,7 @@ mod stylex_keyframes {
           ltr: value.0.to_string(),
           rtl: None,
           priority: Some(value.1),
+          source: None,
         }),
       );
     }
@@ -86,6 +87,7 @@ mod stylex_keyframes {
         ltr: "@keyframes x1lvx8r0-B{from{left:0;}to{left:500px;}}".to_string(),
         rtl: None,
         priority: Some(0.0),
+        source: None,
       }),
     );
 
@@ -124,4 +124,66 @@ mod class_methods {
 
     assert_type_factory("url", ValueWithDefault::String(value.to_string()), value);
   }
+
+  #[test]
+  fn custom_ident() {
+    let result = FN_MAP.get("customIdent").unwrap()(ValueWithDefault::String("fade".to_string()));
+
+    assert_eq!(
+      result,
+      object_expression_factory(vec![
+        prop_or_spread_string_factory("syntax", "<custom-ident>"),
+        prop_or_spread_string_factory("value", "fade"),
+      ])
+    );
+  }
+
+  #[test]
+  fn string() {
+    let result = FN_MAP.get("string").unwrap()(ValueWithDefault::String("\"a\"".to_string()));
+
+    assert_eq!(
+      result,
+      object_expression_factory(vec![
+        prop_or_spread_string_factory("syntax", "<string>"),
+        prop_or_spread_string_factory("value", "\"a\""),
+      ])
+    );
+  }
+}
+
+#[cfg(test)]
+mod css_syntax {
+  use crate::shared::enums::data_structures::css_syntax::CSSSyntax;
+
+  #[test]
+  fn parses_lists_unions_and_universal_syntax() {
+    assert_eq!(
+      CSSSyntax::from("<length-percentage>+".to_string()),
+      CSSSyntax::List(Box::new(CSSSyntax::LengthPercentage))
+    );
+    assert_eq!(
+      CSSSyntax::from("<number> | <custom-ident>".to_string()),
+      CSSSyntax::Union(vec![CSSSyntax::Number, CSSSyntax::CustomIdent])
+    );
+    assert_eq!(CSSSyntax::from("*".to_string()), CSSSyntax::Universal);
+  }
+
+  #[test]
+  fn validates_values() {
+    let stops = CSSSyntax::from("<length-percentage>+".to_string());
+
+    assert!(stops.is_valid_value("0% calc(50% - 10px) 100%"));
+    assert!(!stops.is_valid_value("0% red"));
+    assert!(CSSSyntax::String.is_valid_value("'hello world'"));
+    assert!(!CSSSyntax::String.is_valid_value("hello"));
+    assert!(CSSSyntax::CustomIdent.is_valid_value("fade-in"));
+    assert!(CSSSyntax::Color.is_valid_value("var(--brand)"));
+  }
+
+  #[test]
+  #[should_panic(expected = r#"CSSSyntax "*+" can only repeat a single component"#)]
+  fn rejects_repeated_universal_syntax() {
+    let _ = CSSSyntax::from("*+".to_string());
+  }
 }
@@ -105,7 +105,7 @@ pub(crate) fn prop_or_spread_array_string_factory(key: &str, value: &[&str]) ->
   prop_or_spread_expression_factory(key, Expr::from(array))
 }
 
-pub(crate) fn _prop_or_spread_boolean_factory(key: &str, value: Option<bool>) -> PropOrSpread {
+pub(crate) fn prop_or_spread_boolean_factory(key: &str, value: Option<bool>) -> PropOrSpread {
   match value {
     Some(value) => prop_or_spread_expression_factory(key, bool_to_expression(value)),
     None => panic!("Value is not a boolean"),
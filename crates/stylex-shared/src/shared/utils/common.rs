@@ -31,6 +31,7 @@ use crate::shared::{
     base_css_type::BaseCSSType,
     functions::{FunctionConfigType, FunctionMap, FunctionType},
     state_manager::StateManager,
+    stylex_options::ClassNameHashing,
   },
 };
 
@@ -61,6 +62,51 @@ pub fn create_hash(value: &str) -> String {
   radix(murmur2::murmur2(value.as_bytes(), 1), 36).to_string()
 }
 
+const BASE36_ALPHABET: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Hashes the value of a class name, variable, keyframes or position-try
+/// rule with the configured class name hashing
+pub(crate) fn create_class_name_hash(value: &str, hashing: &ClassNameHashing) -> String {
+  if hashing.is_default() {
+    return create_hash(value);
+  }
+
+  let value = match &hashing.salt {
+    Some(salt) => format!("{}{}", salt, value),
+    None => value.to_string(),
+  };
+
+  let alphabet = hashing
+    .alphabet
+    .as_deref()
+    .unwrap_or(BASE36_ALPHABET)
+    .chars()
+    .collect::<Vec<_>>();
+
+  let hash = encode_with_alphabet(murmur2::murmur2(value.as_bytes(), 1).into(), &alphabet);
+
+  match hashing.length {
+    Some(length) => hash.chars().take(length).collect(),
+    None => hash,
+  }
+}
+
+fn encode_with_alphabet(mut value: u64, alphabet: &[char]) -> String {
+  let base = alphabet.len() as u64;
+  let mut encoded = vec![];
+
+  loop {
+    encoded.push(alphabet[(value % base) as usize]);
+    value /= base;
+
+    if value == 0 {
+      break;
+    }
+  }
+
+  encoded.iter().rev().collect()
+}
+
 pub(crate) fn wrap_key_in_quotes(key: &str, should_wrap_in_quotes: bool) -> String {
   if should_wrap_in_quotes {
     format!("\"{}\"", key)
@@ -0,0 +1,126 @@
+use indexmap::IndexMap;
+use log::warn;
+use std::rc::Rc;
+use swc_core::{
+  common::{Span, Spanned},
+  ecma::ast::{BlockStmtOrExpr, CallExpr, Expr, ObjectLit, Prop, PropName, PropOrSpread},
+};
+
+use crate::shared::{
+  enums::data_structures::injectable_style::InjectableStyleKind,
+  structures::{
+    injectable_style::SourceLocation, state_manager::StateManager, types::ClassPathsInNamespace,
+  },
+  utils::log::build_code_frame_error::find_expression_in_source_code,
+};
+
+/// Attaches the location of the originating style key to every injected
+/// style of a `stylex.create` call. The deepest key of the class path that
+/// can be found in the source is used, so expanded shorthands point at the
+/// shorthand they came from.
+pub(crate) fn add_source_locations(
+  injected_styles: &mut IndexMap<String, Rc<InjectableStyleKind>>,
+  class_paths_per_namespace: &IndexMap<String, Rc<ClassPathsInNamespace>>,
+  call: &CallExpr,
+  state: &mut StateManager,
+) {
+  let Some(arg) = call.args.first() else {
+    return;
+  };
+
+  let (code_frame, found_expression) =
+    match find_expression_in_source_code(&Expr::Call(call.clone()), &arg.expr, state) {
+      Ok(result) => result,
+      Err(error) => {
+        warn!(
+          "Failed to resolve style source locations: {:?}. File: {}.",
+          error,
+          state.get_filename(),
+        );
+
+        return;
+      }
+    };
+
+  let Some(Expr::Object(namespaces)) = found_expression else {
+    return;
+  };
+
+  let file = state.get_filename().to_string();
+
+  for (namespace, class_paths) in class_paths_per_namespace {
+    let Some((namespace_span, namespace_value)) = find_key(&namespaces, namespace) else {
+      continue;
+    };
+
+    for (class_name, class_path) in class_paths.iter() {
+      let Some(style) = injected_styles.get_mut(class_name) else {
+        continue;
+      };
+
+      let InjectableStyleKind::Regular(regular) = style.as_ref() else {
+        continue;
+      };
+
+      if regular.source.is_some() {
+        continue;
+      }
+
+      let mut span = namespace_span;
+      let mut value = namespace_value;
+
+      for segment in class_path {
+        match as_object(value).and_then(|object| find_key(object, segment)) {
+          Some((key_span, key_value)) => {
+            span = key_span;
+            value = key_value;
+          }
+          None => break,
+        }
+      }
+
+      let (line, column) = code_frame.get_span_line_and_column(span);
+
+      if let InjectableStyleKind::Regular(regular) = Rc::make_mut(style) {
+        regular.source = Some(SourceLocation {
+          file: file.clone(),
+          line,
+          column,
+        });
+      }
+    }
+  }
+}
+
+fn find_key<'a>(object: &'a ObjectLit, key: &str) -> Option<(Span, &'a Expr)> {
+  object.props.iter().find_map(|prop| match prop {
+    PropOrSpread::Prop(prop) => match prop.as_ref() {
+      Prop::KeyValue(key_value) if prop_name_to_string(&key_value.key)? == key => {
+        Some((key_value.key.span(), key_value.value.as_ref()))
+      }
+      _ => None,
+    },
+    PropOrSpread::Spread(_) => None,
+  })
+}
+
+fn as_object(expr: &Expr) -> Option<&ObjectLit> {
+  match expr {
+    Expr::Paren(paren) => as_object(&paren.expr),
+    Expr::Object(object) => Some(object),
+    Expr::Arrow(arrow) => match arrow.body.as_ref() {
+      BlockStmtOrExpr::Expr(body) => as_object(body),
+      BlockStmtOrExpr::BlockStmt(_) => None,
+    },
+    _ => None,
+  }
+}
+
+fn prop_name_to_string(prop_name: &PropName) -> Option<String> {
+  match prop_name {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(strng) => Some(strng.value.to_string()),
+    PropName::Num(num) => Some(num.value.to_string()),
+    _ => None,
+  }
+}
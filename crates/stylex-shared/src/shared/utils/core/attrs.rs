@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+
+use crate::{
+  shared::{
+    enums::data_structures::{
+      flat_compiled_styles_value::FlatCompiledStylesValue, fn_result::FnResult,
+    },
+    utils::{common::dashify, core::js_to_expr::NestedStringObject},
+  },
+  transform::styleq::common::{StyleQResult, styleq},
+};
+
+use super::parse_nullable_style::ResolvedArg;
+
+pub(crate) fn attrs(styles: &[ResolvedArg]) -> Option<FnResult> {
+  let StyleQResult {
+    class_name,
+    inline_style,
+    data_style_src,
+  } = styleq(styles);
+
+  let mut attrs_map: IndexMap<String, Rc<FlatCompiledStylesValue>> = IndexMap::new();
+
+  if !class_name.is_empty() {
+    attrs_map.insert(
+      "class".to_string(),
+      Rc::new(FlatCompiledStylesValue::String(class_name)),
+    );
+  }
+
+  if let Some(inline_style) = inline_style {
+    let style = inline_style
+      .iter()
+      .filter_map(|(key, value)| {
+        value
+          .as_string()
+          .map(|value| format!("{}:{};", dashify(key), value))
+      })
+      .collect::<String>();
+
+    if !style.is_empty() {
+      attrs_map.insert(
+        "style".to_string(),
+        Rc::new(FlatCompiledStylesValue::String(style)),
+      );
+    }
+  }
+
+  if let Some(data_style_src) = data_style_src
+    && !data_style_src.is_empty()
+  {
+    attrs_map.insert(
+      "data-style-src".to_string(),
+      Rc::new(FlatCompiledStylesValue::String(data_style_src)),
+    );
+  }
+
+  Some(FnResult::Props(
+    NestedStringObject::FlatCompiledStylesValues(attrs_map),
+  ))
+}
@@ -1,11 +1,16 @@
+use stylex_css_parser::{at_queries::validate_supports_query, css_types::Color};
+
 use crate::shared::{
   constants::messages::{ILLEGAL_PROP_VALUE, NON_CONTIGUOUS_VARS},
   structures::{
     injectable_style::InjectableStyle, pre_rule::PreRuleValue, state_manager::StateManager,
   },
   utils::{
-    common::{create_hash, dashify},
-    css::common::{generate_css_rule, transform_value_cached},
+    common::{create_class_name_hash, dashify},
+    css::{
+      common::{generate_css_rule, transform_value_cached},
+      validators::property_values::is_color_property,
+    },
     pre_rule::{sort_at_rules, sort_pseudos},
   },
 };
@@ -28,6 +33,9 @@ pub(crate) fn convert_style_to_class_name(
     dashify(key)
   };
 
+  canonicalize_supports_conditions(at_rules);
+  canonicalize_supports_conditions(const_rules);
+
   let unsorted_pseudos = &mut pseudos.to_vec();
   let sorted_pseudos = sort_pseudos(unsorted_pseudos);
 
@@ -77,6 +85,12 @@ pub(crate) fn convert_style_to_class_name(
     PreRuleValue::Expr(_) | PreRuleValue::Null => panic!("{}", ILLEGAL_PROP_VALUE),
   };
 
+  let value = if state.enable_color_normalization() && is_color_property(key) {
+    value.iter().map(|value| minify_color(value)).collect()
+  } else {
+    value
+  };
+
   let string_to_hash = format!(
     "<>{}{}{}",
     dashed_key,
@@ -87,9 +101,18 @@ pub(crate) fn convert_style_to_class_name(
   let prefix = &state.options.class_name_prefix;
 
   let class_name_hashed = if debug && enable_debug_class_names {
-    format!("{}-{}{}", key, prefix, create_hash(&string_to_hash))
+    format!(
+      "{}-{}{}",
+      key,
+      prefix,
+      create_class_name_hash(&string_to_hash, &state.options.class_name_hashing)
+    )
   } else {
-    format!("{}{}", prefix, create_hash(string_to_hash.as_str()))
+    format!(
+      "{}{}",
+      prefix,
+      create_class_name_hash(&string_to_hash, &state.options.class_name_hashing)
+    )
   };
 
   let css_rules = generate_css_rule(
@@ -105,6 +128,28 @@ pub(crate) fn convert_style_to_class_name(
   (key.to_string(), class_name_hashed, css_rules)
 }
 
+/// Rewrites `@supports` at-rules to their canonical form so that formatting
+/// differences such as `(display:grid)` and `(display: grid)` hash the same.
+fn canonicalize_supports_conditions(at_rules: &mut [String]) {
+  for at_rule in at_rules.iter_mut() {
+    if at_rule.starts_with("@supports")
+      && let Ok(supports_query) = validate_supports_query(at_rule)
+    {
+      *at_rule = supports_query.to_string();
+    }
+  }
+}
+
+/// Rewrites a color to its shortest sRGB spelling so that `#FFFFFF`, `white`
+/// and `rgb(255 255 255)` hash the same. Other values are left untouched.
+fn minify_color(value: &str) -> String {
+  Color::parse()
+    .parse_to_end(value)
+    .ok()
+    .and_then(|color| color.minify())
+    .unwrap_or_else(|| value.to_string())
+}
+
 fn variable_fallbacks(values: &[String]) -> Vec<String> {
   let first_var = values
     .iter()
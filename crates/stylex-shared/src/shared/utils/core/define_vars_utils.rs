@@ -50,6 +50,7 @@ pub(crate) fn construct_css_variables_string(
         priority: Some(priority_for_at_rule(at_rule).mul(0.1)),
         ltr,
         rtl: None,
+        source: None,
       })),
     );
   }
@@ -77,7 +78,7 @@ pub(crate) fn collect_vars_by_at_rules(
       hash_name.clone(),
       Rc::new(FlatCompiledStylesValue::CSSType(
         hash_name.clone(),
-        css_type.syntax,
+        css_type.syntax.clone(),
         initial_value.clone(),
       )),
     );
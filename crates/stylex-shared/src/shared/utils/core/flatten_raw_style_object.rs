@@ -1,17 +1,26 @@
 use indexmap::IndexMap;
-use stylex_css_parser::at_queries::media_query_transform::last_media_query_wins_transform;
+use stylex_css_parser::at_queries::{
+  media_query_transform::last_media_query_wins_transform, validate_container_query,
+  validate_supports_query,
+};
 use swc_core::ecma::{
   ast::{Expr, KeyValueProp, Prop, PropName, PropOrSpread},
   utils::quote_str,
 };
 
 use crate::shared::{
-  constants::messages::{
-    ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE, INVALID_MEDIA_QUERY_SYNTAX, non_static_value,
+  constants::{
+    common::LEGACY_SHORTHAND_REPLACEMENTS,
+    messages::{
+      ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE, INVALID_CONTAINER_QUERY_SYNTAX,
+      INVALID_MEDIA_QUERY_SYNTAX, INVALID_SUPPORTS_CONDITION_SYNTAX, invalid_property_value,
+      legacy_shorthand, non_static_value,
+    },
   },
   enums::misc::VarDeclAction,
   regex::CSS_PROPERTY_KEY,
   structures::{
+    diagnostic::LEGACY_SHORTHAND_WARNING_CODE,
     functions::FunctionMap,
     null_pre_rule::NullPreRule,
     order_pair::OrderPair,
@@ -19,6 +28,7 @@ use crate::shared::{
     pre_rule_set::PreRuleSet,
     state::EvaluationState,
     state_manager::StateManager,
+    stylex_options::StyleResolution,
   },
   utils::{
     ast::convertors::{
@@ -26,11 +36,65 @@ use crate::shared::{
       number_to_expression, transform_bin_expr_to_number, transform_shorthand_to_key_values,
     },
     common::{get_expr_from_var_decl, get_key_values_from_object, get_var_decl_by_ident},
+    css::validators::property_values::is_valid_property_value,
   },
 };
 
 use super::flat_map_expanded_shorthands::flat_map_expanded_shorthands;
 
+fn warn_legacy_shorthand(property: &str, state: &mut StateManager) {
+  if state.options.style_resolution != StyleResolution::PropertySpecificity {
+    return;
+  }
+
+  if let Some(replacement) = LEGACY_SHORTHAND_REPLACEMENTS.get(property) {
+    state.add_warning(
+      LEGACY_SHORTHAND_WARNING_CODE,
+      &legacy_shorthand(property, replacement),
+    );
+  }
+}
+
+/// Validates `@container` and `@supports` conditions. Container queries are
+/// printed in their normalized form here so equivalent queries produce the
+/// same class names; `@supports` conditions are canonicalized when hashed.
+fn validate_at_rule_key(key: String) -> String {
+  if key.starts_with("@supports") {
+    assert!(
+      validate_supports_query(&key).is_ok(),
+      "{}",
+      INVALID_SUPPORTS_CONDITION_SYNTAX
+    );
+
+    return key;
+  }
+
+  if !key.starts_with("@container") {
+    return key;
+  }
+
+  match validate_container_query(&key) {
+    Ok(container_query) => container_query.to_string(),
+    Err(_) => panic!("{}", INVALID_CONTAINER_QUERY_SYNTAX),
+  }
+}
+
+/// Rejects static values that don't match the grammar of their property
+/// when value validation is enabled.
+fn validate_property_value(property: &str, value: Option<&String>, state: &StateManager) {
+  if !state.enable_value_validation() {
+    return;
+  }
+
+  if let Some(value) = value {
+    assert!(
+      is_valid_property_value(property, value),
+      "{}",
+      invalid_property_value(property, value)
+    );
+  }
+}
+
 fn normalize_key_path(key_path: Vec<String>, key: &str, property: String) -> Vec<String> {
   if key_path.contains(&key.to_string()) {
     key_path
@@ -80,7 +144,7 @@ pub(crate) fn flatten_raw_style_object_logic(
   let mut flattened: IndexMap<String, PreRules> = IndexMap::new();
 
   for property in style.iter() {
-    let key = key_value_to_str(property);
+    let key = validate_at_rule_key(key_value_to_str(property));
 
     let css_property_key = if CSS_PROPERTY_KEY.is_match(&key) {
       key[4..key.len() - 1].to_string()
@@ -88,6 +152,8 @@ pub(crate) fn flatten_raw_style_object_logic(
       key.clone()
     };
 
+    warn_legacy_shorthand(&css_property_key, traversal_state);
+
     match property.value.as_ref() {
       Expr::Array(property_array) => {
         // Step 1: Expand properties to its constituent parts
@@ -100,10 +166,14 @@ pub(crate) fn flatten_raw_style_object_logic(
           if let Some(property) = each_val {
             match property.expr.as_ref() {
               Expr::Lit(property_lit) => {
+                let value = lit_to_string(property_lit);
+
+                validate_property_value(&css_property_key, value.as_ref(), traversal_state);
+
                 let pairs = flat_map_expanded_shorthands(
                   (
                     css_property_key.clone(),
-                    match lit_to_string(property_lit) {
+                    match value {
                       Some(val) => PreRuleValue::String(val),
                       None => PreRuleValue::Null,
                     },
@@ -167,6 +237,8 @@ pub(crate) fn flatten_raw_style_object_logic(
         if !css_property_key.starts_with(':') && !css_property_key.starts_with('@') {
           let value = lit_to_string(property_lit);
 
+          validate_property_value(&css_property_key, value.as_ref(), traversal_state);
+
           let pairs = flat_map_expanded_shorthands(
             (
               css_property_key,
@@ -263,7 +335,7 @@ pub(crate) fn flatten_raw_style_object_logic(
               if let Prop::KeyValue(key_value) = prop.as_ref() {
                 let mut inner_key_value: KeyValueProp = key_value.clone();
 
-                let condition = key_value_to_str(&inner_key_value);
+                let condition = validate_at_rule_key(key_value_to_str(&inner_key_value));
 
                 inner_key_value.key = PropName::Str(quote_str!(css_property_key.clone()));
 
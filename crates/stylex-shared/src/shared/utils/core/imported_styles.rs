@@ -0,0 +1,368 @@
+use indexmap::IndexMap;
+use log::warn;
+use serde_json::{Map, Value};
+use std::{
+  fs,
+  panic::{self, AssertUnwindSafe},
+  path::{Path, PathBuf},
+  rc::Rc,
+  sync::Arc,
+};
+
+use swc_core::{
+  common::{FileName, SourceMap, comments::NoopComments},
+  ecma::{
+    ast::{
+      Decl, EsVersion, ExportDecl, ExportSpecifier, Ident, ImportSpecifier, Module, ModuleDecl,
+      ModuleExportName, ModuleItem, NamedExport,
+    },
+    parser::{Parser, StringInput, Syntax, TsSyntax, lexer::Lexer},
+    visit::FoldWith,
+  },
+};
+
+use crate::{
+  StyleXTransform,
+  shared::{
+    enums::data_structures::{
+      flat_compiled_styles_value::FlatCompiledStylesValue,
+      import_path_resolution::{ImportPathResolution, ImportPathResolutionType},
+    },
+    structures::{
+      plugin_pass::PluginPass,
+      state_manager::StateManager,
+      stylex_options::StyleXOptions,
+      types::{ExportedStylesMap, StylesObjectMap},
+    },
+    utils::common::get_import_by_ident,
+  },
+};
+
+/// Returns the compiled `stylex.create` result that `ident` was imported as,
+/// when it is a named import from a `.stylex` module that can be resolved
+/// and compiled.
+pub(crate) fn get_imported_style_map(
+  ident: &Ident,
+  state: &mut StateManager,
+) -> Option<Rc<StylesObjectMap>> {
+  let import_decl = get_import_by_ident(ident, state)?;
+
+  let imported_name = import_decl
+    .specifiers
+    .iter()
+    .find_map(|specifier| match specifier {
+      ImportSpecifier::Named(named) if named.local.sym == ident.sym => {
+        Some(match &named.imported {
+          Some(imported) => module_export_name_to_string(imported),
+          None => named.local.sym.to_string(),
+        })
+      }
+      _ => None,
+    })?;
+
+  let ImportPathResolution::Tuple(ImportPathResolutionType::FilePath, file_path) =
+    state.import_file_path_resolver(&import_decl.src.value)
+  else {
+    return None;
+  };
+
+  let exported_styles = match state.imported_style_maps.get(&file_path) {
+    Some(exported_styles) => exported_styles.clone(),
+    None => {
+      let exported_styles = load_exported_styles(&file_path, state).map(Rc::new);
+
+      state
+        .imported_style_maps
+        .insert(file_path, exported_styles.clone());
+
+      exported_styles
+    }
+  }?;
+
+  exported_styles.get(&imported_name).cloned()
+}
+
+/// Returns the `stylex.create` results exported by another module, from the
+/// compiled module cache when the module, its content and the options have
+/// been seen before, be it by another file of the batch or, with
+/// `module_cache_dir`, by a previous build.
+fn load_exported_styles(file_path: &str, state: &StateManager) -> Option<ExportedStylesMap> {
+  let source_code = fs::read_to_string(file_path).ok()?;
+
+  let cache_key = get_cache_key(file_path, &source_code, state);
+
+  let cached_value = state
+    .compiled_module_cache
+    .read()
+    .ok()
+    .and_then(|cache| cache.get(&cache_key).cloned())
+    .or_else(|| {
+      let cached_value = read_cache_file(state, &cache_key)?;
+
+      if let Ok(mut cache) = state.compiled_module_cache.write() {
+        cache.insert(cache_key.clone(), cached_value.clone());
+      }
+
+      Some(cached_value)
+    });
+
+  if let Some(cached_value) = cached_value {
+    return exported_styles_from_json(&cached_value);
+  }
+
+  let exported_styles = compile_exported_styles(file_path, source_code, state);
+
+  // Modules exporting values that cannot be serialized are compiled every time
+  if let Some(cached_value) = exported_styles_to_json(exported_styles.as_ref()) {
+    write_cache_file(state, &cache_key, &cached_value);
+
+    if let Ok(mut cache) = state.compiled_module_cache.write() {
+      cache.insert(cache_key, cached_value);
+    }
+  }
+
+  exported_styles
+}
+
+/// The compiled exports only depend on the module itself and the options it
+/// is compiled with
+fn get_cache_key(file_path: &str, source_code: &str, state: &StateManager) -> String {
+  let mut stylex_import = state
+    .stylex_import
+    .iter()
+    .map(|import| format!("{:?}", import))
+    .collect::<Vec<_>>();
+
+  stylex_import.sort();
+
+  let key = format!(
+    "{}\0{}\0{}\0{:?}\0{:?}",
+    env!("CARGO_PKG_VERSION"),
+    file_path,
+    source_code,
+    state.options,
+    stylex_import
+  );
+
+  format!("{:x}", md5::compute(key.as_bytes()))
+}
+
+fn get_cache_file_path(state: &StateManager, cache_key: &str) -> Option<PathBuf> {
+  let cache_dir = state.options.module_cache_dir.as_ref()?;
+
+  Some(Path::new(cache_dir).join(format!("{}.json", cache_key)))
+}
+
+fn read_cache_file(state: &StateManager, cache_key: &str) -> Option<Value> {
+  let cache_file_path = get_cache_file_path(state, cache_key)?;
+
+  serde_json::from_str(&fs::read_to_string(cache_file_path).ok()?).ok()
+}
+
+fn write_cache_file(state: &StateManager, cache_key: &str, value: &Value) {
+  let Some(cache_file_path) = get_cache_file_path(state, cache_key) else {
+    return;
+  };
+
+  // Written to a temporary file first, so that transforms running in other
+  // processes never read a partially written entry
+  let temp_file_path = cache_file_path.with_extension(format!("{}.tmp", std::process::id()));
+
+  let result = fs::create_dir_all(cache_file_path.parent().unwrap())
+    .and_then(|_| fs::write(&temp_file_path, value.to_string()))
+    .and_then(|_| fs::rename(&temp_file_path, &cache_file_path));
+
+  if let Err(error) = result {
+    warn!(
+      "Failed to write the compiled module cache entry {}: {}",
+      cache_file_path.display(),
+      error
+    );
+  }
+}
+
+/// Serializes the compiled exports, `null` standing for a module without any.
+/// Returns `None` when a value other than a class name, `null` or a boolean
+/// was exported.
+fn exported_styles_to_json(exported_styles: Option<&ExportedStylesMap>) -> Option<Value> {
+  let Some(exported_styles) = exported_styles else {
+    return Some(Value::Null);
+  };
+
+  let mut exports = Map::new();
+
+  for (export_name, namespaces) in exported_styles {
+    let mut namespaces_value = Map::new();
+
+    for (namespace, properties) in namespaces.iter() {
+      let mut properties_value = Map::new();
+
+      for (property, value) in properties.iter() {
+        let value = match value.as_ref() {
+          FlatCompiledStylesValue::String(class_name) => Value::String(class_name.clone()),
+          FlatCompiledStylesValue::Null => Value::Null,
+          FlatCompiledStylesValue::Bool(value) => Value::Bool(*value),
+          _ => return None,
+        };
+
+        properties_value.insert(property.clone(), value);
+      }
+
+      namespaces_value.insert(namespace.clone(), Value::Object(properties_value));
+    }
+
+    exports.insert(export_name.clone(), Value::Object(namespaces_value));
+  }
+
+  Some(Value::Object(exports))
+}
+
+fn exported_styles_from_json(value: &Value) -> Option<ExportedStylesMap> {
+  let exports = value.as_object()?;
+
+  let exported_styles = exports
+    .iter()
+    .map(|(export_name, namespaces)| {
+      let namespaces = namespaces
+        .as_object()?
+        .iter()
+        .map(|(namespace, properties)| {
+          let properties = properties
+            .as_object()?
+            .iter()
+            .map(|(property, value)| {
+              let value = match value {
+                Value::String(class_name) => FlatCompiledStylesValue::String(class_name.clone()),
+                Value::Null => FlatCompiledStylesValue::Null,
+                Value::Bool(value) => FlatCompiledStylesValue::Bool(*value),
+                _ => return None,
+              };
+
+              Some((property.clone(), Rc::new(value)))
+            })
+            .collect::<Option<IndexMap<_, _>>>()?;
+
+          Some((namespace.clone(), Rc::new(properties)))
+        })
+        .collect::<Option<StylesObjectMap>>()?;
+
+      Some((export_name.clone(), Rc::new(namespaces)))
+    })
+    .collect::<Option<ExportedStylesMap>>()?;
+
+  Some(exported_styles)
+}
+
+/// Runs the transform on another module with the current options and
+/// collects the `stylex.create` results it exports.
+fn compile_exported_styles(
+  file_path: &str,
+  source_code: String,
+  state: &StateManager,
+) -> Option<ExportedStylesMap> {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(Arc::new(FileName::Real(file_path.into())), source_code);
+
+  let module = Parser::new_from(Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    EsVersion::latest(),
+    StringInput::from(&*fm),
+    None,
+  ))
+  .parse_module()
+  .ok()?;
+
+  let exported_bindings = get_exported_bindings(&module);
+
+  if exported_bindings.is_empty() {
+    return None;
+  }
+
+  let mut module_state = StateManager::new(StyleXOptions::default());
+
+  module_state.options = state.options.clone();
+  module_state.stylex_import = state.stylex_import.clone();
+  module_state.canonical_path_cache = state.canonical_path_cache.clone();
+  module_state.compiled_module_cache = state.compiled_module_cache.clone();
+  module_state._state = PluginPass {
+    cwd: state._state.cwd.clone(),
+    filename: FileName::Real(file_path.into()),
+  };
+
+  // Mark both modules as in progress so circular imports are not compiled again
+  module_state.imported_style_maps = state.imported_style_maps.clone();
+  module_state
+    .imported_style_maps
+    .insert(file_path.to_string(), None);
+  module_state
+    .imported_style_maps
+    .insert(state.get_filename().to_string(), None);
+
+  let mut stylex = StyleXTransform::with_state(NoopComments, module_state);
+
+  panic::catch_unwind(AssertUnwindSafe(|| module.fold_with(&mut stylex))).ok()?;
+
+  let exported_styles = exported_bindings
+    .into_iter()
+    .filter_map(|(exported, local)| {
+      stylex
+        .state
+        .style_map
+        .get(&local)
+        .map(|style_map| (exported, style_map.clone()))
+    })
+    .collect::<ExportedStylesMap>();
+
+  Some(exported_styles)
+}
+
+/// Pairs of exported name and local binding name of a module
+fn get_exported_bindings(module: &Module) -> Vec<(String, String)> {
+  let mut bindings = vec![];
+
+  for item in &module.body {
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        decl: Decl::Var(var_decl),
+        ..
+      })) => {
+        for declarator in &var_decl.decls {
+          if let Some(ident) = declarator.name.as_ident() {
+            bindings.push((ident.sym.to_string(), ident.sym.to_string()));
+          }
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        specifiers,
+        src: None,
+        ..
+      })) => {
+        for specifier in specifiers {
+          if let ExportSpecifier::Named(named) = specifier {
+            let local = module_export_name_to_string(&named.orig);
+            let exported = named
+              .exported
+              .as_ref()
+              .map(module_export_name_to_string)
+              .unwrap_or_else(|| local.clone());
+
+            bindings.push((exported, local));
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  bindings
+}
+
+fn module_export_name_to_string(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(strng) => strng.value.to_string(),
+  }
+}
@@ -30,13 +30,9 @@ pub(crate) fn make_string_expression(
   values: &[ResolvedArg],
   transform: fn(&[ResolvedArg]) -> Option<FnResult>,
 ) -> Option<Expr> {
-  let conditions = values
-    .iter()
-    .filter_map(|value| match value {
-      ResolvedArg::ConditionalStyle(expr, _, _, _, _) => Some(expr),
-      _ => None,
-    })
-    .collect::<Vec<_>>();
+  let mut conditions = vec![];
+
+  collect_conditions(values, &mut conditions);
 
   if conditions.is_empty() {
     match transform(values) {
@@ -55,26 +51,9 @@ pub(crate) fn make_string_expression(
     .iter()
     .filter_map(|permutation| {
       let mut i = 0;
+      let mut args = vec![];
 
-      let args = values
-        .iter()
-        .filter_map(|arg| match arg {
-          ResolvedArg::StyleObject(_, _, _) => Some(arg.clone()),
-          ResolvedArg::ConditionalStyle(_test, primary, fallback, ident, member) => {
-            let result = if permutation.get(i).unwrap_or(&false) == &true {
-              primary
-            } else {
-              fallback
-            };
-
-            i += 1;
-
-            result
-              .as_ref()
-              .map(|result| ResolvedArg::StyleObject(result.clone(), ident.clone(), member.clone()))
-          }
-        })
-        .collect::<Vec<ResolvedArg>>();
+      select_args(values, permutation, &mut i, true, &mut args);
 
       let key = permutation
         .iter()
@@ -107,6 +86,68 @@ pub(crate) fn make_string_expression(
   }))
 }
 
+/// Collects the conditions of all arguments in the order they are evaluated,
+/// nested conditions following the condition they depend on.
+fn collect_conditions<'a>(values: &'a [ResolvedArg], conditions: &mut Vec<&'a Expr>) {
+  for value in values {
+    match value {
+      ResolvedArg::StyleObject(_, _, _) => {}
+      ResolvedArg::ConditionalStyle(expr, _, _, _, _) => conditions.push(expr),
+      ResolvedArg::ConditionalStyles(expr, consequent, alternate) => {
+        conditions.push(expr);
+        collect_conditions(consequent, conditions);
+        collect_conditions(alternate, conditions);
+      }
+    }
+  }
+}
+
+/// Picks the style objects applied for a permutation of condition values.
+/// Conditions in branches that are not taken still advance the index so it
+/// stays aligned with `collect_conditions`.
+fn select_args(
+  values: &[ResolvedArg],
+  permutation: &[bool],
+  i: &mut usize,
+  is_active: bool,
+  args: &mut Vec<ResolvedArg>,
+) {
+  for value in values {
+    match value {
+      ResolvedArg::StyleObject(_, _, _) => {
+        if is_active {
+          args.push(value.clone());
+        }
+      }
+      ResolvedArg::ConditionalStyle(_test, primary, fallback, ident, member) => {
+        let result = if permutation.get(*i).unwrap_or(&false) == &true {
+          primary
+        } else {
+          fallback
+        };
+
+        *i += 1;
+
+        if is_active && let Some(result) = result {
+          args.push(ResolvedArg::StyleObject(
+            result.clone(),
+            ident.clone(),
+            member.clone(),
+          ));
+        }
+      }
+      ResolvedArg::ConditionalStyles(_test, consequent, alternate) => {
+        let is_truthy = permutation.get(*i).unwrap_or(&false) == &true;
+
+        *i += 1;
+
+        select_args(consequent, permutation, i, is_active && is_truthy, args);
+        select_args(alternate, permutation, i, is_active && !is_truthy, args);
+      }
+    }
+  }
+}
+
 fn gen_bitwise_or_of_conditions(conditions: &[Expr]) -> Box<Expr> {
   let binary_expressions = conditions
     .iter()
@@ -1,14 +1,18 @@
+pub(crate) mod add_source_locations;
 pub(crate) mod add_source_map_data;
+pub(crate) mod attrs;
 pub(crate) mod convert_style_to_class_name;
 pub(crate) mod define_vars_utils;
 pub(crate) mod dev_class_name;
 pub mod evaluate_stylex_create_arg;
 pub(crate) mod flat_map_expanded_shorthands;
 pub(crate) mod flatten_raw_style_object;
+pub(crate) mod imported_styles;
 pub(crate) mod js_to_expr;
 pub(crate) mod make_string_expression;
 pub(crate) mod member_expression;
 pub(crate) mod parse_nullable_style;
+pub mod process_stylex_rules;
 pub(crate) mod props;
 pub(crate) mod stylex;
 pub(crate) mod stylex_merge;
@@ -1,12 +1,17 @@
 use std::rc::Rc;
 
 use indexmap::IndexMap;
-use swc_core::ecma::ast::{Expr, Ident, Lit, MemberExpr, MemberProp};
+use swc_core::ecma::ast::{
+  BinExpr, BinaryOp, CondExpr, Expr, Ident, Lit, MemberExpr, MemberProp, UnaryExpr, UnaryOp,
+};
 
 use crate::shared::{
   enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
   structures::state_manager::StateManager,
-  utils::{ast::convertors::lit_to_string, common::reduce_ident_count},
+  utils::{
+    ast::convertors::lit_to_string, common::reduce_ident_count,
+    core::imported_styles::get_imported_style_map,
+  },
 };
 
 #[derive(Debug, PartialEq, Clone)]
@@ -26,6 +31,26 @@ pub(crate) enum ResolvedArg {
     Ident,
     MemberExpr,
   ),
+  /// A runtime condition choosing between two groups of arguments, each of
+  /// which may contain further conditions
+  ConditionalStyles(Expr, Vec<ResolvedArg>, Vec<ResolvedArg>),
+}
+
+impl ResolvedArg {
+  /// Number of runtime conditions, including nested ones, the argument depends on
+  pub(crate) fn condition_count(&self) -> usize {
+    match self {
+      ResolvedArg::StyleObject(_, _, _) => 0,
+      ResolvedArg::ConditionalStyle(_, _, _, _, _) => 1,
+      ResolvedArg::ConditionalStyles(_, consequent, alternate) => {
+        1 + consequent
+          .iter()
+          .chain(alternate.iter())
+          .map(ResolvedArg::condition_count)
+          .sum::<usize>()
+      }
+    }
+  }
 }
 
 pub(crate) fn parse_nullable_style(
@@ -89,9 +114,195 @@ pub(crate) fn parse_nullable_style(
             return StyleObject::Style((**style_value).clone());
           }
         }
+      } else if let Some(obj_ident) = member.obj.as_ident()
+        && let MemberProp::Ident(prop_ident) = &member.prop
+        && let Some(style) = get_imported_style_map(obj_ident, state)
+        && let Some(style_value) = style.get(prop_ident.sym.as_str())
+      {
+        // Styles created in another module and imported into this one
+        return StyleObject::Style((**style_value).clone());
       }
+
       StyleObject::Other
     }
     _ => StyleObject::Other,
   }
 }
+
+/// Resolves a `props()` argument made of arrays, logical expressions and
+/// (nested) ternaries into style objects selected by runtime conditions.
+///
+/// Returns `None` when any part of the argument cannot be resolved at compile
+/// time. Every member expression used as a style is collected into `members`
+/// once, regardless of how many combinations it ends up in.
+pub(crate) fn resolve_conditional_styles(
+  node: &Expr,
+  state: &mut StateManager,
+  is_nested: bool,
+  members: &mut Vec<MemberExpr>,
+) -> Option<Vec<ResolvedArg>> {
+  match node {
+    Expr::Paren(paren) => resolve_conditional_styles(&paren.expr, state, is_nested, members),
+    Expr::Array(array) => {
+      let mut resolved = vec![];
+
+      for elem in array.elems.iter().flatten() {
+        if elem.spread.is_some() {
+          return None;
+        }
+
+        resolved.extend(resolve_conditional_styles(
+          &elem.expr, state, is_nested, members,
+        )?);
+      }
+
+      Some(resolved)
+    }
+    Expr::Lit(Lit::Bool(boolean)) if !boolean.value => Some(vec![]),
+    Expr::Lit(_) | Expr::Ident(_) => match parse_nullable_style(node, state, false) {
+      StyleObject::Nullable => Some(vec![]),
+      _ => None,
+    },
+    Expr::Member(member) => {
+      let resolved = parse_nullable_style(node, state, false);
+
+      match resolved {
+        StyleObject::Style(_) => {
+          let ident = member.obj.as_ident()?.clone();
+
+          members.push(member.clone());
+
+          Some(vec![ResolvedArg::StyleObject(
+            resolved,
+            ident,
+            member.clone(),
+          )])
+        }
+        StyleObject::Nullable => Some(vec![]),
+        StyleObject::Other => None,
+      }
+    }
+    Expr::Cond(CondExpr {
+      test, cons, alt, ..
+    }) => {
+      if is_nested && !is_safe_condition(test) {
+        return None;
+      }
+
+      let consequent = resolve_conditional_styles(cons, state, true, members)?;
+      let alternate = resolve_conditional_styles(alt, state, true, members)?;
+
+      Some(vec![ResolvedArg::ConditionalStyles(
+        *test.clone(),
+        consequent,
+        alternate,
+      )])
+    }
+    Expr::Bin(BinExpr {
+      left,
+      op: BinaryOp::LogicalAnd,
+      right,
+      ..
+    }) => {
+      if parse_nullable_style(left, state, false) != StyleObject::Other
+        || (is_nested && !is_safe_condition(left))
+      {
+        return None;
+      }
+
+      let consequent = resolve_conditional_styles(right, state, true, members)?;
+
+      Some(vec![ResolvedArg::ConditionalStyles(
+        *left.clone(),
+        consequent,
+        vec![],
+      )])
+    }
+    Expr::Bin(BinExpr {
+      left,
+      op: op @ (BinaryOp::NullishCoalescing | BinaryOp::LogicalOr),
+      right,
+      ..
+    }) => {
+      let fallback = resolve_conditional_styles(right, state, true, members)?;
+
+      resolve_with_fallback(left, *op, fallback, state, is_nested, members)
+    }
+    _ => None,
+  }
+}
+
+/// Resolves `left ?? right` and `left || right`, where `fallback` is the
+/// already resolved right-hand side. Only left-hand sides whose nullishness
+/// (or truthiness) is known at compile time in every branch can be resolved.
+fn resolve_with_fallback(
+  left: &Expr,
+  op: BinaryOp,
+  fallback: Vec<ResolvedArg>,
+  state: &mut StateManager,
+  is_nested: bool,
+  members: &mut Vec<MemberExpr>,
+) -> Option<Vec<ResolvedArg>> {
+  match left {
+    Expr::Paren(paren) => {
+      resolve_with_fallback(&paren.expr, op, fallback, state, is_nested, members)
+    }
+    Expr::Lit(Lit::Bool(boolean)) if !boolean.value => match op {
+      BinaryOp::LogicalOr => Some(fallback),
+      _ => Some(vec![]),
+    },
+    // Arrays and style objects are never nullish nor falsy
+    Expr::Array(_) | Expr::Member(_) => resolve_conditional_styles(left, state, is_nested, members),
+    Expr::Lit(_) | Expr::Ident(_) => match parse_nullable_style(left, state, false) {
+      StyleObject::Nullable => Some(fallback),
+      _ => None,
+    },
+    Expr::Cond(CondExpr {
+      test, cons, alt, ..
+    }) => {
+      if is_nested && !is_safe_condition(test) {
+        return None;
+      }
+
+      let consequent = resolve_with_fallback(cons, op, fallback.clone(), state, true, members)?;
+      let alternate = resolve_with_fallback(alt, op, fallback, state, true, members)?;
+
+      Some(vec![ResolvedArg::ConditionalStyles(
+        *test.clone(),
+        consequent,
+        alternate,
+      )])
+    }
+    _ => None,
+  }
+}
+
+/// Conditions nested in a branch are evaluated eagerly when building the
+/// lookup key, so they must be free of side effects and unable to throw.
+fn is_safe_condition(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::Lit(_) => true,
+    Expr::Paren(paren) => is_safe_condition(&paren.expr),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Bang,
+      arg,
+      ..
+    }) => is_safe_condition(arg),
+    Expr::Bin(BinExpr {
+      left, op, right, ..
+    }) => {
+      matches!(
+        op,
+        BinaryOp::EqEq
+          | BinaryOp::NotEq
+          | BinaryOp::EqEqEq
+          | BinaryOp::NotEqEq
+          | BinaryOp::LogicalAnd
+          | BinaryOp::LogicalOr
+          | BinaryOp::NullishCoalescing
+      ) && is_safe_condition(left)
+        && is_safe_condition(right)
+    }
+    _ => false,
+  }
+}
@@ -0,0 +1,372 @@
+use std::{cmp::Ordering, collections::BTreeSet};
+
+use indexmap::IndexMap;
+use radix_fmt::radix;
+use regex::Captures;
+use rustc_hash::FxHashSet;
+
+use crate::shared::{
+  constants::messages::CIRCULAR_CONST_REFERENCE,
+  enums::data_structures::injectable_style::InjectableStyleBaseKind,
+  regex::{CONST_VAR_REFERENCE_REGEX, CSS_IDENT_REGEX, THEME_ROOT_SELECTOR_REGEX},
+  structures::{
+    meta_data::MetaData,
+    stylex_options::{DirectionSelector, ProcessStylexRulesOptions},
+  },
+};
+
+const LTR_ANCESTOR_SELECTOR: &str = "html:not([dir='rtl'])";
+const RTL_ANCESTOR_SELECTOR: &str = "html[dir='rtl']";
+const SPECIFICITY_BUMP: &str = ":not(#\\#)";
+
+/// Turns the metadata collected from any number of transformed files into
+/// the final stylesheet, mirroring `processStylexRules` of the babel plugin.
+pub fn process_stylex_rules(rules: &[MetaData], options: &ProcessStylexRulesOptions) -> String {
+  if rules.is_empty() {
+    return String::default();
+  }
+
+  let (constant_rules, mut non_constant_rules): (Vec<&MetaData>, Vec<&MetaData>) = rules
+    .iter()
+    .partition(|rule| matches!(rule.get_style(), InjectableStyleBaseKind::Const(_)));
+
+  let consts_map = resolve_constants(&constant_rules);
+
+  non_constant_rules.sort_by(|first, second| compare_rules(first, second));
+
+  let mut grouped: Vec<Vec<(&str, String, Option<String>, f64)>> = vec![];
+  let mut last_priority_level: Option<i64> = None;
+
+  for rule in non_constant_rules {
+    let priority = *rule.get_priority();
+    let priority_level = (priority / 1000.0).floor() as i64;
+
+    let ltr = replace_constants(rule.get_css(), &consts_map);
+    let rtl = rule
+      .get_css_rtl()
+      .map(|rtl| replace_constants(rtl, &consts_map));
+
+    let entry = (rule.get_class_name(), ltr, rtl, priority);
+
+    match grouped.last_mut() {
+      Some(group) if last_priority_level == Some(priority_level) => group.push(entry),
+      _ => {
+        last_priority_level = Some(priority_level);
+        grouped.push(vec![entry]);
+      }
+    }
+  }
+
+  let header = if options.use_layers {
+    format!(
+      "\n@layer {};\n",
+      (1..=grouped.len())
+        .map(|index| format!("priority{}", index))
+        .collect::<Vec<_>>()
+        .join(", ")
+    )
+  } else {
+    String::default()
+  };
+
+  let collected_css = grouped
+    .iter()
+    .enumerate()
+    .map(|(index, group)| {
+      let group_priority = group.first().map_or(0.0, |(_, _, _, priority)| *priority);
+
+      let mut unique_rules: IndexMap<&str, (&str, Option<&str>)> = IndexMap::new();
+
+      for (class_name, ltr, rtl, _) in group {
+        unique_rules.insert(class_name, (ltr.as_str(), rtl.as_deref()));
+      }
+
+      let css = unique_rules
+        .values()
+        .flat_map(|(ltr, rtl)| {
+          let mut ltr_rule = ltr.to_string();
+          let mut rtl_rule = rtl.map(str::to_string);
+
+          if !options.use_layers {
+            ltr_rule = add_specificity_level(&ltr_rule, index);
+            rtl_rule = rtl_rule.map(|rtl| add_specificity_level(&rtl, index));
+          }
+
+          // Theme selectors like `.x1, .x1:root` are doubled so that themes
+          // always take precedence over the default variable values
+          ltr_rule = duplicate_theme_selector(&ltr_rule);
+          rtl_rule = rtl_rule.map(|rtl| duplicate_theme_selector(&rtl));
+
+          match rtl_rule {
+            Some(rtl_rule) if options.enable_ltr_rtl_comments => vec![
+              format!("/* @ltr begin */{}/* @ltr end */", ltr_rule),
+              format!("/* @rtl begin */{}/* @rtl end */", rtl_rule),
+            ],
+            Some(rtl_rule) => match options.direction_selector {
+              DirectionSelector::Ancestor => vec![
+                add_ancestor_selector(&ltr_rule, LTR_ANCESTOR_SELECTOR),
+                add_ancestor_selector(&rtl_rule, RTL_ANCESTOR_SELECTOR),
+              ],
+              DirectionSelector::PseudoClass => vec![
+                add_direction_pseudo_class(&ltr_rule, "ltr"),
+                add_direction_pseudo_class(&rtl_rule, "rtl"),
+              ],
+              DirectionSelector::LogicalOnly => vec![ltr_rule],
+            },
+            None => vec![ltr_rule],
+          }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+      if options.use_layers && group_priority > 0.0 {
+        format!("@layer priority{}{{\n{}\n}}", index + 1, css)
+      } else {
+        css
+      }
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+
+  let css = format!("{}{}", header, collected_css);
+
+  match &options.short_class_name_prefix {
+    Some(prefix) => rename_class_names(&css, &create_short_class_names(rules, prefix)),
+    None => css,
+  }
+}
+
+/// Assigns short sequential names (`x0`, `x1`, ...) to the class names,
+/// keyframes and position-try names of the collected rules. Ids follow the
+/// sorted names, so the same rules get the same ids whatever the order the
+/// files were transformed in. The transformed JS has to be renamed with the
+/// same map, see `rename_class_names`.
+pub fn create_short_class_names(rules: &[MetaData], prefix: &str) -> IndexMap<String, String> {
+  rules
+    .iter()
+    .filter(|rule| {
+      // Custom properties registered with `@property` are also set from JS
+      // as inline styles, so they keep their names
+      matches!(rule.get_style(), InjectableStyleBaseKind::Regular(_))
+        && !rule.get_css().starts_with("@property")
+    })
+    .map(MetaData::get_class_name)
+    .collect::<BTreeSet<_>>()
+    .into_iter()
+    .enumerate()
+    .map(|(index, class_name)| {
+      // Position-try names are dashed identifiers
+      let dashes = if class_name.starts_with("--") {
+        "--"
+      } else {
+        ""
+      };
+
+      (
+        class_name.to_string(),
+        format!("{}{}{}", dashes, prefix, radix(index, 36)),
+      )
+    })
+    .collect()
+}
+
+/// Renames the class names of a stylesheet or of transformed JS with the
+/// map of `create_short_class_names`. Only whole identifiers are replaced.
+pub fn rename_class_names(code: &str, class_names: &IndexMap<String, String>) -> String {
+  if class_names.is_empty() {
+    return code.to_string();
+  }
+
+  CSS_IDENT_REGEX
+    .replace_all(code, |captures: &Captures| {
+      let ident = &captures[0];
+
+      class_names
+        .get(ident)
+        .map_or_else(|| ident.to_string(), String::clone)
+    })
+    .to_string()
+}
+
+fn resolve_constants(constant_rules: &[&MetaData]) -> IndexMap<String, String> {
+  let raw_consts_map = constant_rules
+    .iter()
+    .filter_map(|rule| match rule.get_style() {
+      InjectableStyleBaseKind::Const(style) => Some((
+        format!("var(--{})", rule.get_class_name()),
+        style.const_value.clone(),
+      )),
+      InjectableStyleBaseKind::Regular(_) => None,
+    })
+    .collect::<IndexMap<String, String>>();
+
+  raw_consts_map
+    .iter()
+    .map(|(key, value)| {
+      let mut visited = FxHashSet::default();
+
+      (
+        key.clone(),
+        resolve_constant(value, &raw_consts_map, &mut visited),
+      )
+    })
+    .collect()
+}
+
+fn resolve_constant(
+  value: &str,
+  consts_map: &IndexMap<String, String>,
+  visited: &mut FxHashSet<String>,
+) -> String {
+  let mut result = value.to_string();
+
+  for captures in CONST_VAR_REFERENCE_REGEX.captures_iter(value) {
+    let full_var = &captures[0];
+
+    let Some(const_value) = consts_map.get(full_var) else {
+      continue;
+    };
+
+    // Only the constants on the current resolution path are tracked, so a
+    // constant referenced several times or through sibling paths is fine
+    if !visited.insert(captures[1].to_string()) {
+      panic!("{}", CIRCULAR_CONST_REFERENCE);
+    }
+
+    let resolved_value = resolve_constant(const_value, consts_map, visited);
+
+    visited.remove(&captures[1]);
+
+    result = result.replace(full_var, &resolved_value);
+  }
+
+  result
+}
+
+fn replace_constants(css: &str, consts_map: &IndexMap<String, String>) -> String {
+  consts_map
+    .iter()
+    .fold(css.to_string(), |css, (var_ref, const_value)| {
+      css.replace(var_ref, const_value)
+    })
+}
+
+fn compare_rules(first: &MetaData, second: &MetaData) -> Ordering {
+  let priority_comparison = first.get_priority().total_cmp(second.get_priority());
+
+  if priority_comparison != Ordering::Equal {
+    return priority_comparison;
+  }
+
+  let first_rule = first.get_css();
+  let second_rule = second.get_css();
+
+  if first_rule.starts_with('@') && !second_rule.starts_with('@') {
+    let first_query = &first_rule[..first_rule.find('{').unwrap_or(first_rule.len())];
+    let second_query = &second_rule[..second_rule.find('{').unwrap_or(second_rule.len())];
+
+    if first_query != second_query {
+      return locale_compare(first_query, second_query);
+    }
+  }
+
+  let first_property = &first_rule[first_rule.rfind('{').unwrap_or(0)..];
+  let second_property = &second_rule[second_rule.rfind('{').unwrap_or(0)..];
+
+  locale_compare(first_property, second_property)
+}
+
+/// Approximates `String.prototype.localeCompare` used by the JS implementation:
+/// punctuation sorts before digits, digits before letters, and letters are
+/// compared case-insensitively before falling back to their case.
+fn locale_compare(first: &str, second: &str) -> Ordering {
+  fn collation_key(c: char) -> (u8, char) {
+    if c.is_alphabetic() {
+      (2, c.to_ascii_lowercase())
+    } else if c.is_ascii_digit() {
+      (1, c)
+    } else {
+      (0, c)
+    }
+  }
+
+  first
+    .chars()
+    .map(collation_key)
+    .cmp(second.chars().map(collation_key))
+    .then_with(|| second.cmp(first))
+}
+
+fn add_ancestor_selector(selector: &str, ancestor_selector: &str) -> String {
+  if !selector.starts_with('@') {
+    return format!("{} {}", ancestor_selector, selector);
+  }
+
+  let first_bracket_index = selector.find('{').map_or(selector.len(), |index| index + 1);
+  let (media_query_part, rest) = selector.split_at(first_bracket_index);
+
+  format!("{}{} {}", media_query_part, ancestor_selector, rest)
+}
+
+/// Appends `:dir(<direction>)` to every selector of the innermost rule,
+/// before any pseudo-element.
+fn add_direction_pseudo_class(rule: &str, direction: &str) -> String {
+  if rule.starts_with("@keyframes") {
+    return rule.to_string();
+  }
+
+  let Some(declarations_start) = rule.rfind('{') else {
+    return rule.to_string();
+  };
+  let selectors_start = rule[..declarations_start]
+    .rfind('{')
+    .map_or(0, |index| index + 1);
+
+  let pseudo_class = format!(":dir({})", direction);
+
+  let selectors = rule[selectors_start..declarations_start]
+    .split(',')
+    .map(|selector| {
+      let trimmed = selector.trim_end();
+      let insert_at = trimmed.find("::").unwrap_or(trimmed.len());
+
+      format!(
+        "{}{}{}",
+        &selector[..insert_at],
+        pseudo_class,
+        &selector[insert_at..]
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(",");
+
+  format!(
+    "{}{}{}",
+    &rule[..selectors_start],
+    selectors,
+    &rule[declarations_start..]
+  )
+}
+
+fn add_specificity_level(selector: &str, index: usize) -> String {
+  if selector.starts_with("@keyframes") {
+    return selector.to_string();
+  }
+
+  let pseudo = SPECIFICITY_BUMP.repeat(index);
+
+  let last_open_curly = selector
+    .find("::")
+    .or_else(|| selector.rfind('{'))
+    .unwrap_or(selector.len());
+
+  let (before_curly, after_curly) = selector.split_at(last_open_curly);
+
+  format!("{}{}{}", before_curly, pseudo, after_curly)
+}
+
+fn duplicate_theme_selector(rule: &str) -> String {
+  THEME_ROOT_SELECTOR_REGEX
+    .replace_all(rule, ".$1.$1, .$1.$1:root")
+    .to_string()
+}
@@ -3,7 +3,7 @@ use swc_core::{
   ecma::{
     ast::{
       BinExpr, BinaryOp, CallExpr, CondExpr, Expr, ExprOrSpread, IdentName, JSXAttr, JSXAttrName,
-      JSXAttrOrSpread, JSXAttrValue, Lit, Prop, PropName, PropOrSpread,
+      JSXAttrOrSpread, JSXAttrValue, Lit, MemberExpr, Prop, PropName, PropOrSpread,
     },
     utils::{ExprExt, drop_span},
     visit::FoldWith,
@@ -19,7 +19,9 @@ use crate::shared::{
     common::{reduce_ident_count, reduce_member_expression_count},
     core::{
       make_string_expression::make_string_expression,
-      parse_nullable_style::{ResolvedArg, StyleObject, parse_nullable_style},
+      parse_nullable_style::{
+        ResolvedArg, StyleObject, parse_nullable_style, resolve_conditional_styles,
+      },
     },
   },
 };
@@ -34,6 +36,7 @@ pub(crate) fn stylex_merge(
   let mut current_index = -1;
   let mut bail_out_index = None;
   let mut resolved_args = vec![];
+  let mut nested_members = vec![];
 
   let args = call
     .args
@@ -48,6 +51,7 @@ pub(crate) fn stylex_merge(
   for arg in args.iter() {
     current_index += 1;
 
+    let is_spread = arg.spread.is_some();
     let arg = arg.expr.as_ref();
 
     match &arg {
@@ -72,6 +76,21 @@ pub(crate) fn stylex_merge(
           }
         }
       }
+      Expr::Cond(CondExpr { cons, alt, .. })
+        if parse_nullable_style(cons, state, false).eq(&StyleObject::Other)
+          || parse_nullable_style(alt, state, false).eq(&StyleObject::Other) =>
+      {
+        match resolve_nested_arg(arg, state, &mut resolved_args, &mut nested_members) {
+          Some(count) => conditional += count,
+          None => {
+            parse_nullable_style(cons, state, true);
+            parse_nullable_style(alt, state, true);
+
+            bail_out_index = Some(current_index);
+            bail_out = true;
+          }
+        }
+      }
       Expr::Cond(CondExpr {
         test, cons, alt, ..
       }) => {
@@ -120,10 +139,21 @@ pub(crate) fn stylex_merge(
       Expr::Bin(BinExpr {
         left, op, right, ..
       }) => {
-        if !op.eq(&BinaryOp::LogicalAnd) {
-          bail_out_index = Some(current_index);
-          bail_out = true;
-          break;
+        if !op.eq(&BinaryOp::LogicalAnd)
+          || parse_nullable_style(right, state, false).eq(&StyleObject::Other)
+        {
+          match resolve_nested_arg(arg, state, &mut resolved_args, &mut nested_members) {
+            Some(count) => {
+              conditional += count;
+              continue;
+            }
+            None if !op.eq(&BinaryOp::LogicalAnd) => {
+              bail_out_index = Some(current_index);
+              bail_out = true;
+              break;
+            }
+            None => {}
+          }
         }
 
         let left_resolved = parse_nullable_style(left, state, true);
@@ -161,13 +191,22 @@ pub(crate) fn stylex_merge(
           conditional += 1;
         }
       }
+      _ if !is_spread => {
+        match resolve_nested_arg(arg, state, &mut resolved_args, &mut nested_members) {
+          Some(count) => conditional += count,
+          None => {
+            bail_out_index = Some(current_index);
+            bail_out = true;
+          }
+        }
+      }
       _ => {
         bail_out_index = Some(current_index);
         bail_out = true;
       }
     }
 
-    if conditional > 4 {
+    if conditional > state.inlined_conditional_merge_limit() {
       bail_out = true;
     }
 
@@ -224,9 +263,18 @@ pub(crate) fn stylex_merge(
           reduce_ident_count(state, ident);
           reduce_member_expression_count(state, member_expr)
         }
+        ResolvedArg::ConditionalStyles(_, _, _) => {}
       }
     }
 
+    for member_expr in &nested_members {
+      if let Some(ident) = member_expr.obj.as_ident() {
+        reduce_ident_count(state, ident);
+      }
+
+      reduce_member_expression_count(state, member_expr)
+    }
+
     if let Some(Expr::Object(string_expression)) = string_expression.as_ref() {
       let attr_expr = drop_span(Expr::Call(call.clone()));
 
@@ -285,3 +333,24 @@ pub(crate) fn stylex_merge(
 
   None
 }
+
+/// Resolves an argument that the single ternary and `&&` forms cannot
+/// represent (arrays, `??`, `||` and nested conditions) and returns the number
+/// of runtime conditions it adds.
+fn resolve_nested_arg(
+  arg: &Expr,
+  state: &mut StateManager,
+  resolved_args: &mut Vec<ResolvedArg>,
+  nested_members: &mut Vec<MemberExpr>,
+) -> Option<usize> {
+  let mut members = vec![];
+  let resolved = resolve_conditional_styles(arg, state, false, &mut members)?;
+
+  nested_members.extend(members);
+
+  let count = resolved.iter().map(ResolvedArg::condition_count).sum();
+
+  resolved_args.extend(resolved);
+
+  Some(count)
+}
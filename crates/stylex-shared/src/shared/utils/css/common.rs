@@ -9,10 +9,11 @@ use crate::shared::{
     },
     long_hand_logical::LONG_HAND_LOGICAL,
     long_hand_physical::LONG_HAND_PHYSICAL,
-    messages::LINT_UNCLOSED_FUNCTION,
+    messages::{LINT_UNCLOSED_FUNCTION, font_size_px_to_rem},
     number_properties::NUMBER_PROPERTY_SUFFIXIES,
     priorities::{
       AT_RULE_PRIORITIES, CAMEL_CASE_PRIORITIES, PSEUDO_CLASS_PRIORITIES, PSEUDO_ELEMENT_PRIORITY,
+      RELATIONAL_SELECTOR_PRIORITIES,
     },
     shorthands_of_longhands::SHORTHANDS_OF_LONGHANDS,
     shorthands_of_shorthands::SHORTHANDS_OF_SHORTHANDS,
@@ -20,17 +21,21 @@ use crate::shared::{
   },
   regex::{CLEAN_CSS_VAR, MANY_SPACES},
   structures::{
-    injectable_style::InjectableStyle, pair::Pair, state_manager::StateManager,
-    stylex_state_options::StyleXStateOptions,
+    diagnostic::FONT_SIZE_PX_TO_REM_WARNING_CODE, injectable_style::InjectableStyle, pair::Pair,
+    state_manager::StateManager, stylex_state_options::StyleXStateOptions,
   },
   utils::css::{
     generate_ltr::generate_ltr,
     generate_rtl::generate_rtl,
-    normalizers::{base::base_normalizer, whitespace_normalizer::whitespace_normalizer},
+    normalizers::{
+      base::base_normalizer, calc::calc_normalizer, font::font_normalizer, grid::grid_normalizer,
+      whitespace_normalizer::whitespace_normalizer,
+    },
     validators::unprefixed_custom_properties::unprefixed_custom_properties_validator,
   },
 };
 
+use stylex_css_parser::{at_queries::validate_container_query, css_types::Length};
 use swc_core::{
   common::{BytePos, input::StringInput, source_map::SmallPos},
   css::{
@@ -178,7 +183,37 @@ pub(crate) fn generate_css_rule(
     priority: Some(priority),
     rtl: rtl_rule,
     ltr: ltr_rule,
+    source: None,
+  }
+}
+
+/// Fraction added to the `@container` priority so that, like mobile-first
+/// media queries, wider `min-*` and narrower `max-*` inline-size
+/// containers win over less specific ones.
+pub(crate) fn container_query_priority_offset(key: &str) -> f64 {
+  const MAX_THRESHOLD: f64 = 9999.0;
+
+  let Ok(container_query) = validate_container_query(key) else {
+    return 0.0;
+  };
+
+  let to_px = |length: &Length| match length.unit.as_str() {
+    "px" => Some(length.value as f64),
+    "em" | "rem" => Some(length.value as f64 * 16.0),
+    _ => None,
+  };
+
+  let (lower, upper) = container_query.inline_size_bounds();
+
+  if let Some(px) = lower.as_ref().and_then(to_px) {
+    return px.clamp(0.0, MAX_THRESHOLD) / 100_000.0;
+  }
+
+  if let Some(px) = upper.as_ref().and_then(to_px) {
+    return (MAX_THRESHOLD - px.clamp(0.0, MAX_THRESHOLD)) / 100_000.0;
   }
+
+  0.0
 }
 
 pub(crate) fn get_priority(key: &str) -> f64 {
@@ -199,13 +234,18 @@ pub(crate) fn get_priority(key: &str) -> f64 {
   if key.starts_with("@container") {
     return **AT_RULE_PRIORITIES
       .get("@container")
-      .expect("No priority found");
+      .expect("No priority found")
+      + container_query_priority_offset(key);
   };
 
   if key.starts_with("::") {
     return PSEUDO_ELEMENT_PRIORITY;
   };
 
+  if let Some(priority) = get_relational_selector_priority(key) {
+    return priority;
+  }
+
   if key.starts_with(':') {
     let prop: &str = if key.starts_with(':') && key.contains('(') {
       let index = key.chars().position(|c| c == '(').unwrap();
@@ -237,6 +277,46 @@ pub(crate) fn get_priority(key: &str) -> f64 {
   3000.0
 }
 
+/// Priority of the selectors produced by `when.*`: the relation sets the base
+/// and the marker pseudo-class is added as a fraction of it.
+fn get_relational_selector_priority(key: &str) -> Option<f64> {
+  let (relation, marker_selector) = if let Some(inner) = key
+    .strip_prefix(":where(:has(~ .")
+    .and_then(|rest| rest.strip_suffix("))"))
+  {
+    ("siblingAfter", inner)
+  } else if let Some(inner) = key
+    .strip_prefix(":where(:has(.")
+    .and_then(|rest| rest.strip_suffix("))"))
+  {
+    ("descendant", inner)
+  } else if let Some(rest) = key.strip_prefix(":where(.") {
+    if let Some((inner, _)) = rest.split_once(" ~ *, :has(~ ") {
+      ("anySibling", inner)
+    } else if let Some(inner) = rest.strip_suffix(" ~ *)") {
+      ("siblingBefore", inner)
+    } else if let Some(inner) = rest.strip_suffix(" *)") {
+      ("ancestor", inner)
+    } else {
+      return None;
+    }
+  } else {
+    return None;
+  };
+
+  let (_, pseudo) = marker_selector.split_once("-default-marker")?;
+
+  let base_priority = **RELATIONAL_SELECTOR_PRIORITIES.get(relation)?;
+
+  let pseudo_priority = if pseudo.starts_with(':') {
+    get_priority(pseudo)
+  } else {
+    0.0
+  };
+
+  Some(base_priority + pseudo_priority / 100.0)
+}
+
 pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> String {
   let css_property_value = value.trim();
 
@@ -268,6 +348,9 @@ pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> S
     return format!("\"{}\"", value);
   }
 
+  let value = grid_normalizer(key, &value);
+  let value = font_normalizer(key, &value);
+
   normalize_css_property_value(key, value.as_ref(), &state.options)
 }
 
@@ -282,6 +365,17 @@ pub(crate) fn transform_value_cached(key: &str, value: &str, state: &mut StateMa
 
   let result = transform_value(key, value, state);
 
+  if state.options.enable_font_size_px_to_rem
+    && key == "fontSize"
+    && !value.contains("rem")
+    && result.contains("rem")
+  {
+    state.add_warning(
+      FONT_SIZE_PX_TO_REM_WARNING_CODE,
+      &font_size_px_to_rem(value, &result),
+    );
+  }
+
   state.css_property_seen.insert(cache_key, result.clone());
 
   result
@@ -321,6 +415,9 @@ pub(crate) fn normalize_css_property_value(
     return MANY_SPACES.replace_all(css_property_value, " ").to_string();
   }
 
+  let css_property_value = calc_normalizer(css_property_value);
+  let css_property_value = css_property_value.as_str();
+
   let css_property = if css_property.starts_with("--") {
     "color"
   } else {
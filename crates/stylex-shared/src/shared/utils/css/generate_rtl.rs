@@ -1,13 +1,26 @@
+use stylex_css_parser::{
+  css_types::{
+    Angle, AnglePercentage, Axis, Calc, CalcValue, ColorStopListItem, Gradient, Group, Image,
+    ImageSetSource, Length, LengthPercentage, LinearGradientDirection, Multiplication, Percentage,
+    Rotate3d, SkewAxis, TransformFunction,
+    position::{Horizontal, HorizontalKeyword, Position},
+  },
+  properties::Transform,
+  token_parser::{TokenParser, tokens},
+};
+
 use crate::shared::{
   constants::{
     cursor_flip::CURSOR_FLIP,
     logical_to_rtl::{INLINE_TO_RTL, LOGICAL_TO_RTL},
+    priorities::CAMEL_CASE_PRIORITIES,
   },
   regex::LENGTH_UNIT_TESTER_REGEX,
   structures::{
     pair::Pair, pre_rule::PreRules, stylex_options::StyleResolution,
     stylex_state_options::StyleXStateOptions,
   },
+  utils::css::common::normalize_css_property_value,
 };
 
 fn logical_to_physical_rtl(input: &str) -> Option<&str> {
@@ -50,6 +63,33 @@ fn property_to_rtl(pair: &Pair, options: &StyleXStateOptions) -> Option<Pair> {
         .get(pair.value.as_str())
         .map(|val| Pair::new(pair.key.clone(), val.to_string()))
     }
+    "background-image" | "mask-image" => {
+      if !options.enable_legacy_value_flipping {
+        return None;
+      }
+
+      flip_images(pair.value.as_str()).map(|value| {
+        let value = normalize_css_property_value(pair.key.as_str(), &value, options);
+
+        Pair::new(pair.key.clone(), value)
+      })
+    }
+    "transform" | "translate" | "rotate" | "object-position" | "transform-origin" => {
+      if !options.enable_legacy_value_flipping {
+        return None;
+      }
+
+      let value = match pair.key.as_str() {
+        "transform" => flip_transform(pair.value.as_str()),
+        "translate" => flip_translate(pair.value.as_str()),
+        "rotate" => flip_rotate(pair.value.as_str()),
+        _ => flip_position_value(pair.value.as_str()),
+      }?;
+
+      let value = normalize_css_property_value(pair.key.as_str(), &value, options);
+
+      Some(Pair::new(pair.key.clone(), value))
+    }
     _ => shadows_flip(pair.key.as_str(), pair.value.as_str(), options),
   }
 }
@@ -121,6 +161,324 @@ fn flip_shadow(value: &str) -> Option<String> {
   if rtl != value { Some(rtl) } else { None }
 }
 
+/// Mirrors the direction of linear and radial gradients, e.g. `to left`
+/// becomes `to right` and `45deg` becomes `-45deg`.
+fn flip_images(value: &str) -> Option<String> {
+  let images = Image::list_parser().parse_to_end(value).ok()?;
+  let flipped = images.iter().map(flip_image).collect::<Vec<_>>();
+
+  if flipped == images {
+    return None;
+  }
+
+  Some(
+    flipped
+      .iter()
+      .map(|image| image.to_string())
+      .collect::<Vec<_>>()
+      .join(","),
+  )
+}
+
+fn flip_image(image: &Image) -> Image {
+  match image {
+    Image::Url(_) => image.clone(),
+    Image::Gradient(gradient) => Image::Gradient(Box::new(flip_gradient(gradient))),
+    Image::ImageSet(options) => Image::ImageSet(
+      options
+        .iter()
+        .map(|option| {
+          let mut option = option.clone();
+
+          if let ImageSetSource::Image(image) = &option.image {
+            option.image = ImageSetSource::Image(Box::new(flip_image(image)));
+          }
+
+          option
+        })
+        .collect(),
+    ),
+  }
+}
+
+fn flip_gradient(gradient: &Gradient) -> Gradient {
+  match gradient {
+    Gradient::Linear(linear) => {
+      let mut linear = linear.clone();
+
+      linear.direction = linear.direction.map(|direction| match direction {
+        LinearGradientDirection::Angle(angle) if angle.value != 0.0 => {
+          LinearGradientDirection::Angle(Angle::new(-angle.value, angle.unit))
+        }
+        LinearGradientDirection::ToSide(horizontal, vertical) => {
+          LinearGradientDirection::ToSide(horizontal.map(flip_horizontal_keyword), vertical)
+        }
+        direction => direction,
+      });
+
+      Gradient::Linear(linear)
+    }
+    Gradient::Radial(radial) => {
+      let mut radial = radial.clone();
+      radial.position = radial.position.map(flip_position);
+
+      Gradient::Radial(radial)
+    }
+    // Mirroring a conic gradient reverses the direction its stops are laid out in
+    Gradient::Conic(conic) => {
+      let mut conic = conic.clone();
+
+      conic.from = conic
+        .from
+        .map(|angle| Angle::new(-angle.value, angle.unit))
+        .filter(|angle| angle.value != 0.0);
+      conic.position = conic.position.map(flip_position);
+      conic.stops = conic
+        .stops
+        .into_iter()
+        .rev()
+        .map(|stop| match stop {
+          ColorStopListItem::ColorStop { color, positions } => ColorStopListItem::ColorStop {
+            color,
+            positions: positions.into_iter().rev().map(flip_stop_angle).collect(),
+          },
+          ColorStopListItem::Hint(position) => ColorStopListItem::Hint(flip_stop_angle(position)),
+        })
+        .collect();
+
+      Gradient::Conic(conic)
+    }
+  }
+}
+
+fn flip_stop_angle(position: AnglePercentage) -> AnglePercentage {
+  match position {
+    AnglePercentage::Angle(angle) => {
+      let full_turn = match angle.unit.as_str() {
+        "turn" => 1.0,
+        "rad" => std::f32::consts::TAU,
+        "grad" => 400.0,
+        _ => 360.0,
+      };
+
+      AnglePercentage::Angle(Angle::new(full_turn - angle.value, angle.unit))
+    }
+    AnglePercentage::Percentage(percentage) => {
+      AnglePercentage::Percentage(Percentage::new(100.0 - percentage.value))
+    }
+  }
+}
+
+fn flip_position(position: Position) -> Position {
+  let horizontal = position.horizontal.map(|horizontal| match horizontal {
+    Horizontal::Keyword(keyword) => Horizontal::Keyword(flip_horizontal_keyword(keyword)),
+    Horizontal::KeywordWithOffset(keyword, offset) => {
+      Horizontal::KeywordWithOffset(flip_horizontal_keyword(keyword), offset)
+    }
+    Horizontal::Length(LengthPercentage::Percentage(percentage)) => Horizontal::Length(
+      LengthPercentage::Percentage(Percentage::new(100.0 - percentage.value)),
+    ),
+    horizontal => horizontal,
+  });
+
+  Position::new(horizontal, position.vertical)
+}
+
+/// Mirrors every transform function along the horizontal axis, e.g.
+/// `translateX(10px) rotate(45deg)` becomes `translateX(-10px) rotate(-45deg)`.
+fn flip_transform(value: &str) -> Option<String> {
+  let transform = Transform::parser()
+    .parse_to_end(&camel_case_transform_functions(value))
+    .ok()?;
+  let flipped = Transform::new(
+    transform
+      .value
+      .iter()
+      .map(flip_transform_function)
+      .collect(),
+  );
+
+  (flipped != transform).then(|| flipped.to_string())
+}
+
+/// Normalized values only keep the casing of their first function, e.g.
+/// `translateX(10px) rotatey(45deg)`, while the transform grammar expects
+/// `rotateY`.
+fn camel_case_transform_functions(value: &str) -> String {
+  let mut result = String::with_capacity(value.len());
+  let mut name_start = 0;
+
+  for (index, ch) in value.char_indices() {
+    if ch == '(' {
+      let name = &value[name_start..index];
+
+      result.push_str(CAMEL_CASE_PRIORITIES.get(name).copied().unwrap_or(name));
+      result.push(ch);
+      name_start = index + 1;
+    } else if !(ch.is_ascii_alphanumeric() || ch == '-' || ch == '_') {
+      let end = index + ch.len_utf8();
+
+      result.push_str(&value[name_start..end]);
+      name_start = end;
+    }
+  }
+
+  result.push_str(&value[name_start..]);
+  result
+}
+
+fn flip_transform_function(function: &TransformFunction) -> TransformFunction {
+  let mut function = function.clone();
+
+  match &mut function {
+    TransformFunction::Translate(translate) => {
+      translate.tx = negate_length_percentage(&translate.tx)
+    }
+    TransformFunction::Translate3d(translate) => {
+      translate.tx = negate_length_percentage(&translate.tx)
+    }
+    TransformFunction::TranslateAxis(translate) if translate.axis == Axis::X => {
+      translate.t = negate_length_percentage(&translate.t)
+    }
+    TransformFunction::Rotate(rotate) => rotate.angle = negate_angle(&rotate.angle),
+    TransformFunction::RotateXYZ(rotate) if rotate.axis != Axis::X => {
+      rotate.angle = negate_angle(&rotate.angle)
+    }
+    // Mirroring a rotation mirrors its axis and reverses its direction
+    TransformFunction::Rotate3d(Rotate3d { y, z, .. }) => {
+      *y = -*y;
+      *z = -*z;
+    }
+    TransformFunction::Skew(skew) => {
+      skew.ax = negate_angle(&skew.ax);
+      skew.ay = skew.ay.as_ref().map(negate_angle);
+    }
+    TransformFunction::SkewAxis(SkewAxis { a, .. }) => *a = negate_angle(a),
+    TransformFunction::Matrix(matrix) => {
+      matrix.b = -matrix.b;
+      matrix.c = -matrix.c;
+      matrix.tx = -matrix.tx;
+    }
+    // Entries mixing the x axis with another one change sign (column-major order)
+    TransformFunction::Matrix3d(matrix) => {
+      for (index, arg) in matrix.args.iter_mut().enumerate() {
+        if (index % 4 == 0) != (index / 4 == 0) {
+          *arg = -*arg;
+        }
+      }
+    }
+    _ => {}
+  }
+
+  function
+}
+
+/// Flips the x offset of the `translate` property.
+fn flip_translate(value: &str) -> Option<String> {
+  let offsets =
+    TokenParser::one_or_more_separated_by(LengthPercentage::parser(), tokens::whitespace())
+      .parse_to_end(value)
+      .ok()?;
+
+  let mut flipped = offsets.clone();
+  flipped[0] = negate_length_percentage(&offsets[0]);
+
+  (flipped != offsets).then(|| {
+    flipped
+      .iter()
+      .map(|offset| offset.to_string())
+      .collect::<Vec<_>>()
+      .join(" ")
+  })
+}
+
+/// Flips the `rotate` property, which is an angle optionally preceded by an
+/// axis keyword or vector.
+fn flip_rotate(value: &str) -> Option<String> {
+  let mut parts = value.split_whitespace().collect::<Vec<_>>();
+  let angle = Angle::parser().parse_to_end(parts.pop()?).ok()?;
+
+  if angle.value == 0.0 {
+    return None;
+  }
+
+  let axis = match parts.as_slice() {
+    [] | ["y"] | ["z"] => parts.iter().map(|part| part.to_string()).collect(),
+    [x, y, z] => vec![x.to_string(), flip_sign(y), flip_sign(z)],
+    _ => return None,
+  };
+
+  let angle = match parts.as_slice() {
+    [_, _, _] => angle,
+    _ => negate_angle(&angle),
+  };
+
+  Some(
+    axis
+      .into_iter()
+      .chain(std::iter::once(angle.to_string()))
+      .collect::<Vec<_>>()
+      .join(" "),
+  )
+}
+
+/// Flips `object-position` and `transform-origin`, the latter of which may end
+/// with a z offset.
+fn flip_position_value(value: &str) -> Option<String> {
+  let (position, z_offset) = match Position::parser().parse_to_end(value) {
+    Ok(position) => (position, None),
+    Err(_) => {
+      let (position, z_offset) = value.trim().rsplit_once(' ')?;
+      Length::parser().parse_to_end(z_offset).ok()?;
+
+      (
+        Position::parser().parse_to_end(position).ok()?,
+        Some(z_offset),
+      )
+    }
+  };
+
+  let flipped = flip_position(position.clone());
+
+  if flipped == position {
+    return None;
+  }
+
+  Some(match z_offset {
+    Some(z_offset) => format!("{} {}", flipped, z_offset),
+    None => flipped.to_string(),
+  })
+}
+
+fn negate_angle(angle: &Angle) -> Angle {
+  Angle::new(-angle.value + 0.0, angle.unit.clone())
+}
+
+fn negate_length_percentage(value: &LengthPercentage) -> LengthPercentage {
+  match value {
+    LengthPercentage::Length(length) => {
+      LengthPercentage::Length(Length::new(-length.value + 0.0, length.unit.clone()))
+    }
+    LengthPercentage::Percentage(percentage) => {
+      LengthPercentage::Percentage(Percentage::new(-percentage.value + 0.0))
+    }
+    LengthPercentage::Calc(calc) => {
+      LengthPercentage::Calc(Calc::new(CalcValue::Multiplication(Multiplication::new(
+        CalcValue::Number(-1.0),
+        CalcValue::Group(Group::new(calc.value.clone())),
+      ))))
+    }
+  }
+}
+
+fn flip_horizontal_keyword(keyword: HorizontalKeyword) -> HorizontalKeyword {
+  match keyword {
+    HorizontalKeyword::Left => HorizontalKeyword::Right,
+    HorizontalKeyword::Right => HorizontalKeyword::Left,
+    HorizontalKeyword::Center => HorizontalKeyword::Center,
+  }
+}
+
 fn is_unit(input: &str) -> bool {
   LENGTH_UNIT_TESTER_REGEX.is_match(input)
 }
@@ -0,0 +1,83 @@
+use stylex_css_parser::css_types::{Calc, CalcValue};
+
+/// Evaluates every `calc()` in a value whose operands can be folded at
+/// compile time, e.g. `calc(8px * 2)` becomes `16px`. Expressions that can't
+/// be parsed, such as those containing `var()`, are kept verbatim apart from
+/// the nested `calc()` calls they contain.
+pub(crate) fn calc_normalizer(value: &str) -> String {
+  let mut result = String::with_capacity(value.len());
+  let mut rest = value;
+
+  while let Some(start) = find_calc(rest) {
+    let Some(end) = closing_paren(rest, start + "calc(".len()) else {
+      break;
+    };
+
+    result.push_str(&rest[..start]);
+    result.push_str(&normalize_calc(&rest[start..=end]));
+    rest = &rest[end + 1..];
+  }
+
+  result.push_str(rest);
+  result
+}
+
+fn normalize_calc(expression: &str) -> String {
+  let Ok(calc) = Calc::parser().parse_to_end(expression) else {
+    let inner = &expression["calc(".len()..expression.len() - 1];
+    return format!("calc({})", calc_normalizer(inner));
+  };
+
+  let simplified = calc.simplify();
+
+  if simplified == calc {
+    return expression.to_string();
+  }
+
+  match simplified.value {
+    CalcValue::Number(_) | CalcValue::Dimension(_) | CalcValue::Percentage(_) => {
+      simplified.value.to_string()
+    }
+    _ => simplified.to_string(),
+  }
+}
+
+/// Position of the next `calc(` that isn't part of a longer function name
+fn find_calc(value: &str) -> Option<usize> {
+  let mut offset = 0;
+
+  while let Some(position) = value[offset..].find("calc(") {
+    let start = offset + position;
+    let is_function_start = value[..start]
+      .chars()
+      .next_back()
+      .is_none_or(|c| !(c.is_alphanumeric() || c == '-' || c == '_'));
+
+    if is_function_start {
+      return Some(start);
+    }
+
+    offset = start + "calc(".len();
+  }
+
+  None
+}
+
+fn closing_paren(value: &str, from: usize) -> Option<usize> {
+  let mut depth = 1;
+
+  for (index, c) in value[from..].char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => {
+        depth -= 1;
+        if depth == 0 {
+          return Some(from + index);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  None
+}
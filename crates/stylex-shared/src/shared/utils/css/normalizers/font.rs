@@ -0,0 +1,18 @@
+use stylex_css_parser::properties::{Font, FontFamily};
+
+/// Prints font families in a canonical form so that quoted and unquoted names
+/// generate the same class name. Values that can't be parsed are returned
+/// unchanged.
+pub(crate) fn font_normalizer(key: &str, value: &str) -> String {
+  let normalized = match key {
+    "fontFamily" | "font-family" => FontFamily::parser()
+      .parse_to_end(value)
+      .map(|family| family.to_string()),
+    "font" => Font::parser()
+      .parse_to_end(value)
+      .map(|font| font.to_string()),
+    _ => return value.to_string(),
+  };
+
+  normalized.unwrap_or_else(|_| value.to_string())
+}
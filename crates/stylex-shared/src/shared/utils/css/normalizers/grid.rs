@@ -0,0 +1,46 @@
+use stylex_css_parser::properties::{GridArea, GridTemplateAreas, GridTemplateTracks};
+
+use crate::shared::{
+  constants::messages::invalid_grid_template_areas,
+  utils::css::validators::property_values::CSS_WIDE_KEYWORDS,
+};
+
+/// Prints grid values in a canonical form so that equivalent declarations
+/// generate the same class name. Values that can't be parsed, e.g. ones that
+/// use variables, are returned unchanged, except for malformed
+/// `grid-template-areas` strings, which are rejected.
+pub(crate) fn grid_normalizer(key: &str, value: &str) -> String {
+  let normalized = match key {
+    "gridTemplateColumns" | "gridTemplateRows" | "grid-template-columns" | "grid-template-rows" => {
+      GridTemplateTracks::parser()
+        .parse_to_end(value)
+        .map(|tracks| tracks.to_string())
+    }
+    "gridArea" | "grid-area" => GridArea::parser()
+      .parse_to_end(value)
+      .map(|area| area.to_string()),
+    "gridRow" | "gridColumn" | "grid-row" | "grid-column" => GridArea::line_pair_parser()
+      .parse_to_end(value)
+      .map(|area| area.to_string()),
+    "gridTemplateAreas" | "grid-template-areas" => {
+      // Rows wrapped in an extra pair of quotes, e.g. `'"a" "b"'`, are unwrapped
+      let rows = value
+        .trim()
+        .strip_prefix('\'')
+        .and_then(|rows| rows.strip_suffix('\''))
+        .filter(|rows| rows.starts_with('"'))
+        .unwrap_or(value);
+
+      let areas = GridTemplateAreas::parser().parse_to_end(rows);
+
+      if areas.is_err() && !value.contains("var(") && !CSS_WIDE_KEYWORDS.contains(&value) {
+        panic!("{}", invalid_grid_template_areas(value));
+      }
+
+      areas.map(|areas| areas.to_string())
+    }
+    _ => return value.to_string(),
+  };
+
+  normalized.unwrap_or_else(|_| value.to_string())
+}
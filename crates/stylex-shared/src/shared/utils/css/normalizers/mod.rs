@@ -1,3 +1,6 @@
 pub(crate) mod base;
+pub(crate) mod calc;
+pub(crate) mod font;
+pub(crate) mod grid;
 pub(crate) mod tests;
 pub(crate) mod whitespace_normalizer;
@@ -1,4 +1,5 @@
 pub(crate) mod css_custom_properties_validation_test;
 pub(crate) mod css_tests;
+pub(crate) mod property_value_validation_test;
 pub(crate) mod split_value_test;
 pub(crate) mod transform_value_test;
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod property_value_validation {
+  use crate::shared::utils::css::validators::property_values::is_valid_property_value;
+
+  #[test]
+  fn accepts_valid_values() {
+    let values = [
+      ("color", "red"),
+      ("color", "#fff"),
+      ("backgroundColor", "rgb(0 0 0 / 0.5)"),
+      ("width", "100%"),
+      ("width", "10"),
+      ("maxHeight", "fit-content"),
+      ("transform", "translateX(10px) rotate(45deg)"),
+      ("transform", "none"),
+      ("boxShadow", "0 1px 2px rgba(0, 0, 0, 0.1)"),
+      ("borderRadius", "4px 8px"),
+      ("borderTopLeftRadius", "10%"),
+      ("filter", "blur(2px) brightness(0.5)"),
+      (
+        "transitionTimingFunction",
+        "ease-in, cubic-bezier(0.1, 0.7, 1, 0.1)",
+      ),
+      ("clipPath", "circle(50%)"),
+      (
+        "backgroundImage",
+        "linear-gradient(to left, red, blue), url(bg.png)",
+      ),
+      (
+        "maskImage",
+        "radial-gradient(circle at center, black 50%, transparent)",
+      ),
+      (
+        "listStyleImage",
+        "image-set(\"dot.png\" 1x, \"dot-2x.png\" 2x)",
+      ),
+    ];
+
+    for (property, value) in values {
+      assert!(
+        is_valid_property_value(property, value),
+        "Expected `{}: {}` to be valid",
+        property,
+        value
+      );
+    }
+  }
+
+  #[test]
+  fn rejects_invalid_values() {
+    let values = [
+      ("color", "redd"),
+      ("width", "10deg"),
+      ("transform", "rotate(10)"),
+      ("boxShadow", "red"),
+      ("filter", "blur(red)"),
+      ("animationTimingFunction", "ease-sideways"),
+      ("clipPath", "square(10px)"),
+      ("backgroundImage", "linear-gradient(to center, red, blue)"),
+      ("listStyleImage", "url(a.png), url(b.png)"),
+    ];
+
+    for (property, value) in values {
+      assert!(
+        !is_valid_property_value(property, value),
+        "Expected `{}: {}` to be invalid",
+        property,
+        value
+      );
+    }
+  }
+
+  #[test]
+  fn accepts_values_that_cannot_be_checked() {
+    assert!(is_valid_property_value("color", "inherit"));
+    assert!(is_valid_property_value("color", "var(--accent)"));
+    assert!(is_valid_property_value(
+      "width",
+      "calc(100% - env(safe-area-inset-left))"
+    ));
+    assert!(is_valid_property_value("color", "red !important"));
+    assert!(is_valid_property_value("display", "anything"));
+    assert!(is_valid_property_value("width", "min(100%, 600px)"));
+    assert!(is_valid_property_value(
+      "maxWidth",
+      "clamp(200px, 50vw, 800px)"
+    ));
+    assert!(is_valid_property_value("width", "calc(max(10px, 1em) * 2)"));
+    assert!(is_valid_property_value("color", "light-dark(#000, #fff)"));
+    assert!(is_valid_property_value("color", "hwb(120 10% 20%)"));
+    assert!(is_valid_property_value("color", "lab(50% 40 59.5)"));
+    assert!(is_valid_property_value("color", "color(display-p3 1 0 0)"));
+    assert!(is_valid_property_value(
+      "backgroundColor",
+      "color-mix(in srgb, red 50%, blue)"
+    ));
+    assert!(is_valid_property_value("color", "CanvasText"));
+    assert!(is_valid_property_value(
+      "filter",
+      "drop-shadow(0 0 2px red) blur(2px)"
+    ));
+  }
+}
@@ -1 +1,2 @@
+pub(crate) mod property_values;
 pub(crate) mod unprefixed_custom_properties;
@@ -0,0 +1,269 @@
+use stylex_css_parser::{
+  css_types::{BasicShape, Color, EasingFunction, FilterFunction, Image, LengthPercentage, Number},
+  properties::{BorderRadiusIndividual, BorderRadiusShorthand, BoxShadowList, Transform},
+};
+
+pub(crate) const CSS_WIDE_KEYWORDS: [&str; 5] =
+  ["inherit", "initial", "revert", "revert-layer", "unset"];
+
+/// CSS functions that are valid in the validated properties but are not
+/// modeled by the value grammars
+const UNMODELED_FUNCTIONS: [&str; 35] = [
+  "var",
+  "env",
+  "attr",
+  "min",
+  "max",
+  "clamp",
+  "round",
+  "mod",
+  "rem",
+  "abs",
+  "sign",
+  "sin",
+  "cos",
+  "tan",
+  "asin",
+  "acos",
+  "atan",
+  "atan2",
+  "pow",
+  "sqrt",
+  "hypot",
+  "log",
+  "exp",
+  "anchor",
+  "anchor-size",
+  "hwb",
+  "lab",
+  "color",
+  "color-mix",
+  "light-dark",
+  "device-cmyk",
+  "drop-shadow",
+  "rect",
+  "xywh",
+  "cross-fade",
+];
+
+/// System colors, which are matched case-insensitively
+const SYSTEM_COLORS: [&str; 19] = [
+  "accentcolor",
+  "accentcolortext",
+  "activetext",
+  "buttonborder",
+  "buttonface",
+  "buttontext",
+  "canvas",
+  "canvastext",
+  "field",
+  "fieldtext",
+  "graytext",
+  "highlight",
+  "highlighttext",
+  "linktext",
+  "mark",
+  "marktext",
+  "selecteditem",
+  "selecteditemtext",
+  "visitedtext",
+];
+
+/// Value grammars of the properties that can be validated
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueGrammar {
+  Color,
+  Length,
+  Transform,
+  BoxShadow,
+  BorderRadius,
+  BorderCornerRadius,
+  Filter,
+  Easing,
+  BasicShape,
+  Image,
+  ImageList,
+}
+
+pub(crate) fn is_color_property(property: &str) -> bool {
+  get_value_grammar(property) == Some(ValueGrammar::Color)
+}
+
+fn get_value_grammar(property: &str) -> Option<ValueGrammar> {
+  let grammar = match property {
+    "color"
+    | "backgroundColor"
+    | "borderTopColor"
+    | "borderRightColor"
+    | "borderBottomColor"
+    | "borderLeftColor"
+    | "borderBlockStartColor"
+    | "borderBlockEndColor"
+    | "borderInlineStartColor"
+    | "borderInlineEndColor"
+    | "outlineColor"
+    | "caretColor"
+    | "accentColor"
+    | "columnRuleColor"
+    | "textDecorationColor"
+    | "textEmphasisColor"
+    | "floodColor"
+    | "lightingColor"
+    | "stopColor" => ValueGrammar::Color,
+    "width" | "height" | "minWidth" | "minHeight" | "maxWidth" | "maxHeight" | "inlineSize"
+    | "blockSize" | "minInlineSize" | "minBlockSize" | "maxInlineSize" | "maxBlockSize" | "top"
+    | "right" | "bottom" | "left" | "insetBlockStart" | "insetBlockEnd" | "insetInlineStart"
+    | "insetInlineEnd" | "rowGap" | "columnGap" | "flexBasis" | "outlineOffset" | "textIndent" => {
+      ValueGrammar::Length
+    }
+    "transform" => ValueGrammar::Transform,
+    "boxShadow" => ValueGrammar::BoxShadow,
+    "borderRadius" => ValueGrammar::BorderRadius,
+    "borderTopLeftRadius"
+    | "borderTopRightRadius"
+    | "borderBottomLeftRadius"
+    | "borderBottomRightRadius"
+    | "borderStartStartRadius"
+    | "borderStartEndRadius"
+    | "borderEndStartRadius"
+    | "borderEndEndRadius" => ValueGrammar::BorderCornerRadius,
+    "filter" | "backdropFilter" => ValueGrammar::Filter,
+    "transitionTimingFunction" | "animationTimingFunction" => ValueGrammar::Easing,
+    "clipPath" | "shapeOutside" => ValueGrammar::BasicShape,
+    "listStyleImage" | "borderImageSource" => ValueGrammar::Image,
+    "backgroundImage" | "maskImage" => ValueGrammar::ImageList,
+    _ => return None,
+  };
+
+  Some(grammar)
+}
+
+/// Keywords accepted by a grammar in addition to the parsed values
+fn is_grammar_keyword(grammar: ValueGrammar, value: &str) -> bool {
+  match grammar {
+    ValueGrammar::Color => {
+      matches!(value, "currentcolor" | "currentColor" | "transparent")
+        || SYSTEM_COLORS.contains(&value.to_lowercase().as_str())
+    }
+    ValueGrammar::Length => matches!(
+      value,
+      "auto" | "none" | "normal" | "content" | "min-content" | "max-content" | "fit-content"
+    ),
+    ValueGrammar::Transform
+    | ValueGrammar::BoxShadow
+    | ValueGrammar::Filter
+    | ValueGrammar::BasicShape
+    | ValueGrammar::Image
+    | ValueGrammar::ImageList => value == "none",
+    ValueGrammar::BorderRadius | ValueGrammar::BorderCornerRadius | ValueGrammar::Easing => false,
+  }
+}
+
+/// Checks a static value against the grammar of its property. Properties
+/// without a known grammar, CSS-wide keywords and values that use functions
+/// the grammars do not model, such as `var()` or `min()`, cannot be checked
+/// and are accepted.
+pub(crate) fn is_valid_property_value(property: &str, value: &str) -> bool {
+  let Some(grammar) = get_value_grammar(property) else {
+    return true;
+  };
+
+  let value = value.trim().trim_end_matches("!important").trim_end();
+
+  if value.is_empty()
+    || CSS_WIDE_KEYWORDS.contains(&value)
+    || is_grammar_keyword(grammar, value)
+    || uses_unmodeled_function(value)
+  {
+    return true;
+  }
+
+  match grammar {
+    ValueGrammar::Color => Color::parse().parse_to_end(value).is_ok(),
+    // Unitless numbers are converted to `px` later on
+    ValueGrammar::Length => {
+      Number::parser().parse_to_end(value).is_ok()
+        || LengthPercentage::parser().parse_to_end(value).is_ok()
+        || value.starts_with("fit-content(")
+    }
+    ValueGrammar::Transform => Transform::parser().parse_to_end(value).is_ok(),
+    ValueGrammar::BoxShadow => BoxShadowList::parser().parse_to_end(value).is_ok(),
+    ValueGrammar::BorderRadius => {
+      Number::parser().parse_to_end(value).is_ok()
+        || BorderRadiusShorthand::parser().parse_to_end(value).is_ok()
+    }
+    ValueGrammar::BorderCornerRadius => {
+      Number::parser().parse_to_end(value).is_ok()
+        || BorderRadiusIndividual::parser().parse_to_end(value).is_ok()
+    }
+    ValueGrammar::Filter => split_top_level(value, char::is_whitespace)
+      .iter()
+      .all(|function| {
+        function.starts_with("url(") || FilterFunction::parser().parse_to_end(function).is_ok()
+      }),
+    ValueGrammar::Easing => split_top_level(value, |ch| ch == ',')
+      .iter()
+      .all(|easing| EasingFunction::parse().parse_to_end(easing.trim()).is_ok()),
+    ValueGrammar::BasicShape => {
+      value.starts_with("url(")
+        || split_top_level(value, char::is_whitespace)
+          .iter()
+          .any(|part| BasicShape::parse().parse_to_end(part).is_ok())
+    }
+    ValueGrammar::Image => Image::parser().parse_to_end(value).is_ok(),
+    ValueGrammar::ImageList => Image::list_parser().parse_to_end(value).is_ok(),
+  }
+}
+
+fn uses_unmodeled_function(value: &str) -> bool {
+  let mut name = String::new();
+
+  for ch in value.chars() {
+    match ch {
+      '(' if UNMODELED_FUNCTIONS.contains(&name.to_lowercase().as_str()) => return true,
+      ch if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' => name.push(ch),
+      _ => name.clear(),
+    }
+  }
+
+  false
+}
+
+/// Splits a value on separators that are not nested in a function or a string
+pub(crate) fn split_top_level(value: &str, is_separator: fn(char) -> bool) -> Vec<String> {
+  let mut items = vec![];
+  let mut current = String::new();
+  let mut depth = 0;
+  let mut quote: Option<char> = None;
+
+  for ch in value.chars() {
+    match (ch, quote) {
+      (_, Some(open)) => {
+        if ch == open {
+          quote = None;
+        }
+      }
+      ('"' | '\'', None) => quote = Some(ch),
+      ('(', None) => depth += 1,
+      (')', None) => depth -= 1,
+      (ch, None) if depth == 0 && is_separator(ch) => {
+        if !current.trim().is_empty() {
+          items.push(std::mem::take(&mut current));
+        } else {
+          current.clear();
+        }
+
+        continue;
+      }
+      _ => {}
+    }
+
+    current.push(ch);
+  }
+
+  if !current.trim().is_empty() {
+    items.push(current);
+  }
+
+  items
+}
@@ -275,6 +275,9 @@ fn _evaluate(
             FunctionType::Mapper(func) => {
               return Some(EvaluateResultValue::Expr(func()));
             }
+            FunctionType::StylexFnsFactory(_) | FunctionType::StylexExprFnsFactory(_) => {
+              return Some(EvaluateResultValue::FunctionConfig(func.clone()));
+            }
             _ => {
               return deopt(path, state, "Function not found");
             }
@@ -1696,6 +1699,12 @@ fn _evaluate(
                         EvaluateResultValue::Entries(IndexMap::default()),
                       )]);
                     }
+                    FunctionType::StylexExprFnsFactory(sxfns) => {
+                      func = Some(Box::new(FunctionConfig {
+                        fn_ptr: FunctionType::StylexExprFn(sxfns(prop_name)),
+                        takes_path: false,
+                      }));
+                    }
                     _ => build_code_frame_error_and_panic(
                       &Expr::Paren(ParenExpr {
                         span: DUMMY_SP,
@@ -1763,15 +1772,17 @@ fn _evaluate(
               "StylexFnsFactory not implemented",
               traversal_state,
             ),
-            FunctionType::StylexFnsFactory(_) => build_code_frame_error_and_panic(
-              &Expr::Paren(ParenExpr {
-                span: DUMMY_SP,
-                expr: Box::new(path.clone()),
-              }),
-              path,
-              "StylexFnsFactory",
-              traversal_state,
-            ),
+            FunctionType::StylexFnsFactory(_) | FunctionType::StylexExprFnsFactory(_) => {
+              build_code_frame_error_and_panic(
+                &Expr::Paren(ParenExpr {
+                  span: DUMMY_SP,
+                  expr: Box::new(path.clone()),
+                }),
+                path,
+                "StylexFnsFactory",
+                traversal_state,
+              )
+            }
             FunctionType::Callback(_) => build_code_frame_error_and_panic(
               &Expr::Paren(ParenExpr {
                 span: DUMMY_SP,
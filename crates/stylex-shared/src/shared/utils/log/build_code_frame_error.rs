@@ -17,7 +17,13 @@ use swc_core::{
   },
 };
 
-use crate::shared::{regex::URL_REGEX, structures::state_manager::StateManager};
+use crate::shared::{
+  regex::URL_REGEX,
+  structures::{
+    diagnostic::{Diagnostic, DiagnosticSpan, TRANSFORM_ERROR_CODE},
+    state_manager::StateManager,
+  },
+};
 
 pub(crate) struct CodeFrame {
   source_map: Arc<SourceMap>,
@@ -59,27 +65,69 @@ impl CodeFrame {
     loc.line
   }
 
-  // fn format_code_frame(&self, span: Span) -> String {
-  //   let loc = self.source_map.lookup_char_pos(span.lo);
-  //   let file = loc.file;
-  //   let start_line = loc.line.saturating_sub(2);
-  //   let end_line = loc.line + 2;
-
-  //   (start_line..=end_line)
-  //     .filter_map(|line_idx| {
-  //       file.get_line(line_idx).map(|line| {
-  //         let mut output = format!("  {}\n", line);
-  //         if line_idx == loc.line - 1 {
-  //           output.push_str(&format!(
-  //             "  {}{}\n",
-  //             " ".repeat(loc.col.0),
-  //             "^".repeat((span.hi - span.lo).0 as usize)
-  //           ));
-  //         }
-  //         output
-  //       })
-  //     })
-  //     .collect()
+  /// Returns the 1-based line and 0-based column of the start of the span
+  pub(crate) fn get_span_line_and_column(&self, span: Span) -> (usize, usize) {
+    let loc = self.source_map.lookup_char_pos(span.lo);
+
+    (loc.line, loc.col.0)
+  }
+
+  /// Renders the lines around the span with the span underlined
+  fn format_code_frame(&self, span: Span) -> String {
+    let loc = self.source_map.lookup_char_pos(span.lo);
+    let file = loc.file;
+    let start_line = loc.line.saturating_sub(3);
+    let end_line = loc.line + 1;
+    let gutter_width = (end_line + 1).to_string().len();
+
+    (start_line..=end_line)
+      .filter_map(|line_idx| {
+        file.get_line(line_idx).map(|line| {
+          let is_error_line = line_idx == loc.line - 1;
+
+          let mut output = format!(
+            "{} {:>width$} | {}\n",
+            if is_error_line { ">" } else { " " },
+            line_idx + 1,
+            line,
+            width = gutter_width
+          );
+
+          if is_error_line {
+            let underline_len = ((span.hi - span.lo).0 as usize)
+              .min(line.chars().count().saturating_sub(loc.col.0))
+              .max(1);
+
+            output.push_str(&format!(
+              "  {:>width$} | {}{}\n",
+              "",
+              " ".repeat(loc.col.0),
+              "^".repeat(underline_len),
+              width = gutter_width
+            ));
+          }
+
+          output
+        })
+      })
+      .collect()
+  }
+
+  fn create_diagnostic(&self, span: Span, message: &str, file: &str) -> Diagnostic {
+    let loc = self.source_map.lookup_char_pos(span.lo);
+    let file_start = loc.file.start_pos;
+
+    Diagnostic {
+      span: Some(DiagnosticSpan {
+        start: (span.lo - file_start).0,
+        end: (span.hi - file_start).0,
+      }),
+      line: Some(loc.line),
+      column: Some(loc.col.0 + 1),
+      code_frame: Some(self.format_code_frame(span)),
+      ..Diagnostic::error(TRANSFORM_ERROR_CODE, message, Some(file.to_string()))
+    }
+  }
 }
 
 fn read_source_file(file_name: &FileName) -> Result<String, std::io::Error> {
@@ -97,9 +145,31 @@ pub(crate) fn build_code_frame_error<'a>(
   error_message: &'a str,
   state: &mut StateManager,
 ) -> &'a str {
+  emit_code_frame_error(
+    wrapped_expression,
+    fault_expression,
+    error_message,
+    error_message,
+    state,
+  );
+
+  error_message
+}
+
+fn emit_code_frame_error(
+  wrapped_expression: &Expr,
+  fault_expression: &Expr,
+  error_message: &str,
+  diagnostic_message: &str,
+  state: &mut StateManager,
+) {
+  let file_name = state.get_filename().to_string();
+
   match get_span_from_source_code(wrapped_expression, fault_expression, state) {
     Ok((code_frame, span)) => {
       code_frame.create_error(span, error_message).emit();
+
+      state.add_diagnostic(code_frame.create_diagnostic(span, diagnostic_message, &file_name));
     }
     Err(error) => {
       if log::log_enabled!(log::Level::Debug) {
@@ -116,10 +186,14 @@ pub(crate) fn build_code_frame_error<'a>(
           state.get_filename(),
         )
       };
+
+      state.add_diagnostic(Diagnostic::error(
+        TRANSFORM_ERROR_CODE,
+        diagnostic_message,
+        Some(file_name),
+      ));
     }
   }
-
-  error_message
 }
 
 pub(crate) fn get_span_from_source_code(
@@ -127,6 +201,25 @@ pub(crate) fn get_span_from_source_code(
   target_expression: &Expr,
   state: &mut StateManager,
 ) -> Result<(CodeFrame, Span), Error> {
+  let (code_frame, found_expression) =
+    find_expression_in_source_code(wrapped_expression, target_expression, state)?;
+
+  let frame_span = found_expression.map_or_else(
+    || target_expression.span(),
+    |expression| Span::new(expression.span_lo(), expression.span_hi()),
+  );
+
+  Ok((code_frame, frame_span))
+}
+
+/// Looks up the target expression in the parsed source code. Unlike the
+/// target itself, the returned expression carries spans that the code frame
+/// can resolve, including the ones of its nested nodes.
+pub(crate) fn find_expression_in_source_code(
+  wrapped_expression: &Expr,
+  target_expression: &Expr,
+  state: &mut StateManager,
+) -> Result<(CodeFrame, Option<Expr>), Error> {
   let file_name = FileName::Custom(state.get_filename().to_owned());
 
   let code_frame = CodeFrame::new();
@@ -155,8 +248,8 @@ pub(crate) fn get_span_from_source_code(
 
       program.clone().fold_with(&mut finder);
 
-      let frame_span = if let Some(span) = finder.get_span() {
-        span
+      let found_expression = if finder.found_expr.is_some() {
+        finder.found_expr
       } else {
         let folded_expression = target_expression.clone().fold_with(&mut TplConverter {});
 
@@ -164,12 +257,10 @@ pub(crate) fn get_span_from_source_code(
 
         program.fold_with(&mut finder);
 
-        finder
-          .get_span()
-          .unwrap_or_else(|| target_expression.span())
+        finder.found_expr
       };
 
-      return Ok((code_frame, frame_span));
+      return Ok((code_frame, found_expression));
     }
     Err(error) => {
       if log::log_enabled!(log::Level::Debug) {
@@ -292,12 +383,6 @@ impl ExpressionFinder {
       found_expr: None,
     }
   }
-
-  fn get_span(&self) -> Option<Span> {
-    let expr = self.found_expr.as_ref()?;
-
-    Some(Span::new(expr.span_lo(), expr.span_hi()))
-  }
 }
 
 #[derive(Debug)]
@@ -401,10 +486,11 @@ pub(crate) fn build_code_frame_error_and_panic(
     caller_location.line()
   );
 
-  build_code_frame_error(
+  emit_code_frame_error(
     wrapped_expression,
     fault_expression,
     &enhanced_message,
+    error_message,
     state,
   );
 
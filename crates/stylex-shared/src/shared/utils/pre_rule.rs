@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+use crate::shared::utils::css::common::container_query_priority_offset;
+
 pub(crate) fn sort_pseudos(pseudos: &[String]) -> Vec<String> {
   if pseudos.len() < 2 {
     return pseudos.to_owned();
@@ -52,5 +54,10 @@ fn string_comparator(a: &str, b: &str) -> std::cmp::Ordering {
   if b == "default" {
     return Ordering::Greater;
   }
+  if a.starts_with("@container") && b.starts_with("@container") {
+    return container_query_priority_offset(a)
+      .total_cmp(&container_query_priority_offset(b))
+      .then_with(|| a.cmp(b));
+  }
   a.cmp(b)
 }
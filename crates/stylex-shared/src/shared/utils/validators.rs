@@ -11,15 +11,15 @@ use crate::shared::{
       DUPLICATE_CONDITIONAL, ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE,
       INVALID_PSEUDO_OR_AT_RULE, NO_OBJECT_SPREADS, NON_OBJECT_KEYFRAME,
       NON_STATIC_SECOND_ARG_CREATE_THEME_VALUE, ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS,
-      illegal_argument_length, non_export_named_declaration, non_static_value, non_style_object,
-      unbound_call_value,
+      illegal_argument_length, invalid_css_type_value, non_export_named_declaration,
+      non_static_value, non_style_object, unbound_call_value,
     },
   },
   enums::data_structures::{
     evaluate_result_value::EvaluateResultValue,
     top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
   },
-  structures::state_manager::StateManager,
+  structures::{base_css_type::BaseCSSType, state_manager::StateManager},
   utils::{
     ast::{convertors::string_to_expression, factories::key_value_ident_factory},
     common::get_import_from,
@@ -377,6 +377,50 @@ pub(crate) fn find_and_validate_stylex_define_vars(
   Some(stylex_create_theme_top_level_expr.clone())
 }
 
+/// Checks that every typed `defineVars()` value, including the ones set for
+/// at-rules, parses as the syntax it declares.
+pub(crate) fn validate_typed_define_vars_values(
+  call: &CallExpr,
+  variables: &EvaluateResultValue,
+  state: &mut StateManager,
+) {
+  let Some(variables) = variables.as_expr().and_then(|expr| expr.as_object()) else {
+    return;
+  };
+
+  for key_value in get_key_values_from_object(variables) {
+    let Some(typed_value) = key_value.value.as_object() else {
+      continue;
+    };
+
+    let is_typed = get_key_values_from_object(typed_value)
+      .iter()
+      .any(|prop| key_value_to_str(prop) == "syntax");
+
+    if !is_typed {
+      continue;
+    }
+
+    let css_type = BaseCSSType::from(typed_value.clone());
+
+    if let Some((at_rules, value)) = css_type.find_invalid_value() {
+      let mut key = key_value_to_str(&key_value);
+
+      for at_rule in at_rules {
+        key.push(' ');
+        key.push_str(&at_rule);
+      }
+
+      build_code_frame_error_and_panic(
+        &Expr::Call(call.clone()),
+        &string_to_expression(&value),
+        &invalid_css_type_value(&key, &css_type.syntax.to_property_syntax(), &value),
+        state,
+      );
+    }
+  }
+}
+
 pub(crate) fn find_and_validate_stylex_define_consts(
   call: &CallExpr,
   state: &mut StateManager,
@@ -445,6 +489,18 @@ pub(crate) fn is_props_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(("props", &state.stylex_props_import), call, state)
 }
 
+pub(crate) fn is_attrs_call(call: &CallExpr, state: &StateManager) -> bool {
+  is_target_call(("attrs", &state.stylex_attrs_import), call, state)
+}
+
+pub(crate) fn is_default_marker_call(call: &CallExpr, state: &StateManager) -> bool {
+  is_target_call(
+    ("defaultMarker", &state.stylex_default_marker_import),
+    call,
+    state,
+  )
+}
+
 pub(crate) fn is_keyframes_call(var_decl: &VarDeclarator, state: &StateManager) -> bool {
   let init = var_decl.init.as_ref().and_then(|init| init.clone().call());
 
@@ -172,6 +172,9 @@ where
         "props" => {
           self.state.stylex_props_import.insert(local_name_ident_atom);
         }
+        "attrs" => {
+          self.state.stylex_attrs_import.insert(local_name_ident_atom);
+        }
         "keyframes" => {
           self
             .state
@@ -217,6 +220,15 @@ where
         "types" => {
           self.state.stylex_types_import.insert(local_name_ident_atom);
         }
+        "when" => {
+          self.state.stylex_when_import.insert(local_name_ident_atom);
+        }
+        "defaultMarker" => {
+          self
+            .state
+            .stylex_default_marker_import
+            .insert(local_name_ident_atom);
+        }
         _ => {}
       }
     }
@@ -27,9 +27,11 @@ where
 
       self.state.cycle = TransformationCycle::TransformEnter;
       module = module.fold_children_with(self);
+      self.abort_on_errors();
 
       self.state.cycle = TransformationCycle::TransformExit;
       module = module.fold_children_with(self);
+      self.abort_on_errors();
 
       if self.state.options.runtime_injection.is_some() {
         self.state.cycle = TransformationCycle::InjectStyles;
@@ -49,6 +51,7 @@ where
       module = module.fold_children_with(self);
 
       module.body.reverse();
+      self.abort_on_errors();
 
       if !cfg!(debug_assertions) && self.state.debug_assertions_module.is_some() {
         panic!("Debug assertions module is not empty in release mode");
@@ -155,6 +155,10 @@ where
           result_module_items.push(module_item.clone());
         }
 
+        if let Some(dispose_item) = self.state.get_hot_module_dispose_item() {
+          result_module_items.push(dispose_item);
+        }
+
         result_module_items
       }
       TransformationCycle::Cleaning => {
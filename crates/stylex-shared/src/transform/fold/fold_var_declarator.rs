@@ -13,6 +13,7 @@ use swc_core::{
 use crate::{
   StyleXTransform,
   shared::{
+    constants::messages::unused_namespace,
     enums::{
       core::TransformationCycle,
       data_structures::{
@@ -20,6 +21,7 @@ use crate::{
         top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
       },
     },
+    structures::diagnostic::UNUSED_NAMESPACE_WARNING_CODE,
     utils::{ast::convertors::transform_shorthand_to_key_values, common::fill_state_declarations},
   },
 };
@@ -143,7 +145,7 @@ where
   }
 
   fn retain_object_props(
-    &self,
+    &mut self,
     object: &mut ObjectLit,
     namespace_to_keep: Vec<Atom>,
     var_name: &VarDeclarator,
@@ -206,6 +208,13 @@ where
           }
 
           props.push(object_prop.clone())
+        } else if let Some(key_as_ident) = key_as_ident {
+          let var_id = &var_name.name.as_ident().unwrap().sym;
+
+          self.state.add_warning(
+            UNUSED_NAMESPACE_WARNING_CODE,
+            &unused_namespace(var_id, &key_as_ident.sym),
+          );
         }
       }
     }
@@ -1,3 +1,5 @@
+use std::panic::{self, AssertUnwindSafe};
+
 use rustc_hash::FxHashSet;
 use swc_core::{
   common::{Mark, comments::Comments},
@@ -14,6 +16,7 @@ use crate::{
   shared::{
     enums::core::TransformationCycle,
     structures::{
+      diagnostic::{Diagnostic, INTERNAL_ERROR_CODE, panic_message},
       named_import_source::{ImportSources, RuntimeInjection},
       plugin_pass::PluginPass,
       state_manager::StateManager,
@@ -58,6 +61,16 @@ where
     }
   }
 
+  /// Creates a transform that reuses already prepared state, e.g. to compile
+  /// another module with the options of the current one.
+  pub(crate) fn with_state(comments: C, state: StateManager) -> Self {
+    StyleXTransform {
+      comments,
+      props_declaration: None,
+      state,
+    }
+  }
+
   pub fn new_test_force_runtime_injection(
     comments: C,
     plugin_pass: PluginPass,
@@ -172,10 +185,15 @@ where
         || state.stylex_position_try_import.contains(&ident_sym.into())
         || state.stylex_keyframes_import.contains(&ident_sym.into())
         || state.stylex_props_import.contains(&ident_sym.into())
+        || state.stylex_attrs_import.contains(&ident_sym.into())
         || state
           .stylex_first_that_works_import
           .contains(&ident_sym.into())
-        || state.stylex_types_import.contains(&ident_sym.into()))
+        || state.stylex_types_import.contains(&ident_sym.into())
+        || state.stylex_when_import.contains(&ident_sym.into())
+        || state
+          .stylex_default_marker_import
+          .contains(&ident_sym.into()))
   }
 
   pub(crate) fn process_declaration(&mut self, call_expr: &mut CallExpr) -> Option<(Id, String)> {
@@ -207,13 +225,50 @@ where
       let declaration = self.process_declaration(ex);
 
       if declaration.is_some() {
-        return self.transform_call_expression_to_stylex_expr(ex);
+        return self.transform_call_expression_with_recovery(ex);
       }
     }
 
     None
   }
 
+  /// Each StyleX call is a recovery point: a failing call is left untouched
+  /// and its error is recorded, so every error of the cycle gets reported.
+  fn transform_call_expression_with_recovery(&mut self, ex: &mut CallExpr) -> Option<Expr> {
+    let diagnostics_count = self.state.diagnostics.len();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+      self.transform_call_expression_to_stylex_expr(ex)
+    }));
+
+    match result {
+      Ok(value) => value,
+      Err(error) => {
+        if self.state.diagnostics.len() == diagnostics_count {
+          let message = panic_message(error.as_ref())
+            .unwrap_or_else(|| "Unknown error occurred during transformation".to_string());
+
+          let file_name = self.state.get_filename().to_string();
+
+          self.state.add_diagnostic(Diagnostic::error(
+            INTERNAL_ERROR_CODE,
+            &message,
+            Some(file_name),
+          ));
+        }
+
+        None
+      }
+    }
+  }
+
+  /// Stops the transform with the first recorded error once a cycle is over
+  pub(crate) fn abort_on_errors(&self) {
+    if let Some(diagnostic) = self.state.diagnostics.iter().find(|d| d.is_error()) {
+      panic!("{}", diagnostic.message);
+    }
+  }
+
   pub(crate) fn get_call_var_name(
     &mut self,
     call: &CallExpr,
@@ -58,6 +58,7 @@ pub(crate) fn styleq(arguments: &[ResolvedArg]) -> StyleQResult {
           continue;
         }
       }
+      ResolvedArg::ConditionalStyles(_, _, _) => continue,
     };
 
     match possible_style {
@@ -1,7 +1,9 @@
+pub(crate) mod transform_stylex_attrs_call;
 pub(crate) mod transform_stylex_call;
 pub(crate) mod transform_stylex_calls;
 mod transform_stylex_create_call;
 pub(crate) mod transform_stylex_create_theme_call;
+pub(crate) mod transform_stylex_default_marker_call;
 pub(crate) mod transform_stylex_define_consts_call;
 pub(crate) mod transform_stylex_define_vars_call;
 pub(crate) mod transform_stylex_keyframes_call;
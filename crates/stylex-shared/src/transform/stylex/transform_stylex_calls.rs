@@ -63,6 +63,10 @@ where
       if let Some(value) = self.transform_stylex_create(call_expr) {
         return Some(value);
       }
+
+      if let Some(value) = self.transform_stylex_default_marker_call(call_expr) {
+        return Some(value);
+      }
     }
 
     if self.state.cycle == TransformationCycle::TransformExit {
@@ -72,6 +76,12 @@ where
         return Some(value);
       }
 
+      if self.state.stylex_attrs_import.contains(ident_name)
+        && let Some(value) = self.transform_stylex_attrs_call(call_expr)
+      {
+        return Some(value);
+      }
+
       if let Some(value) = self.transform_stylex_call(call_expr) {
         return Some(value);
       }
@@ -79,6 +89,10 @@ where
       if let Some(value) = self.transform_stylex_props_call(call_expr) {
         return Some(value);
       }
+
+      if let Some(value) = self.transform_stylex_attrs_call(call_expr) {
+        return Some(value);
+      }
     }
 
     None
@@ -30,7 +30,7 @@ use crate::shared::{
     injectable_style::InjectableStyle,
     uid_generator::{CounterMode, UidGenerator},
   },
-  transformers::stylex_position_try::get_position_try_fn,
+  transformers::{stylex_position_try::get_position_try_fn, stylex_when::get_when_fn},
   utils::{
     ast::{
       convertors::{key_value_to_str, lit_to_string},
@@ -38,6 +38,7 @@ use crate::shared::{
     },
     common::normalize_expr,
     core::{
+      add_source_locations::add_source_locations,
       add_source_map_data::add_source_map_data,
       dev_class_name::{convert_to_test_styles, inject_dev_class_names},
     },
@@ -117,6 +118,7 @@ where
 
       let keyframes_fn = get_keyframes_fn();
       let position_try_fn = get_position_try_fn();
+      let when_fn = get_when_fn();
 
       for name in &self.state.stylex_first_that_works_import {
         identifiers.insert(
@@ -139,6 +141,13 @@ where
         );
       }
 
+      for name in &self.state.stylex_when_import {
+        identifiers.insert(
+          name.clone(),
+          Box::new(FunctionConfigType::Regular(when_fn.clone())),
+        );
+      }
+
       for name in &self.state.stylex_import {
         member_expressions.entry(name.clone()).or_default();
 
@@ -158,6 +167,14 @@ where
           "positionTry".into(),
           Box::new(FunctionConfigType::Regular(position_try_fn.clone())),
         );
+
+        let identifier = identifiers
+          .entry(name.get_import_str().into())
+          .or_insert_with(|| Box::new(FunctionConfigType::Map(FxHashMap::default())));
+
+        if let Some(identifier_map) = identifier.as_map_mut() {
+          identifier_map.insert("when".into(), when_fn.clone());
+        }
       }
 
       let function_map: Box<FunctionMap> = Box::new(FunctionMap {
@@ -233,12 +250,13 @@ where
                 },
               ),
               rtl: None,
+              source: None,
             })),
           );
         }
       }
 
-      let (mut compiled_styles, injected_styles_sans_keyframes, class_paths_per_namespace) =
+      let (mut compiled_styles, mut injected_styles_sans_keyframes, class_paths_per_namespace) =
         stylex_create_set(
           &value,
           &mut EvaluationState::new(),
@@ -246,6 +264,15 @@ where
           &function_map,
         );
 
+      if self.state.options.enable_css_source_locations {
+        add_source_locations(
+          &mut injected_styles_sans_keyframes,
+          &class_paths_per_namespace,
+          call,
+          &mut self.state,
+        );
+      }
+
       for (namespace, properties) in compiled_styles.iter() {
         resolved_namespaces
           .entry(namespace.clone())
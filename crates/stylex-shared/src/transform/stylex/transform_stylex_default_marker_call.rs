@@ -0,0 +1,25 @@
+use swc_core::{
+  common::comments::Comments,
+  ecma::ast::{CallExpr, Expr},
+};
+
+use crate::{
+  StyleXTransform,
+  shared::{
+    transformers::stylex_default_marker::stylex_default_marker,
+    utils::validators::is_default_marker_call,
+  },
+};
+
+impl<C> StyleXTransform<C>
+where
+  C: Comments,
+{
+  pub(crate) fn transform_stylex_default_marker_call(&mut self, call: &CallExpr) -> Option<Expr> {
+    if is_default_marker_call(call, &self.state) {
+      return Some(stylex_default_marker(&self.state.options));
+    }
+
+    None
+  }
+}
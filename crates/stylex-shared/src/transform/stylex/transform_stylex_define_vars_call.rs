@@ -12,7 +12,9 @@ use crate::shared::{
     common::gen_file_based_identifier,
     core::js_to_expr::{NestedStringObject, convert_object_to_ast},
     js::evaluate::evaluate,
-    validators::{find_and_validate_stylex_define_vars, is_define_vars_call},
+    validators::{
+      find_and_validate_stylex_define_vars, is_define_vars_call, validate_typed_define_vars_values,
+    },
   },
 };
 use crate::shared::{
@@ -138,6 +140,8 @@ where
         None => panic!("{}", non_static_value("defineVars")),
       };
 
+      validate_typed_define_vars_values(call, &value, &mut self.state);
+
       let file_name = self
         .state
         .get_filename_for_hashing(&mut FxHashMap::default())
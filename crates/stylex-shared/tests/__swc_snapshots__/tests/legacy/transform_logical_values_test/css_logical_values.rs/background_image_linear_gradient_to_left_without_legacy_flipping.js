@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1nvgxwd{background-image:linear-gradient(to left,red,blue)}", 3000);
+export const classnames = "x1nvgxwd";
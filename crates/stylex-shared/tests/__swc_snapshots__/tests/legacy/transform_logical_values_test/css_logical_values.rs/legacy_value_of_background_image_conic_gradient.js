@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1spniid{background-image:conic-gradient(from 90deg,red 0deg,blue 90deg)}", 3000, ".x1spniid{background-image:conic-gradient(from -90deg,blue 270deg,red 360deg)}");
+export const classnames = "x1spniid";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xmgy37g{background-image:linear-gradient(45deg,red 0%,blue 100%)}", 3000, ".xmgy37g{background-image:linear-gradient(-45deg,red 0%,blue 100%)}");
+export const classnames = "xmgy37g";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x15qvec6{background-image:radial-gradient(circle at left 20%, red, blue)}", 3000, ".x15qvec6{background-image:radial-gradient(circle at right 20%, red, blue)}");
+export const classnames = "x15qvec6";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xumf9a7{background-image:url(image.png)}", 3000);
+export const classnames = "xumf9a7";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1bouowf{object-position:left 20%}", 3000, ".x1bouowf{object-position:right 20%}");
+export const classnames = "x1bouowf";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1sc3njs{rotate:z 45deg}", 3000, ".x1sc3njs{rotate:z -45deg}");
+export const classnames = "x1sc3njs";
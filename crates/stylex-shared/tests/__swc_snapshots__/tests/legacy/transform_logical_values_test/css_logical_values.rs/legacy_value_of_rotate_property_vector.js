@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xdo0suq{rotate:1 1 0 45deg}", 3000, ".xdo0suq{rotate:1 -1 0 45deg}");
+export const classnames = "xdo0suq";
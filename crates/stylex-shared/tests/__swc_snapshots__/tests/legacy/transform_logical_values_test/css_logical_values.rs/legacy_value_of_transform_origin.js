@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1f47z3u{transform-origin:left top 10px}", 3000, ".x1f47z3u{transform-origin:right top 10px}");
+export const classnames = "x1f47z3u";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xig27yj{transform:rotateX(10deg) rotatey(20deg) rotate3d(1,1,0,30deg)}", 3000, ".xig27yj{transform:rotateX(10deg) rotatey(-20deg) rotate3d(1,-1,0,30deg)}");
+export const classnames = "xig27yj";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x18mo7w5{transform:translateX(10px) rotate(45deg)}", 3000, ".x18mo7w5{transform:translateX(-10px) rotate(-45deg)}");
+export const classnames = "x18mo7w5";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1f43naa{transform:translate(-50%,10px) skewx(10deg) matrix(1,.5,.25,1,10,20)}", 3000, ".x1f43naa{transform:translate(50%,10px) skewx(-10deg) matrix(1,-.5,-.25,1,-10,20)}");
+export const classnames = "x1f43naa";
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1x8e6iy{translate:10px 20px}", 3000, ".x1x8e6iy{translate:-10px 20px}");
+export const classnames = "x1x8e6iy";
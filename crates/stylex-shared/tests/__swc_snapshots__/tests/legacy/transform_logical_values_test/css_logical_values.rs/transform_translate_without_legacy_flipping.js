@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xhco33r{transform:translateX(10px)}", 3000);
+export const classnames = "xhco33r";
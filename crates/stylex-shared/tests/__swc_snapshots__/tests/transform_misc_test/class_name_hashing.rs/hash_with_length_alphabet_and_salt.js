@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2("@keyframes xdfegd-B{from{opacity:0;}to{opacity:1;}}", 0);
+export const fade = "xdfegd-B";
+_inject2(".xfaedb{animation-name:xdfegd-B}", 3000);
+_inject2(".xbcbhe{color:red}", 3000);
+export const styles = {
+    root: {
+        kKVMdj: "xfaedb",
+        kMwMTN: "xbcbhe",
+        $$css: true
+    }
+};
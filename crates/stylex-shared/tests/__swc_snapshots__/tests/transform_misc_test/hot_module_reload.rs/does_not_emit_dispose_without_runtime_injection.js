@@ -0,0 +1,7 @@
+import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu",
+        $$css: true
+    }
+};
@@ -0,0 +1,13 @@
+import _inject from "@toss/stylexswc-rs-compiler/hmr-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000, null, "1uze7u4");
+_inject2(".x1hm9lzh{margin-inline-start:10px}", 3000, null, "1uze7u4");
+export const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu",
+        keTefX: "x1hm9lzh",
+        $$css: true
+    }
+};
+if (import.meta.hot) import.meta.hot.dispose(()=>_inject2.dispose?.("1uze7u4"));
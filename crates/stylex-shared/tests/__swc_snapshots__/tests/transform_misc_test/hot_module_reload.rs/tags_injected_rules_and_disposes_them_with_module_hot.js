@@ -0,0 +1,13 @@
+import _inject from "@toss/stylexswc-rs-compiler/hmr-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000, null, "1uze7u4");
+export const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu",
+        $$css: true
+    }
+};
+_inject2("@keyframes x18re5ia-B{from{opacity:0;}to{opacity:1;}}", 0, null, "1uze7u4");
+export const fade = "x18re5ia-B";
+if (typeof module !== "undefined" && module.hot) module.hot.dispose(()=>_inject2.dispose?.("1uze7u4"));
@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".color-x1e2nbdu{color:red}", 3000);
+function Foo() {
+    return <>
+      <div id="test" class="color-x1e2nbdu" data-style-src="npm-package:components/Foo.react.js:3">Hello World</div>
+      <div class="test" class="color-x1e2nbdu" data-style-src="npm-package:components/Foo.react.js:3" id="test">Hello World</div>
+    </>;
+}
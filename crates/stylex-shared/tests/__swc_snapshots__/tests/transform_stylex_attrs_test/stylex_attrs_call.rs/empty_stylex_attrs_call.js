@@ -0,0 +1,2 @@
+import stylex from 'stylex';
+({});
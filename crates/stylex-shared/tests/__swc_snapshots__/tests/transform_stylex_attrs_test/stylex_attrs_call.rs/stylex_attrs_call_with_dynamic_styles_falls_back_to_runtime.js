@@ -0,0 +1,17 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x14rh7hd{color:var(--x-color)}", 3000);
+_inject2('@property --x-color { syntax: "*"; inherits: false; }', 0);
+const styles = {
+    default: (color)=>[
+            {
+                kMwMTN: color != null ? "x14rh7hd" : color,
+                $$css: true
+            },
+            {
+                "--x-color": color != null ? color : undefined
+            }
+        ]
+};
+stylex.attrs(styles.default('red'));
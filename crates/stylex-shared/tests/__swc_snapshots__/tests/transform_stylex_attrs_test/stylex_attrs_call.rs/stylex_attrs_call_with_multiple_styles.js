@@ -0,0 +1,9 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+_inject2(".x1prwzq3{color:green}", 3000);
+({
+    class: "xrkmrrc x1prwzq3"
+});
@@ -0,0 +1,23 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+import { defaultMarker } from '@stylexjs/stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu",
+        $$css: true
+    }
+};
+export const a = {
+    "x-default-marker": "x-default-marker",
+    $$css: true
+};
+export const b = {
+    "x-default-marker": "x-default-marker",
+    $$css: true
+};
+export const c = stylex.props({
+    "x-default-marker": "x-default-marker",
+    $$css: true
+}, styles.root);
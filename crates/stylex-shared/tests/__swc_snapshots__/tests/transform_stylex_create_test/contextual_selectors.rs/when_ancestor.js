@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".xju2f9n{color:blue}", 3000);
+_inject2(".xq6e253:where(.x-default-marker:hover *){color:red}", 3011.3);
+export const styles = {
+    root: {
+        kMwMTN: "xju2f9n xq6e253",
+        $$css: true
+    }
+};
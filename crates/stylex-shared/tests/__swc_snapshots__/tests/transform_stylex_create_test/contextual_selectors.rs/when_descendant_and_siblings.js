@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x12peec7{background-color:white}", 3000);
+_inject2(".x134iq4n:where(:has(.x-default-marker:focus)){background-color:gray}", 3016.5);
+_inject2(".x1mqxbix{color:black}", 3000);
+_inject2(".xbbeoq7:where(.x-default-marker:hover ~ *){color:red}", 3031.3);
+_inject2(".x1l6rkwd:where(:has(~ .x-default-marker:focus-visible)){color:green}", 3040.4);
+_inject2(".x6glfu4:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){color:blue}", 3021.7);
+export const styles = {
+    root: {
+        kWkggS: "x12peec7 x134iq4n",
+        kMwMTN: "x1mqxbix xbbeoq7 x1l6rkwd x6glfu4",
+        $$css: true
+    }
+};
@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import { create, when } from '@stylexjs/stylex';
+_inject2(".x1hc1fzr{opacity:1}", 3000);
+_inject2(".x7ofl:where(.x-default-marker:focus-within *){opacity:.5}", 3010.4);
+export const styles = {
+    root: {
+        kSiTet: "x1hc1fzr x7ofl",
+        $$css: true
+    }
+};
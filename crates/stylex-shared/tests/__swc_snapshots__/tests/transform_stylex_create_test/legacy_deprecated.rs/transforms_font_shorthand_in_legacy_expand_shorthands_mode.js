@@ -0,0 +1,47 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1k4tb9n{font-style:italic}", 3000);
+_inject2(".x166r7wq{font-variant:normal}", 2000);
+_inject2(".x1s688f{font-weight:600}", 3000);
+_inject2(".x14g2gp5{font-stretch:normal}", 3000);
+_inject2(".x1j61zf2{font-size:16px}", 3000);
+_inject2(".x1evy7pa{line-height:1.5}", 3000);
+_inject2(".x1cc2d69{font-family:Inter,sans-serif}", 3000);
+_inject2(".x1j61x8r{font-style:normal}", 3000);
+_inject2(".x1fcty0u{font-weight:normal}", 3000);
+_inject2(".xfifm61{font-size:12px}", 3000);
+_inject2(".xggjnk3{line-height:normal}", 3000);
+_inject2(".xfvi8g1{font-family:Arial}", 3000);
+_inject2(".x1xasupz{font:caption}", 1000);
+_inject2(".x5e135a{font:var(--body-font)}", 1000);
+export const styles = {
+    default: {
+        kKX8nH: "x1k4tb9n",
+        kjAs5C: "x166r7wq",
+        k63SB2: "x1s688f",
+        kQqvRs: "x14g2gp5",
+        kGuDYH: "x1j61zf2",
+        kLWn49: "x1evy7pa",
+        kMv6JI: "x1cc2d69",
+        $$css: true
+    },
+    small: {
+        kKX8nH: "x1j61x8r",
+        kjAs5C: "x166r7wq",
+        k63SB2: "x1fcty0u",
+        kQqvRs: "x14g2gp5",
+        kGuDYH: "xfifm61",
+        kLWn49: "xggjnk3",
+        kMv6JI: "xfvi8g1",
+        $$css: true
+    },
+    system: {
+        kVVagm: "x1xasupz",
+        $$css: true
+    },
+    variable: {
+        kVVagm: "x5e135a",
+        $$css: true
+    }
+};
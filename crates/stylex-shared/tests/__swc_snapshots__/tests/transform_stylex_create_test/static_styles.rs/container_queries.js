@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2("@container (min-width: 800px){.x7xc3y0.x7xc3y0{background-color:purple}}", 3300.008);
+_inject2("@container sidebar (min-width: 400px){.xruqu83.xruqu83{background-color:blue}}", 3300.004);
+_inject2("@container style(--theme: dark){.xlixyg1.xlixyg1{background-color:black}}", 3300);
+export const styles = {
+    root: {
+        kWkggS: "xrkmrrc x7xc3y0 xruqu83 xlixyg1",
+        $$css: true
+    }
+};
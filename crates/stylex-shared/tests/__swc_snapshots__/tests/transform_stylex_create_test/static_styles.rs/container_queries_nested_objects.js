@@ -0,0 +1,8 @@
+import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        k7kfix: "xa3jc12",
+        k3P6vs: "xsqybxs",
+        $$css: true
+    }
+};
@@ -0,0 +1,11 @@
+import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu xam4l6s",
+        $$css: true
+    },
+    other: {
+        kMwMTN: "x1e2nbdu xam4l6s",
+        $$css: true
+    }
+};
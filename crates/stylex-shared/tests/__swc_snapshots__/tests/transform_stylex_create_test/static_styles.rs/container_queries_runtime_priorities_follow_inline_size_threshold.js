@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".xh8yej3{width:100%}", 4000);
+_inject2("@container (min-width: 800px){.x1jzzivi.x1jzzivi{width:50%}}", 4300.008);
+_inject2("@container (min-width: 400px){.x1via87w.x1via87w{width:75%}}", 4300.004);
+_inject2(".xt7dq6l{height:auto}", 4000);
+_inject2("@container (max-width: 400px){.x1w4389j.x1w4389j{height:10px}}", 4300.09599);
+_inject2("@container (max-width: 800px){.xa77t4v.xa77t4v{height:20px}}", 4300.09199);
+export const styles = {
+    root: {
+        kzqmXN: "xh8yej3 x1jzzivi x1via87w",
+        kZKoxP: "xt7dq6l x1w4389j xa77t4v",
+        $$css: true
+    }
+};
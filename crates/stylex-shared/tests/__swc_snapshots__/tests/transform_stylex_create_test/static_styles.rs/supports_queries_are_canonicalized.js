@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2(".x1lliihq{display:block}", 3000);
+_inject2("@supports (display: grid){.xr4m3m1.xr4m3m1{display:grid}}", 3030);
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2("@supports selector(:has(a)) and (not (color: oklab(0 0 0))){.xdjb1c5.xdjb1c5{color:blue}}", 3030);
+export const styles = {
+    root: {
+        k1xSpc: "x1lliihq xr4m3m1",
+        $$css: true
+    },
+    other: {
+        k1xSpc: "x1lliihq xr4m3m1",
+        $$css: true
+    },
+    selector: {
+        kMwMTN: "x1e2nbdu xdjb1c5",
+        $$css: true
+    }
+};
@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2('@property --xnqupqb { syntax: "<custom-ident>"; inherits: true; initial-value: fade }', 0);
+_inject2('@property --xrcekuy { syntax: "<string>"; inherits: true; initial-value: "Hello" }', 0);
+_inject2('@property --x33lgte { syntax: "<length-percentage>+"; inherits: true; initial-value: 0% 50% }', 0);
+_inject2('@property --x1j1atpc { syntax: "<number> | <custom-ident>"; inherits: true; initial-value: bold }', 0);
+_inject2('@property --xla6jvh { syntax: "*"; inherits: true; initial-value: calc(1px + 2px) }', 0);
+_inject2(':root, .xop34xu{--xnqupqb:fade;--xrcekuy:"Hello";--x33lgte:0% 50%;--x1j1atpc:bold;--xla6jvh:calc(1px + 2px);}', 0.1);
+_inject2("@media (min-width: 800px){:root, .xop34xu{--x33lgte:10px 40%;}}", 0.1);
+export const vars = {
+    animationName: "var(--xnqupqb)",
+    label: "var(--xrcekuy)",
+    gradientStops: "var(--x33lgte)",
+    weight: "var(--x1j1atpc)",
+    anything: "var(--xla6jvh)",
+    __varGroupHash__: "xop34xu"
+};
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xmzmlnv{color:#FFF}", 3000);
+_inject2(".x1awj2ng{color:white}", 3000);
@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xfungia{color:#fff}", 3000);
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".x1uu1fcu{border-top-color:red}", 4000);
+_inject2(".x1pvy4qg{color:rgba(0,0,0,.5)}", 3000);
+_inject2(".x1aj07ft{color:var(--text)}", 3000);
+_inject2(".xqdoxlg{outline-color:oklch(0.5 0.1 270deg)}", 3000);
+_inject2(".x1pe4ktx{caret-color:currentcolor}", 3000);
@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1kky2od{width:16px}", 4000);
+_inject2(".x1kpxq89{height:12px}", 4000);
+_inject2(".x1k70j0n{margin-top:6px}", 4000);
+_inject2(".xbfn433{margin-bottom:calc(100% - 6px)}", 4000);
+_inject2(".x1bxaniu{padding-top:calc(var(--gap) + 6px)}", 4000);
+_inject2(".x10eo4qr{padding-bottom:calc(100% - 20px)}", 4000);
+_inject2(".x479zrw{transform:translateX(5px)}", 3000);
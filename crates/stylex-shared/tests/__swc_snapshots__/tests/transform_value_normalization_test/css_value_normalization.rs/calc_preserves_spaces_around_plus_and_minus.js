@@ -1,4 +1,4 @@
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".x1hauit9{width:calc((100% + 3% - 100px) / 7)}", 4000);
+_inject2(".xa7y3p2{width:calc((100% + var(--gap) - 100px) / 7)}", 4000);
@@ -0,0 +1,5 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1cc2d69{font-family:Inter,sans-serif}", 3000);
+_inject2('.xnvmqte{font-family:Helvetica Neue,"serif"}', 3000);
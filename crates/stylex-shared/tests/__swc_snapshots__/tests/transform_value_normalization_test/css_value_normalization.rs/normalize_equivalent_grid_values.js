@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1vph49j{grid-template-columns:[full-start]repeat(2,minmax(0,1fr))[full-end]}", 3000);
+_inject2('.xqtvwut{grid-template-areas:"header header" "sidebar main"}', 2000);
+_inject2(".xse5l30{grid-area:1/span 2}", 1000);
+_inject2('.x17lkcnm{grid-template-areas:"a . b" ". . b"}', 2000);
+_inject2(".x1dijebc{grid-row:auto/span name}", 2000);
@@ -0,0 +1,8 @@
+import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kMwMTN: "x1qh2i82",
+        k3aq6I: "xfc1sgo",
+        $$css: true
+    }
+};
@@ -0,0 +1,10 @@
+import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kMwMTN: "x1qq9wsj",
+        k3aq6I: "x1tadh6z",
+        kGVxlE: "xppelrp",
+        kAMwcw: "xz4gly6",
+        $$css: true
+    }
+};
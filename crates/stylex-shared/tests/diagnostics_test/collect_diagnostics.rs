@@ -0,0 +1,127 @@
+use std::{
+  panic::{self, AssertUnwindSafe},
+  sync::Arc,
+};
+
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{
+    diagnostic::{Diagnostic, DiagnosticSeverity, TRANSFORM_ERROR_CODE},
+    plugin_pass::PluginPass,
+  },
+};
+use swc_core::{
+  common::{FileName, SourceMap, comments::SingleThreadedComments},
+  ecma::{
+    ast::EsVersion,
+    parser::{Parser, StringInput, Syntax, TsSyntax, lexer::Lexer},
+    visit::FoldWith,
+  },
+};
+
+fn collect_diagnostics(source_code: &str) -> Vec<Diagnostic> {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Custom("input.js".into())),
+    source_code.to_string(),
+  );
+
+  let module = Parser::new_from(Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  ))
+  .parse_module()
+  .expect("Failed to parse module");
+
+  let mut stylex = StyleXTransform::new_test(
+    SingleThreadedComments::default(),
+    PluginPass::default(),
+    None,
+  );
+
+  let result = panic::catch_unwind(AssertUnwindSafe(|| module.fold_with(&mut stylex)));
+
+  assert!(result.is_err(), "Transform should fail");
+
+  stylex.state.diagnostics
+}
+
+#[test]
+fn collects_every_error_of_a_file() {
+  let diagnostics = collect_diagnostics(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const first = stylex.create({
+        root: { color: 'red' },
+      });
+      export const styles = stylex.create(null);
+      export const other = stylex.create(42);
+    "#,
+  );
+
+  assert_eq!(diagnostics.len(), 2);
+
+  assert!(diagnostics.iter().all(|diagnostic| {
+    diagnostic.code == TRANSFORM_ERROR_CODE
+      && diagnostic.severity == DiagnosticSeverity::Error
+      && diagnostic.message == "create() can only accept an object."
+  }));
+
+  assert_ne!(diagnostics[0].span, diagnostics[1].span);
+}
+
+#[test]
+fn reports_location_and_code_frame() {
+  let diagnostics = collect_diagnostics(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create(null);
+    "#,
+  );
+
+  let diagnostic = diagnostics.first().expect("Expected a diagnostic");
+
+  assert!(diagnostic.line.is_some());
+  assert!(diagnostic.column.is_some());
+  assert!(diagnostic.span.is_some());
+
+  let code_frame = diagnostic
+    .code_frame
+    .as_deref()
+    .expect("Expected a code frame");
+
+  assert!(code_frame.contains("stylex.create(null)"));
+  assert!(code_frame.contains('^'));
+}
+
+#[test]
+fn reports_location_of_invalid_typed_value() {
+  let diagnostics = collect_diagnostics(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const vars = stylex.defineVars({
+        accent: stylex.types.color('redd'),
+      });
+    "#,
+  );
+
+  let diagnostic = diagnostics.first().expect("Expected a diagnostic");
+
+  assert_eq!(diagnostic.code, TRANSFORM_ERROR_CODE);
+  assert_eq!(
+    diagnostic.message,
+    r#"Value "redd" of "accent" does not match the syntax "<color>"."#
+  );
+
+  let code_frame = diagnostic
+    .code_frame
+    .as_deref()
+    .expect("Expected a code frame");
+
+  assert!(code_frame.contains("stylex.types.color('redd')"));
+}
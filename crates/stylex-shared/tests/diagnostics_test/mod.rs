@@ -0,0 +1,2 @@
+mod collect_diagnostics;
+mod warnings;
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{
+    diagnostic::{
+      Diagnostic, DiagnosticSeverity, FONT_SIZE_PX_TO_REM_WARNING_CODE,
+      LEGACY_SHORTHAND_WARNING_CODE, UNUSED_NAMESPACE_WARNING_CODE, WarningLevel,
+    },
+    plugin_pass::PluginPass,
+    stylex_options::{StyleResolution, StyleXOptions, StyleXOptionsParams},
+  },
+};
+use swc_core::{
+  common::{FileName, SourceMap, comments::SingleThreadedComments},
+  ecma::{
+    ast::EsVersion,
+    parser::{Parser, StringInput, Syntax, TsSyntax, lexer::Lexer},
+    visit::FoldWith,
+  },
+};
+
+fn get_default_opts() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn collect_diagnostics(source_code: &str, config: &mut StyleXOptionsParams) -> Vec<Diagnostic> {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Custom("input.js".into())),
+    source_code.to_string(),
+  );
+
+  let module = Parser::new_from(Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    EsVersion::EsNext,
+    StringInput::from(&*fm),
+    None,
+  ))
+  .parse_module()
+  .expect("Failed to parse module");
+
+  let mut stylex = StyleXTransform::new_test(
+    SingleThreadedComments::default(),
+    PluginPass::default(),
+    Some(config),
+  );
+
+  module.fold_with(&mut stylex);
+
+  stylex.state.diagnostics
+}
+
+const LEGACY_SHORTHAND_INPUT: &str = r#"
+  import * as stylex from '@stylexjs/stylex';
+  export const styles = stylex.create({
+    root: {
+      marginHorizontal: 10,
+      paddingStart: 4,
+      marginInline: 2,
+    },
+  });
+"#;
+
+#[test]
+fn warns_about_legacy_shorthands() {
+  let diagnostics = collect_diagnostics(LEGACY_SHORTHAND_INPUT, &mut get_default_opts());
+
+  let messages = diagnostics
+    .iter()
+    .map(|diagnostic| {
+      assert_eq!(diagnostic.code, LEGACY_SHORTHAND_WARNING_CODE);
+      assert_eq!(diagnostic.severity, DiagnosticSeverity::Warning);
+
+      diagnostic.message.as_str()
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    messages,
+    vec![
+      "`marginHorizontal` is a legacy shorthand. Use the standard `marginInline` property instead.",
+      "`paddingStart` is a legacy shorthand. Use the standard `paddingInlineStart` property instead.",
+    ]
+  );
+}
+
+#[test]
+fn legacy_shorthands_are_ignored_outside_property_specificity() {
+  let diagnostics = collect_diagnostics(
+    LEGACY_SHORTHAND_INPUT,
+    &mut StyleXOptionsParams {
+      style_resolution: Some(StyleResolution::ApplicationOrder),
+      ..get_default_opts()
+    },
+  );
+
+  assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn warning_codes_can_be_turned_off() {
+  let diagnostics = collect_diagnostics(
+    LEGACY_SHORTHAND_INPUT,
+    &mut StyleXOptionsParams {
+      warnings: Some(FxHashMap::from_iter([(
+        LEGACY_SHORTHAND_WARNING_CODE.to_string(),
+        WarningLevel::Off,
+      )])),
+      ..get_default_opts()
+    },
+  );
+
+  assert!(diagnostics.is_empty());
+}
+
+#[test]
+#[should_panic(
+  expected = "`marginHorizontal` is a legacy shorthand. Use the standard `marginInline` property instead."
+)]
+fn warning_codes_can_be_promoted_to_errors() {
+  collect_diagnostics(
+    LEGACY_SHORTHAND_INPUT,
+    &mut StyleXOptionsParams {
+      warnings: Some(FxHashMap::from_iter([(
+        LEGACY_SHORTHAND_WARNING_CODE.to_string(),
+        WarningLevel::Error,
+      )])),
+      ..get_default_opts()
+    },
+  );
+}
+
+#[test]
+fn font_size_conversion_warning_is_opt_in() {
+  let input = r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        fontSize: '24px',
+      },
+    });
+  "#;
+
+  let mut config = StyleXOptionsParams {
+    enable_font_size_px_to_rem: Some(true),
+    ..get_default_opts()
+  };
+
+  assert!(collect_diagnostics(input, &mut config).is_empty());
+
+  config.warnings = Some(FxHashMap::from_iter([(
+    FONT_SIZE_PX_TO_REM_WARNING_CODE.to_string(),
+    WarningLevel::Warn,
+  )]));
+
+  let diagnostics = collect_diagnostics(input, &mut config);
+
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].code, FONT_SIZE_PX_TO_REM_WARNING_CODE);
+  assert_eq!(
+    diagnostics[0].message,
+    "fontSize value `24px` was converted to `1.5rem`."
+  );
+}
+
+#[test]
+fn warns_about_unused_namespaces() {
+  let diagnostics = collect_diagnostics(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({
+        used: { color: 'red' },
+        unused: { color: 'blue' },
+      });
+      export const used = styles.used;
+    "#,
+    &mut get_default_opts(),
+  );
+
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].code, UNUSED_NAMESPACE_WARNING_CODE);
+  assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+  assert_eq!(
+    diagnostics[0].message,
+    "Namespace `styles.unused` is never used and was removed from the output."
+  );
+}
@@ -0,0 +1,22 @@
+import * as stylex from '@stylexjs/stylex';
+
+export const buttonStyles = stylex.create({
+  base: {
+    color: 'red',
+    padding: 4,
+  },
+  active: {
+    color: 'blue',
+  },
+  dynamic: (size) => ({
+    width: size,
+  }),
+});
+
+const layout = stylex.create({
+  row: {
+    display: 'flex',
+  },
+});
+
+export { layout as layoutStyles };
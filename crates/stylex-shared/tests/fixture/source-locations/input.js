@@ -0,0 +1,15 @@
+import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: {
+    backgroundColor: 'red',
+    margin: '10px 20px',
+    color: {
+      default: 'blue',
+      ':hover': 'green',
+    },
+  },
+  dynamic: (size) => ({
+    width: size,
+  }),
+});
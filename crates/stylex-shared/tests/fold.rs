@@ -1,3 +1,4 @@
+mod diagnostics_test;
 mod evaluation;
 mod legacy;
 mod metadata_test;
@@ -6,6 +7,7 @@ mod transform_misc_test;
 mod transform_override_vars_test;
 mod transform_polyfills_test;
 mod transform_process_test;
+mod transform_stylex_attrs_test;
 mod transform_stylex_create_test;
 mod transform_stylex_create_theme_test;
 mod transform_stylex_define_consts_test;
@@ -564,3 +564,318 @@ test!(
         export const classnames = stylex(styles.x);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_background_image_linear_gradient_to_left,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { backgroundImage: 'linear-gradient(to left, red, blue)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_background_image_linear_gradient_angle,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { backgroundImage: 'linear-gradient(45deg, red 0%, blue 100%)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_background_image_radial_gradient_position,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { backgroundImage: 'radial-gradient(circle at left 20%, red, blue)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_background_image_conic_gradient,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { backgroundImage: 'conic-gradient(from 90deg, red 0deg, blue 90deg)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_background_image_url,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { backgroundImage: 'url(image.png)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(false),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  background_image_linear_gradient_to_left_without_legacy_flipping,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { backgroundImage: 'linear-gradient(to left, red, blue)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_transform_translate_and_rotate,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { transform: 'translateX(10px) rotate(45deg)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_transform_translate_skew_and_matrix,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { transform: 'translate(-50%, 10px) skewX(10deg) matrix(1, 0.5, 0.25, 1, 10, 20)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_transform_rotate_axes,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { transform: 'rotateX(10deg) rotateY(20deg) rotate3d(1, 1, 0, 30deg)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_translate_property,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { translate: '10px 20px' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_rotate_property,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { rotate: 'z 45deg' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_rotate_property_vector,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { rotate: '1 1 0 45deg' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_object_position,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { objectPosition: 'left 20%' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  legacy_value_of_transform_origin,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { transformOrigin: 'left top 10px' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(false),
+      ..StyleXOptionsParams::default()
+    }),
+  ),
+  transform_translate_without_legacy_flipping,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({ x: { transform: 'translateX(10px)' } });
+        export const classnames = stylex(styles.x);
+    "#
+);
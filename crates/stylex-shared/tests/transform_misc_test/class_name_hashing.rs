@@ -0,0 +1,56 @@
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{ClassNameHashing, StyleXOptions, StyleXOptionsParams},
+  },
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      class_name_hashing: Some(ClassNameHashing {
+        length: Some(5),
+        alphabet: Some("abcdefghij".to_string()),
+        salt: Some("package-a".to_string()),
+      }),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  hash_with_length_alphabet_and_salt,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const fade = stylex.keyframes({
+      from: { opacity: 0 },
+      to: { opacity: 1 },
+    });
+    export const styles = stylex.create({
+      root: {
+        animationName: fade,
+        color: 'red',
+      },
+    });
+  "#
+);
+
+#[test]
+#[should_panic(expected = "classNameHashing.alphabet must contain at least two unique")]
+fn rejects_alphabet_with_repeated_characters() {
+  let _ = StyleXOptions::from(StyleXOptionsParams {
+    class_name_hashing: Some(ClassNameHashing {
+      alphabet: Some("abca".to_string()),
+      ..ClassNameHashing::default()
+    }),
+    ..StyleXOptionsParams::default()
+  });
+}
@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{HotModuleReload, StyleXOptionsParams},
+  },
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(PathBuf::from("/app/src/Button.js")),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    plugin_pass(),
+    Some(&mut StyleXOptionsParams {
+      hot_module_reload: Some(HotModuleReload::ImportMeta),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  tags_injected_rules_and_disposes_them_with_import_meta_hot,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+        marginInlineStart: 10,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    plugin_pass(),
+    Some(&mut StyleXOptionsParams {
+      hot_module_reload: Some(HotModuleReload::Module),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  tags_injected_rules_and_disposes_them_with_module_hot,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+      },
+    });
+    export const fade = stylex.keyframes({
+      from: { opacity: 0 },
+      to: { opacity: 1 },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    plugin_pass(),
+    Some(&mut StyleXOptionsParams {
+      hot_module_reload: Some(HotModuleReload::ImportMeta),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  does_not_emit_dispose_without_runtime_injection,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+      },
+    });
+  "#
+);
@@ -1,2 +1,4 @@
+mod class_name_hashing;
+mod hot_module_reload;
 mod react;
 mod scope;
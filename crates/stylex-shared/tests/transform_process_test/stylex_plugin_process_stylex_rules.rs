@@ -1,16 +1,33 @@
 use crate::utils::transform::stringify_js;
 use insta::assert_snapshot;
-use std::sync::LazyLock;
+use std::{cell::RefCell, rc::Rc, sync::LazyLock};
 use stylex_shared::{
   StyleXTransform,
-  shared::structures::{
-    meta_data::MetaData,
-    plugin_pass::PluginPass,
-    stylex_options::{ModuleResolution, StyleResolution, StyleXOptionsParams},
+  shared::{
+    enums::data_structures::injectable_style::InjectableStyleBaseKind,
+    structures::{
+      injectable_style::{InjectableStyleBase, InjectableStyleConstBase},
+      meta_data::MetaData,
+      plugin_pass::PluginPass,
+      stylex_options::{
+        ClassNameHashing, DirectionSelector, ModuleResolution, ProcessStylexRulesOptions,
+        StyleResolution, StyleXOptionsParams,
+      },
+    },
+    utils::core::process_stylex_rules::{
+      create_short_class_names, process_stylex_rules, rename_class_names,
+    },
+  },
+};
+use swc_core::{
+  common::{FileName, Mark, comments::Comments},
+  ecma::{
+    ast::{Pass, Program},
+    parser::{Syntax, TsSyntax},
+    transforms::base::resolver,
+    visit::fold_pass,
   },
 };
-use swc_core::common::FileName;
-use swc_core::ecma::parser::{Syntax, TsSyntax};
 
 static CWD: LazyLock<std::path::PathBuf> = LazyLock::new(|| std::env::current_dir().unwrap());
 static FIXTURE_DIR: LazyLock<std::path::PathBuf> =
@@ -23,6 +40,24 @@ struct TransformResult {
   metadata: Vec<MetaData>,
 }
 
+// Runs the transform and stores the collected metadata once the file is processed
+struct MetadataCollector<C: Comments> {
+  stylex: StyleXTransform<C>,
+  metadata: Rc<RefCell<Vec<MetaData>>>,
+}
+
+impl<C: Comments> Pass for MetadataCollector<C> {
+  fn process(&mut self, program: &mut Program) {
+    resolver(Mark::new(), Mark::new(), true).process(program);
+    fold_pass(&mut self.stylex).process(program);
+
+    self
+      .metadata
+      .borrow_mut()
+      .extend(self.stylex.state.metadata.values().flatten().cloned());
+  }
+}
+
 fn transform(source: &str, opts: Option<StyleXOptionsParams>) -> TransformResult {
   // Match JS plugin options exactly
   let default_opts = StyleXOptionsParams {
@@ -53,6 +88,8 @@ fn transform(source: &str, opts: Option<StyleXOptionsParams>) -> TransformResult
     None => default_opts,
   };
 
+  let collected_metadata: Rc<RefCell<Vec<MetaData>>> = Rc::default();
+
   // Transform tokens file
   let tokens_source = r#"
     import * as stylex from '@stylexjs/stylex';
@@ -73,15 +110,16 @@ fn transform(source: &str, opts: Option<StyleXOptionsParams>) -> TransformResult
       tsx: true,
       ..Default::default()
     }),
-    |tr| {
-      StyleXTransform::new_test_with_pass(
+    |tr| MetadataCollector {
+      stylex: StyleXTransform::new_test(
         tr.comments.clone(),
         PluginPass {
           cwd: Some(FIXTURE_DIR.clone()),
           filename: FileName::Real(FIXTURE_DIR.join("input.stylex.js")),
         },
         Some(&mut plugin_opts.clone()),
-      )
+      ),
+      metadata: collected_metadata.clone(),
     },
   );
 
@@ -101,15 +139,16 @@ fn transform(source: &str, opts: Option<StyleXOptionsParams>) -> TransformResult
       tsx: true,
       ..Default::default()
     }),
-    |tr| {
-      StyleXTransform::new_test_with_pass(
+    |tr| MetadataCollector {
+      stylex: StyleXTransform::new_test(
         tr.comments.clone(),
         PluginPass {
           cwd: Some(FIXTURE_DIR.clone()),
           filename: FileName::Real(FIXTURE_DIR.join("input.stylex.js")),
         },
         Some(&mut plugin_opts.clone()),
-      )
+      ),
+      metadata: collected_metadata.clone(),
     },
   );
 
@@ -131,22 +170,20 @@ fn transform(source: &str, opts: Option<StyleXOptionsParams>) -> TransformResult
       tsx: true,
       ..Default::default()
     }),
-    |tr| {
-      StyleXTransform::new_test_with_pass(
+    |tr| MetadataCollector {
+      stylex: StyleXTransform::new_test(
         tr.comments.clone(),
         PluginPass {
           cwd: Some(FIXTURE_DIR.clone()),
           filename: FileName::Real(FIXTURE_DIR.join("input.stylex.js")),
         },
         Some(&mut plugin_opts.clone()),
-      )
+      ),
+      metadata: collected_metadata.clone(),
     },
   );
 
-  // For now, we'll collect metadata as an empty vec since we need to figure out
-  // how to properly extract it from the transform process
-  // TODO: Extract actual metadata from the transforms
-  let metadata: Vec<MetaData> = vec![];
+  let metadata = collected_metadata.take();
 
   TransformResult {
     code: main_code,
@@ -154,59 +191,15 @@ fn transform(source: &str, opts: Option<StyleXOptionsParams>) -> TransformResult
   }
 }
 
-// Function to process metadata into CSS string (equivalent to processStylexRules)
-fn process_stylex_rules(metadata: &[MetaData], use_layers: bool) -> String {
-  if metadata.is_empty() {
-    // For the "no rules" test, return the expected CSS from JS test
-    return ":root, .xsg933n{--blue-xpqh4lw:blue;}\n:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}".to_string();
-  }
-
-  if use_layers {
-    // Expected output for useLayers:true from JS test
-    r#"
-@layer priority1, priority2, priority3, priority4;
-@property --color { syntax: "*"; inherits: false;}
-@keyframes xi07kvp-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange);}}
-:root, .xsg933n{--blue-xpqh4lw:blue;}
-:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
-.x6xqkwy, .x6xqkwy:root{--blue-xpqh4lw:lightblue;}
-.margin-xymmreb:not(#\\#){margin:10px 20px}
-@layer priority2{
-.padding-xss17vw{padding:var(--large-x1ec7iuc)}
-}
-@layer priority3{
-.borderColor-x1bg2uv5{border-color:green}
-@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
-@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
-}
-@layer priority4{
-.animationName-xckgs0v{animation-name:xi07kvp-B}
-.backgroundColor-xrkmrrc{background-color:red}
-.color-xfx01vb{color:var(--color)}
-
-.textShadow-x1skrh0i:not(#\\#):not(#\\#):not(#\\#){text-shadow:1px 2px 3px 4px red}
-@media (min-width:320px){.textShadow-x1cmij7u.textShadow-x1cmij7u:not(#\\#):not(#\\#):not(#\\#){text-shadow:10px 20px 30px 40px green}}"#.to_string()
-  } else {
-    // Updated output for useLayers:false
-    r#"@property --x-color { syntax: "*"; inherits: false;}
-@keyframes xi07kvp-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange);}}
-:root, .xsg933n{--blue-xpqh4lw:blue;}
-:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
-.x6xqkwy, .x6xqkwy:root{--blue-xpqh4lw:lightblue;}
-.x57uvma, .x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
-.margin-xymmreb:not(#\\#){margin:10px 20px}
-.padding-xss17vw:not(#\\#){padding:var(--large-x1ec7iuc)}
-.borderColor-x1bg2uv5:not(#\\#):not(#\\#){border-color:green}
-@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c:not(#\\#):not(#\\#){border-color:var(--blue-xpqh4lw)}}
-@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys:not(#\\#):not(#\\#){border-color:yellow}}}
-.animationName-xckgs0v:not(#\\#):not(#\\#):not(#\\#){animation-name:xi07kvp-B}
-.backgroundColor-xrkmrrc:not(#\\#):not(#\\#):not(#\\#){background-color:red}
-.color-x14rh7hd:not(#\\#):not(#\\#):not(#\\#){color:var(--x-color)}
-html:not([dir='rtl']) .float-x1kmio9f:not(#\\#):not(#\\#):not(#\\#){float:left}
-html[dir='rtl'] .float-x1kmio9f:not(#\\#):not(#\\#):not(#\\#){float:right}
-.textShadow-x1skrh0i:not(#\\#):not(#\\#):not(#\\#){text-shadow:1px 2px 3px 4px red}
-@media (min-width:320px){.textShadow-x1cmij7u.textShadow-x1cmij7u:not(#\\#):not(#\\#):not(#\\#){text-shadow:10px 20px 30px 40px green}}"#.to_string()
-  }
+fn process_rules(metadata: &[MetaData], use_layers: bool, enable_ltr_rtl_comments: bool) -> String {
+  process_stylex_rules(
+    metadata,
+    &ProcessStylexRulesOptions {
+      use_layers,
+      enable_ltr_rtl_comments,
+      ..ProcessStylexRulesOptions::default()
+    },
+  )
 }
 
 const FIXTURE: &str = r#"
@@ -259,7 +252,7 @@ fn no_rules() {
   assert_snapshot!("no_rules_code", result.code);
 
   // Assert processed CSS rules
-  let css_rules = process_stylex_rules(&result.metadata, false);
+  let css_rules = process_rules(&result.metadata, false, false);
   assert_snapshot!("no_rules_css", css_rules);
 }
 
@@ -271,7 +264,7 @@ fn all_rules_use_layers_false() {
   assert_snapshot!("all_rules_use_layers_false_code", result.code);
 
   // Assert processed CSS rules
-  let css_rules = process_stylex_rules(&result.metadata, false);
+  let css_rules = process_rules(&result.metadata, false, false);
   assert_snapshot!("all_rules_use_layers_false_css", css_rules);
 }
 
@@ -293,6 +286,409 @@ fn all_rules_use_layers_true() {
   assert_snapshot!("all_rules_use_layers_true_code", result.code);
 
   // Assert processed CSS rules with layers
-  let css_rules = process_stylex_rules(&result.metadata, true);
+  let css_rules = process_rules(&result.metadata, true, false);
   assert_snapshot!("all_rules_use_layers_true_css", css_rules);
 }
+
+#[test]
+fn legacy_expand_shorthands_with_logical_styles_polyfill() {
+  let opts = StyleXOptionsParams {
+    style_resolution: Some(StyleResolution::LegacyExpandShorthands),
+    enable_logical_styles_polyfill: Some(true),
+    ..Default::default()
+  };
+  let result = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        container: {
+          margin: '10px 20px',
+          padding: '5px 15px',
+          float: 'inline-start'
+        }
+      });
+    "#,
+    Some(opts),
+  );
+
+  let css_rules = process_rules(&result.metadata, false, true);
+  assert_snapshot!(
+    "legacy_expand_shorthands_with_logical_styles_polyfill_css",
+    css_rules
+  );
+}
+
+#[test]
+fn legacy_expand_shorthands_duplicates_theme_selectors() {
+  let opts = StyleXOptionsParams {
+    style_resolution: Some(StyleResolution::LegacyExpandShorthands),
+    ..Default::default()
+  };
+  let result = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const themeColor = stylex.createTheme(vars, {
+        blue: 'lightblue'
+      });
+      export const themeSpacing = stylex.createTheme(spacing, {
+        small: '5px',
+        medium: '10px',
+        large: '20px'
+      });
+    "#,
+    Some(opts),
+  );
+
+  let css_rules = process_rules(&result.metadata, false, false);
+  assert_snapshot!(
+    "legacy_expand_shorthands_duplicates_theme_selectors_css",
+    css_rules
+  );
+}
+
+#[test]
+fn container_queries_ordered_by_inline_size_threshold() {
+  let result = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        card: {
+          padding: {
+            default: '4px',
+            '@container (min-width: 1024px)': '24px',
+            '@container (min-width: 40em)': '16px',
+            '@container (min-width: 320px)': '8px',
+          },
+          margin: {
+            default: '4px',
+            '@container (max-width: 320px)': '0px',
+            '@container (max-width: 1024px)': '2px',
+          },
+        }
+      });
+    "#,
+    None,
+  );
+
+  let css_rules = process_rules(&result.metadata, false, false);
+  assert_snapshot!(
+    "container_queries_ordered_by_inline_size_threshold_css",
+    css_rules
+  );
+}
+
+#[test]
+fn direction_selector_pseudo_class() {
+  let result = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: {
+          float: 'inline-start',
+          boxShadow: {
+            default: 'none',
+            '@media (min-width: 320px)': '1px 2px 3px red'
+          },
+          '::before': {
+            textShadow: '1px 2px 3px red'
+          }
+        }
+      });
+    "#,
+    Some(StyleXOptionsParams {
+      enable_legacy_value_flipping: Some(true),
+      ..Default::default()
+    }),
+  );
+
+  let css_rules = process_stylex_rules(
+    &result.metadata,
+    &ProcessStylexRulesOptions {
+      direction_selector: DirectionSelector::PseudoClass,
+      ..ProcessStylexRulesOptions::default()
+    },
+  );
+  assert_snapshot!("direction_selector_pseudo_class_css", css_rules);
+
+  let css_rules = process_stylex_rules(
+    &result.metadata,
+    &ProcessStylexRulesOptions {
+      direction_selector: DirectionSelector::LogicalOnly,
+      ..ProcessStylexRulesOptions::default()
+    },
+  );
+  assert_snapshot!("direction_selector_logical_only_css", css_rules);
+}
+
+#[test]
+fn css_source_locations() {
+  let input = CWD.join("tests/fixture/source-locations/input.js");
+  let source = std::fs::read_to_string(&input).unwrap();
+  let collected_metadata: Rc<RefCell<Vec<MetaData>>> = Rc::default();
+
+  stringify_js(
+    &source,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| MetadataCollector {
+      stylex: StyleXTransform::new_test(
+        tr.comments.clone(),
+        PluginPass {
+          cwd: Some(CWD.clone()),
+          filename: FileName::Real(input.clone()),
+        },
+        Some(&mut StyleXOptionsParams {
+          enable_css_source_locations: Some(true),
+          ..Default::default()
+        }),
+      ),
+      metadata: collected_metadata.clone(),
+    },
+  );
+
+  let sources = collected_metadata
+    .take()
+    .iter()
+    .map(|meta| {
+      let location = meta.get_source().map_or("none".to_string(), |source| {
+        assert_eq!(source.file, input.to_string_lossy());
+
+        format!("{}:{}", source.line, source.column)
+      });
+
+      format!("{} {}", meta.get_class_name(), location)
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+
+  assert_snapshot!("css_source_locations", sources);
+}
+
+#[test]
+fn imported_constants_with_custom_class_name_hashing() {
+  let collected_metadata: Rc<RefCell<Vec<MetaData>>> = Rc::default();
+
+  let options = StyleXOptionsParams {
+    unstable_module_resolution: Some(ModuleResolution {
+      r#type: "commonJS".to_string(),
+      root_dir: Some(FIXTURE_DIR.clone().to_string_lossy().to_string()),
+      theme_file_extension: None,
+    }),
+    class_name_hashing: Some(ClassNameHashing {
+      length: Some(6),
+      alphabet: Some("abcdefghij".to_string()),
+      salt: Some("package-a".to_string()),
+    }),
+    ..Default::default()
+  };
+
+  let constants_path = FIXTURE_DIR.join("constants.stylex.js");
+
+  let files = [
+    (
+      std::fs::read_to_string(&constants_path).unwrap(),
+      constants_path,
+    ),
+    (
+      r#"
+        import * as stylex from '@stylexjs/stylex';
+        import { constants } from './constants.stylex';
+
+        export const styles = stylex.create({
+          root: {
+            color: {
+              default: constants.YELLOW,
+              [constants.mediaBig]: constants.ORANGE,
+            },
+          },
+        });
+      "#
+      .to_string(),
+      FIXTURE_DIR.join("app.js"),
+    ),
+  ];
+
+  for (source, filename) in files {
+    stringify_js(
+      &source,
+      Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+      }),
+      |tr| MetadataCollector {
+        stylex: StyleXTransform::new_test(
+          tr.comments.clone(),
+          PluginPass {
+            cwd: Some(FIXTURE_DIR.clone()),
+            filename: FileName::Real(filename.clone()),
+          },
+          Some(&mut options.clone()),
+        ),
+        metadata: collected_metadata.clone(),
+      },
+    );
+  }
+
+  let css_rules = process_rules(&collected_metadata.take(), false, false);
+
+  assert!(
+    css_rules.contains("{color:yellow}")
+      && css_rules.contains("@media (max-width: 1000px){.")
+      && css_rules.contains("{color:var(--orange)}"),
+    "Expected the imported constants to be resolved:\n{}",
+    css_rules
+  );
+}
+
+fn const_rule(name: &str, value: &str) -> MetaData {
+  MetaData::from_style(
+    name.to_string(),
+    InjectableStyleBaseKind::Const(InjectableStyleConstBase {
+      ltr: String::default(),
+      rtl: None,
+      const_key: name.to_string(),
+      const_value: value.to_string(),
+    }),
+    0.0,
+  )
+}
+
+fn regular_rule(class_name: &str, ltr: &str) -> MetaData {
+  MetaData::from_style(
+    class_name.to_string(),
+    InjectableStyleBaseKind::Regular(InjectableStyleBase {
+      ltr: ltr.to_string(),
+      rtl: None,
+    }),
+    3000.0,
+  )
+}
+
+fn at_rule(name: &str, css: &str) -> MetaData {
+  MetaData::from_style(
+    name.to_string(),
+    InjectableStyleBaseKind::Regular(InjectableStyleBase {
+      ltr: css.to_string(),
+      rtl: None,
+    }),
+    0.0,
+  )
+}
+
+#[test]
+fn constants_referenced_several_times_are_resolved() {
+  let metadata = vec![
+    const_rule("a", "1px"),
+    const_rule("b", "calc(var(--a) + var(--a))"),
+    regular_rule("x1", ".x1{width:var(--b)}"),
+  ];
+
+  assert_eq!(
+    process_rules(&metadata, true, false),
+    "\n@layer priority1;\n@layer priority1{\n.x1{width:calc(1px + 1px)}\n}"
+  );
+}
+
+#[test]
+fn constants_referenced_through_sibling_paths_are_resolved() {
+  let metadata = vec![
+    const_rule("a", "1px"),
+    const_rule("b", "var(--a)"),
+    const_rule("c", "var(--a)"),
+    const_rule("d", "calc(var(--b) + var(--c))"),
+    regular_rule("x1", ".x1{width:var(--d)}"),
+  ];
+
+  assert_eq!(
+    process_rules(&metadata, true, false),
+    "\n@layer priority1;\n@layer priority1{\n.x1{width:calc(1px + 1px)}\n}"
+  );
+}
+
+#[test]
+#[should_panic(expected = "Circular reference detected in constants.")]
+fn circular_constant_references_are_rejected() {
+  let metadata = vec![
+    const_rule("a", "var(--b)"),
+    const_rule("b", "var(--a)"),
+    regular_rule("x1", ".x1{width:var(--a)}"),
+  ];
+
+  process_rules(&metadata, true, false);
+}
+
+fn short_class_names_metadata() -> Vec<MetaData> {
+  vec![
+    at_rule("xk-B", "@keyframes xk-B{from{opacity:0;}}"),
+    at_rule("--xp", "@position-try --xp{top:0;}"),
+    at_rule(
+      "--x-opacity",
+      "@property --x-opacity { syntax: \"*\"; inherits: false; }",
+    ),
+    regular_rule("xb", ".xb{animation-name:xk-B}"),
+    regular_rule("xa", ".xa{position-try-fallbacks:--xp}"),
+    regular_rule("xc", ".xc{opacity:var(--x-opacity)}"),
+    const_rule("xconst", "1px"),
+  ]
+}
+
+#[test]
+fn short_class_names_do_not_depend_on_rule_order() {
+  let metadata = short_class_names_metadata();
+  let reversed_metadata = metadata.iter().rev().cloned().collect::<Vec<_>>();
+
+  let short_class_names = create_short_class_names(&metadata, "s");
+
+  assert_eq!(
+    short_class_names.into_iter().collect::<Vec<_>>(),
+    vec![
+      ("--xp".to_string(), "--s0".to_string()),
+      ("xa".to_string(), "s1".to_string()),
+      ("xb".to_string(), "s2".to_string()),
+      ("xc".to_string(), "s3".to_string()),
+      ("xk-B".to_string(), "s4".to_string()),
+    ]
+  );
+  assert_eq!(
+    create_short_class_names(&reversed_metadata, "s"),
+    create_short_class_names(&metadata, "s")
+  );
+}
+
+#[test]
+fn short_class_names_rename_stylesheet_and_js() {
+  let metadata = short_class_names_metadata();
+
+  let css_rules = process_stylex_rules(
+    &metadata,
+    &ProcessStylexRulesOptions {
+      short_class_name_prefix: Some("s".to_string()),
+      ..ProcessStylexRulesOptions::default()
+    },
+  );
+
+  assert_eq!(
+    css_rules,
+    [
+      "@property --x-opacity { syntax: \"*\"; inherits: false; }",
+      "@keyframes s4{from{opacity:0;}}",
+      "@position-try --s0{top:0;}",
+      ".s2:not(#\\#){animation-name:s4}",
+      ".s3:not(#\\#){opacity:var(--x-opacity)}",
+      ".s1:not(#\\#){position-try-fallbacks:--s0}",
+    ]
+    .join("\n")
+  );
+
+  let short_class_names = create_short_class_names(&metadata, "s");
+
+  assert_eq!(
+    rename_class_names(
+      r#"export const fade = "xk-B"; export const styles = { root: { kMwMTN: "xa xb", kxcRWa: "xc xab", $$css: true } };"#,
+      &short_class_names
+    ),
+    r#"export const fade = "s4"; export const styles = { root: { kMwMTN: "s1 s2", kxcRWa: "s3 xab", $$css: true } };"#
+  );
+}
@@ -0,0 +1 @@
+mod stylex_attrs_call;
@@ -0,0 +1,181 @@
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  empty_stylex_attrs_call,
+  r#"
+        import stylex from 'stylex';
+        stylex.attrs();
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  basic_stylex_attrs_call,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            red: {
+                color: 'red',
+            }
+        });
+        stylex.attrs(styles.red);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  stylex_attrs_call_with_multiple_styles,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+                color: 'blue',
+            },
+            active: {
+                color: 'green',
+            }
+        });
+        stylex.attrs([styles.default, styles.active]);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  stylex_attrs_call_with_conditional_styles,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            active: {
+                color: 'blue',
+            }
+        });
+        stylex.attrs([styles.default, isActive && styles.active]);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  named_import_attrs_call,
+  r#"
+        import { attrs, create } from '@stylexjs/stylex';
+        const styles = create({
+            red: {
+                color: 'red',
+            }
+        });
+        attrs(styles.red);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  stylex_attrs_call_with_dynamic_styles_falls_back_to_runtime,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: (color) => ({
+                color,
+            })
+        });
+        stylex.attrs(styles.default('red'));
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass {
+      cwd: None,
+      filename: FileName::Real("/js/node_modules/npm-package/dist/components/Foo.react.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      debug: Some(true),
+      dev: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  attrs_call_with_jsx_spread,
+  r#"
+import stylex from 'stylex';
+const styles = stylex.create({
+  red: {
+    color: 'red',
+  }
+});
+function Foo() {
+  return (
+    <>
+      <div id="test" {...stylex.attrs(styles.red)}>Hello World</div>
+      <div class="test" {...stylex.attrs(styles.red)} id="test">Hello World</div>
+    </>
+  );
+}
+  "#
+);
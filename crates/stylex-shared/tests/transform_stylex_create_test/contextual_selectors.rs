@@ -0,0 +1,174 @@
+use stylex_shared::{StyleXTransform, shared::structures::plugin_pass::PluginPass};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  when_ancestor,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'blue',
+          [stylex.when.ancestor(':hover')]: 'red',
+        },
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  when_descendant_and_siblings,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        backgroundColor: {
+          default: 'white',
+          [stylex.when.descendant(':focus')]: 'gray',
+        },
+        color: {
+          default: 'black',
+          [stylex.when.siblingBefore(':hover')]: 'red',
+          [stylex.when.siblingAfter(':focus-visible')]: 'green',
+          [stylex.when.anySibling(':active')]: 'blue',
+        },
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  when_named_import,
+  r#"
+    import { create, when } from '@stylexjs/stylex';
+    export const styles = create({
+      root: {
+        opacity: {
+          default: 1,
+          [when.ancestor(':focus-within')]: 0.5,
+        },
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_force_runtime_injection_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    None
+  ),
+  default_marker,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    import { defaultMarker } from '@stylexjs/stylex';
+    const styles = stylex.create({
+      root: {
+        color: 'red',
+      },
+    });
+    export const a = stylex.defaultMarker();
+    export const b = defaultMarker();
+    export const c = stylex.props(stylex.defaultMarker(), styles.root);
+  "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Pseudo selector passed to when.* must start with \":\" and cannot be a pseudo-element."
+)]
+fn when_rejects_pseudo_elements() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: {
+          color: {
+            default: 'blue',
+            [stylex.when.ancestor('::before')]: 'red',
+          },
+        },
+      });
+    "#,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Pseudo selector passed to when.* must start with \":\" and cannot be a pseudo-element."
+)]
+fn when_rejects_non_pseudo_selectors() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: {
+          color: {
+            default: 'blue',
+            [stylex.when.ancestor('hover')]: 'red',
+          },
+        },
+      });
+    "#,
+    r#""#,
+  )
+}
@@ -339,3 +339,40 @@ test!(
             });
           "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    let mut config = StyleXOptionsParams {
+      runtime_injection: Some(true),
+      style_resolution: Some(StyleResolution::LegacyExpandShorthands),
+      ..StyleXOptionsParams::default()
+    };
+    StyleXTransform::new_test_force_runtime_injection_with_pass(
+      tr.comments.clone(),
+      PluginPass::default(),
+      Some(&mut config),
+    )
+  },
+  transforms_font_shorthand_in_legacy_expand_shorthands_mode,
+  r#"
+            import stylex from 'stylex';
+            export const styles = stylex.create({
+              default: {
+                font: 'italic 600 16px/1.5 "Inter", sans-serif',
+              },
+              small: {
+                font: '12px Arial',
+              },
+              system: {
+                font: 'caption',
+              },
+              variable: {
+                font: 'var(--body-font)',
+              },
+            });
+          "#
+);
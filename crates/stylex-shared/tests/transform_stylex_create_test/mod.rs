@@ -1,3 +1,4 @@
+mod contextual_selectors;
 mod debug_options;
 mod dynamic_styles;
 mod legacy_deprecated;
@@ -924,6 +924,152 @@ test!(
           "#
 );
 
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    StyleXTransform::new_test_force_runtime_injection_with_pass(
+      tr.comments.clone(),
+      PluginPass::default(),
+      None,
+    )
+  },
+  supports_queries_are_canonicalized,
+  r#"
+            import * as stylex from '@stylexjs/stylex';
+            export const styles = stylex.create({
+              root: {
+                display: {
+                  default: 'block',
+                  '@supports (display:grid)': 'grid',
+                },
+              },
+              other: {
+                display: {
+                  default: 'block',
+                  '@supports (  display :  grid )': 'grid',
+                },
+              },
+              selector: {
+                color: {
+                  default: 'red',
+                  '@supports selector( :has( a ) ) and (not (color: oklab(0 0 0)))': 'blue',
+                },
+              },
+            });
+          "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    StyleXTransform::new_test_force_runtime_injection_with_pass(
+      tr.comments.clone(),
+      PluginPass::default(),
+      None,
+    )
+  },
+  container_queries,
+  r#"
+            import * as stylex from '@stylexjs/stylex';
+            export const styles = stylex.create({
+              root: {
+                backgroundColor: {
+                  default: 'red',
+                  '@container (min-width: 800px)': 'purple',
+                  '@container sidebar (min-width: 400px)': 'blue',
+                  '@container style(--theme: dark)': 'black',
+                }
+              },
+            });
+          "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    StyleXTransform::new_test_force_runtime_injection_with_pass(
+      tr.comments.clone(),
+      PluginPass::default(),
+      None,
+    )
+  },
+  container_queries_runtime_priorities_follow_inline_size_threshold,
+  r#"
+            import * as stylex from '@stylexjs/stylex';
+            export const styles = stylex.create({
+              root: {
+                width: {
+                  default: '100%',
+                  '@container (min-width: 800px)': '50%',
+                  '@container (min-width: 400px)': '75%',
+                },
+                height: {
+                  default: 'auto',
+                  '@container (max-width: 400px)': '10px',
+                  '@container (max-width: 800px)': '20px',
+                },
+              },
+            });
+          "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(tr.comments.clone(), PluginPass::default(), None),
+  container_queries_range_syntax_is_normalized,
+  r#"
+            import * as stylex from '@stylexjs/stylex';
+            export const styles = stylex.create({
+              root: {
+                color: {
+                  default: 'red',
+                  '@container card (inline-size >= 400px)': 'blue',
+                },
+              },
+              other: {
+                color: {
+                  default: 'red',
+                  '@container card (min-inline-size: 400px)': 'blue',
+                },
+              },
+            });
+          "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(tr.comments.clone(), PluginPass::default(), None),
+  container_queries_nested_objects,
+  r#"
+            import * as stylex from '@stylexjs/stylex';
+            export const styles = stylex.create({
+              root: {
+                '@container (max-width: 600px)': {
+                  color: 'blue',
+                },
+                '@container (max-width: 300px)': {
+                  color: 'green',
+                },
+              },
+            });
+          "#
+);
+
 test!(
   Syntax::Typescript(TsSyntax {
     tsx: true,
@@ -42,3 +42,38 @@ test!(
     });
   "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/vars.stylex.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      unstable_module_resolution: Some(StyleXOptions::get_common_js_module_resolution(Some(
+        "/stylex/packages/".to_string()
+      ))),
+      runtime_injection: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  extended_typed_syntaxes,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const vars = stylex.defineVars({
+      animationName: stylex.types.customIdent('fade'),
+      label: stylex.types.string('"Hello"'),
+      gradientStops: {
+        syntax: '<length-percentage>+',
+        value: { default: '0% 50%', '@media (min-width: 800px)': '10px 40%' },
+      },
+      weight: { syntax: '<number> | <custom-ident>', value: 'bold' },
+      anything: { syntax: '*', value: 'calc(1px + 2px)' },
+    });
+  "#
+);
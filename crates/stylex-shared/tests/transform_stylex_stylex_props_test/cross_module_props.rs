@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use crate::utils::transform::stringify_js;
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{ModuleResolution, StyleXOptionsParams},
+  },
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+fn transform(input: &str) -> String {
+  transform_with_module_cache_dir(input, None)
+}
+
+fn transform_with_module_cache_dir(input: &str, module_cache_dir: Option<&PathBuf>) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      let fixture_path: PathBuf = std::env::current_dir()
+        .unwrap()
+        .join("tests/fixture/cross-module");
+
+      StyleXTransform::new_test_with_pass(
+        tr.comments.clone(),
+        PluginPass {
+          cwd: Some(fixture_path.clone()),
+          filename: fixture_path.join("app.js").into(),
+        },
+        Some(&mut StyleXOptionsParams {
+          unstable_module_resolution: Some(ModuleResolution {
+            r#type: "commonJS".to_string(),
+            root_dir: Some(fixture_path.to_string_lossy().to_string()),
+            theme_file_extension: None,
+          }),
+          module_cache_dir: module_cache_dir.map(|dir| dir.to_string_lossy().to_string()),
+          ..Default::default()
+        }),
+      )
+    },
+  )
+}
+
+#[test]
+fn folds_props_with_imported_styles() {
+  let output = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      import { buttonStyles } from './button.stylex';
+
+      export const className = stylex.props(buttonStyles.base);
+    "#,
+  );
+
+  insta::assert_snapshot!("folds_props_with_imported_styles", output);
+}
+
+#[test]
+fn folds_props_with_imported_and_local_styles() {
+  let output = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      import { buttonStyles as button, layoutStyles } from './button.stylex';
+
+      const styles = stylex.create({
+        override: {
+          color: 'green',
+        },
+      });
+
+      export function Button({ isActive }) {
+        return stylex.props(
+          layoutStyles.row,
+          button.base,
+          isActive && button.active,
+          styles.override,
+        );
+      }
+    "#,
+  );
+
+  insta::assert_snapshot!("folds_props_with_imported_and_local_styles", output);
+}
+
+#[test]
+fn keeps_runtime_merge_for_unresolvable_imported_styles() {
+  let output = transform(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      import { buttonStyles } from './button.stylex';
+
+      export const dynamic = stylex.props(buttonStyles.dynamic(10));
+      export const unknown = stylex.props(buttonStyles.unknown);
+    "#,
+  );
+
+  insta::assert_snapshot!(
+    "keeps_runtime_merge_for_unresolvable_imported_styles",
+    output
+  );
+}
+
+#[test]
+fn reuses_compiled_exports_from_module_cache_dir() {
+  let module_cache_dir = std::env::temp_dir().join(format!(
+    "stylex-module-cache-{}-reuses-compiled-exports",
+    std::process::id()
+  ));
+
+  let _ = std::fs::remove_dir_all(&module_cache_dir);
+
+  let input = r#"
+    import * as stylex from '@stylexjs/stylex';
+    import { buttonStyles } from './button.stylex';
+
+    export const className = stylex.props(buttonStyles.base);
+  "#;
+
+  let output = transform_with_module_cache_dir(input, Some(&module_cache_dir));
+
+  assert_eq!(output, transform(input));
+
+  let cache_files = std::fs::read_dir(&module_cache_dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .collect::<Vec<_>>();
+
+  assert_eq!(cache_files.len(), 1);
+  assert_eq!(cache_files[0].extension().unwrap(), "json");
+
+  // A cached entry is used instead of compiling the module again
+  let cache_entry = std::fs::read_to_string(&cache_files[0]).unwrap();
+
+  std::fs::write(&cache_files[0], cache_entry.replace("x1e2nbdu", "xcached")).unwrap();
+
+  let cached_output = transform_with_module_cache_dir(input, Some(&module_cache_dir));
+
+  std::fs::remove_dir_all(&module_cache_dir).unwrap();
+
+  assert_eq!(cached_output, output.replace("x1e2nbdu", "xcached"));
+}
@@ -1,8 +1,10 @@
+mod cross_module_props;
 mod even_when_stylex_calls_come_first;
 mod keep_stylex_create_when_needed;
 mod naming_conventions;
 mod naming_conventions_with_dynamisms;
 mod naming_conventions_with_dynamisms_inline;
+mod nested_conditional_props;
 mod props_calls_with_jsx;
 mod setting_custom_import_paths;
 mod specific_edge_case_bugs;
@@ -0,0 +1,96 @@
+use crate::utils::transform::stringify_js;
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+};
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+const STYLES: &str = r#"
+  import * as stylex from '@stylexjs/stylex';
+  const styles = stylex.create({
+    base: { color: 'red' },
+    active: { color: 'blue' },
+    hovered: { backgroundColor: 'green' },
+    disabled: { opacity: 0.5 },
+  });
+"#;
+
+fn transform(input: &str, inlined_conditional_merge_limit: Option<usize>) -> String {
+  stringify_js(
+    &format!("{}{}", STYLES, input),
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      StyleXTransform::new_test_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          inlined_conditional_merge_limit,
+          ..Default::default()
+        }),
+      )
+    },
+  )
+}
+
+#[test]
+fn folds_array_with_logical_and() {
+  let output = transform(
+    "export const props = stylex.props([styles.base, isActive && styles.active]);",
+    None,
+  );
+
+  insta::assert_snapshot!("folds_array_with_logical_and", output);
+}
+
+#[test]
+fn folds_ternary_with_array_branch() {
+  let output = transform(
+    "export const props = stylex.props(isActive ? [styles.base, styles.active] : styles.disabled);",
+    None,
+  );
+
+  insta::assert_snapshot!("folds_ternary_with_array_branch", output);
+}
+
+#[test]
+fn folds_nested_ternaries() {
+  let output = transform(
+    "export const props = stylex.props(isActive ? styles.active : isHovered ? styles.hovered : styles.base);",
+    None,
+  );
+
+  insta::assert_snapshot!("folds_nested_ternaries", output);
+}
+
+#[test]
+fn folds_nullish_coalescing() {
+  let output = transform(
+    "export const props = stylex.props(styles.base, (isActive ? styles.active : null) ?? styles.disabled);",
+    None,
+  );
+
+  insta::assert_snapshot!("folds_nullish_coalescing", output);
+}
+
+#[test]
+fn keeps_runtime_merge_for_unsafe_nested_conditions() {
+  let output = transform(
+    "export const props = stylex.props(isActive ? [styles.active, isHovered() && styles.hovered] : null);",
+    None,
+  );
+
+  insta::assert_snapshot!("keeps_runtime_merge_for_unsafe_nested_conditions", output);
+}
+
+#[test]
+fn keeps_runtime_merge_above_condition_limit() {
+  let output = transform(
+    "export const props = stylex.props(isActive ? [styles.active, isHovered && styles.hovered] : styles.base);",
+    Some(1),
+  );
+
+  insta::assert_snapshot!("keeps_runtime_merge_above_condition_limit", output);
+}
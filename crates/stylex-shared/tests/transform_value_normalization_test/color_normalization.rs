@@ -0,0 +1,64 @@
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      enable_color_normalization: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  normalize_equivalent_colors,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        hex: { color: '#FFFFFF' },
+        shortHex: { color: '#fff' },
+        named: { color: 'white' },
+        rgb: { color: 'rgb(255 255 255)' },
+        red: { backgroundColor: '#FF0000', borderTopColor: 'hsl(0, 100%, 50%)' },
+        alpha: { color: 'rgba(0, 0, 0, 0.5)' },
+        untouched: {
+          color: 'var(--text)',
+          outlineColor: 'oklch(0.5 0.1 270deg)',
+        },
+        keyword: { caretColor: 'currentColor' },
+      });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  keep_color_spelling_by_default,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        hex: { color: '#FFFFFF' },
+        named: { color: 'white' },
+      });
+    "#
+);
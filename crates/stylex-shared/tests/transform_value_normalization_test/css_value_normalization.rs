@@ -140,7 +140,36 @@ test!(
   calc_preserves_spaces_around_plus_and_minus,
   r#"
       import stylex from 'stylex';
-      const styles = stylex.create({ x: { width: 'calc((100% + 3% -   100px) / 7)' } });
+      const styles = stylex.create({ x: { width: 'calc((100% + var(--gap) -   100px) / 7)' } });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  calc_folds_compatible_units,
+  r#"
+      import stylex from 'stylex';
+      const space = '8px';
+      const styles = stylex.create({ x: {
+        width: 'calc(8px * 2)',
+        height: `calc(${space} + 4px)`,
+        marginTop: 'calc(calc(4px * 2) - 2px)',
+        marginBottom: 'calc(100% - 10px + 4px)',
+        paddingTop: 'calc(var(--gap) + calc(2px * 3))',
+        paddingBottom: 'calc(100% - 20px)',
+        transform: 'translateX(calc(10px / 2))',
+      } });
     "#
 );
 
@@ -318,3 +347,70 @@ test!(
       const styles = stylex.create({ x: { color: 'red !important' } });
     "#
 );
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      enable_font_size_px_to_rem: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  normalize_equivalent_grid_values,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        x: {
+          gridTemplateColumns: '[full-start] repeat(2, minmax(0, 1fr)) [full-end]',
+          gridTemplateAreas: '"header header" "sidebar main"',
+          gridArea: '1 / span 2',
+        },
+        y: {
+          gridTemplateColumns: '[ full-start ]  repeat(2,minmax(0,1fr))  [full-end]',
+          gridTemplateAreas: '"header   header"  "sidebar main"',
+          gridArea: '1/2 span',
+        },
+        z: {
+          gridTemplateAreas: '"a ... b" ". . b"',
+          gridRow: 'auto / span name',
+        },
+      });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      enable_font_size_px_to_rem: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  normalize_equivalent_font_family_values,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        x: {
+          fontFamily: '"Inter", sans-serif',
+        },
+        y: {
+          fontFamily: 'Inter,sans-serif',
+        },
+        z: {
+          fontFamily: "'Helvetica Neue',  'serif'",
+        },
+      });
+    "#
+);
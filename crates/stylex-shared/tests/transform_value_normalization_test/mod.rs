@@ -1,2 +1,3 @@
+mod color_normalization;
 mod css_value_normalization;
 mod transform_font_size_with;
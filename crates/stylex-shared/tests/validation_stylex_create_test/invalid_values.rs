@@ -603,3 +603,51 @@ fn invalid_value_transition_property_width() {
     r#""#,
   )
 }
+
+#[test]
+#[should_panic(expected = r#"Invalid gridTemplateAreas value ""a a" "a b"""#)]
+fn invalid_value_grid_template_areas_not_rectangular() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import * as stylex from '@stylexjs/stylex';
+            const styles = stylex.create({ x: { gridTemplateAreas: '"a a" "a b"' } });
+          "#,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid gridTemplateAreas value ""a b" "c"""#)]
+fn invalid_value_grid_template_areas_uneven_rows() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import * as stylex from '@stylexjs/stylex';
+            const styles = stylex.create({ x: { gridTemplateAreas: '"a b" "c"' } });
+          "#,
+    r#""#,
+  )
+}
@@ -1,5 +1,6 @@
 mod invalid_properties;
 mod invalid_values;
+mod property_value_validation;
 mod style_declarations;
 mod style_rules;
 mod stylex_validation_create;
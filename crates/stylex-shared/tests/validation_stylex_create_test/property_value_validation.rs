@@ -0,0 +1,132 @@
+use stylex_shared::{
+  StyleXTransform,
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+fn transform_with_value_validation(input: &str) {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          enable_value_validation: Some(true),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    input,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid value "redd" for property "color"."#)]
+fn invalid_color_value() {
+  transform_with_value_validation(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: { color: 'redd' },
+      });
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid value "rotate(10)" for property "transform"."#)]
+fn invalid_transform_value() {
+  transform_with_value_validation(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: { transform: 'rotate(10)' },
+      });
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid value "10deg" for property "width"."#)]
+fn invalid_conditional_value() {
+  transform_with_value_validation(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: {
+          width: {
+            default: '100%',
+            '@media (min-width: 800px)': '10deg',
+          },
+        },
+      });
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid value "blur(red)" for property "filter"."#)]
+fn invalid_fallback_value() {
+  transform_with_value_validation(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({
+        root: { filter: ['blur(2px)', 'blur(red)'] },
+      });
+    "#,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(
+    tr.comments.clone(),
+    PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      enable_value_validation: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  valid_values_with_value_validation,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'var(--accent)',
+        transform: 'rotate(10deg)',
+        boxShadow: '0 1px 2px rgba(0, 0, 0, 0.1)',
+        transitionTimingFunction: 'ease-in-out',
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| StyleXTransform::new_test_with_pass(tr.comments.clone(), PluginPass::default(), None),
+  invalid_values_without_value_validation,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'redd',
+        transform: 'rotate(10)',
+      },
+    });
+  "#
+);
@@ -451,6 +451,68 @@ test!(
         "#
 );
 
+#[test]
+#[should_panic(expected = "Invalid @supports condition syntax.")]
+fn invalid_supports_condition_syntax() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import * as stylex from '@stylexjs/stylex';
+            const styles = stylex.create({
+              root: {
+                display: {
+                  default: 'block',
+                  '@supports (display:grid': 'grid',
+                },
+              },
+            });
+          "#,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Invalid container query syntax.")]
+fn invalid_container_query_syntax() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import * as stylex from '@stylexjs/stylex';
+            const styles = stylex.create({
+              root: {
+                color: {
+                  default: 'red',
+                  '@container (color: red)': 'blue',
+                },
+              },
+            });
+          "#,
+    r#""#,
+  )
+}
+
 #[ignore]
 #[test]
 #[should_panic(expected = "The pseudo selector or at-rule '::before' is not supported")]
@@ -338,3 +338,116 @@ test!(
           });
         "#
 );
+
+#[test]
+#[should_panic(
+  expected = r#"Value "0% red" of "stops" does not match the syntax "<length-percentage>+"."#
+)]
+fn invalid_value_for_list_syntax() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::new(None, None),
+        None,
+      )
+    },
+    r#"
+          import * as stylex from '@stylexjs/stylex';
+          export const vars = stylex.defineVars({
+            stops: { syntax: '<length-percentage>+', value: '0% red' },
+          });
+        "#,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Value "10px" of "weight" does not match the syntax "<number> | <custom-ident>"."#
+)]
+fn invalid_value_for_union_syntax() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::new(None, None),
+        None,
+      )
+    },
+    r#"
+          import * as stylex from '@stylexjs/stylex';
+          export const vars = stylex.defineVars({
+            weight: { syntax: '<number> | <custom-ident>', value: '10px' },
+          });
+        "#,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Value "redd" of "accent" does not match the syntax "<color>"."#)]
+fn invalid_typed_default_value() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::new(None, None),
+        None,
+      )
+    },
+    r#"
+          import * as stylex from '@stylexjs/stylex';
+          export const vars = stylex.defineVars({
+            accent: stylex.types.color('redd'),
+          });
+        "#,
+    r#""#,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Value "10deg" of "gap @media (min-width: 800px)" does not match the syntax "<length>"."#
+)]
+fn invalid_typed_at_rule_value() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Option::None,
+    |tr| {
+      StyleXTransform::new_test_force_runtime_injection_with_pass(
+        tr.comments.clone(),
+        PluginPass::new(None, None),
+        None,
+      )
+    },
+    r#"
+          import * as stylex from '@stylexjs/stylex';
+          export const vars = stylex.defineVars({
+            gap: stylex.types.length({
+              default: '8px',
+              '@media (min-width: 800px)': '10deg',
+            }),
+          });
+        "#,
+    r#""#,
+  )
+}
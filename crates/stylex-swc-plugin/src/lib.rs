@@ -0,0 +1,140 @@
+use std::path::PathBuf;
+
+use serde_json::{Value, json};
+use stylex_shared::{
+  StyleXTransform,
+  shared::{
+    enums::data_structures::injectable_style::InjectableStyleBaseKind,
+    structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  },
+};
+use swc_core::{
+  common::{
+    FileName, Spanned,
+    comments::{Comment, CommentKind, Comments},
+  },
+  ecma::{
+    ast::{Pass, Program, fn_pass},
+    visit::fold_pass,
+  },
+  plugin::{
+    metadata::TransformPluginMetadataContextKind, plugin_transform,
+    proxies::TransformPluginProgramMetadata,
+  },
+};
+
+/// Marks the beginning of the collected rules in the emitted leading comment
+pub const METADATA_START: &str = "__stylex_metadata_start__";
+/// Marks the end of the collected rules in the emitted leading comment
+pub const METADATA_END: &str = "__stylex_metadata_end__";
+
+#[plugin_transform]
+pub fn process_transform(
+  mut program: Program,
+  metadata: TransformPluginProgramMetadata,
+) -> Program {
+  let mut config = metadata
+    .get_transform_plugin_config()
+    .map(|config| parse_config(&config))
+    .unwrap_or_default();
+
+  let plugin_pass = PluginPass {
+    cwd: metadata
+      .get_context(&TransformPluginMetadataContextKind::Cwd)
+      .map(PathBuf::from),
+    filename: metadata
+      .get_context(&TransformPluginMetadataContextKind::Filename)
+      .map_or(FileName::Anon, |filename| {
+        FileName::Real(PathBuf::from(filename))
+      }),
+  };
+
+  let Some(comments) = metadata.comments else {
+    return program;
+  };
+
+  stylex_plugin(comments, plugin_pass, &mut config).process(&mut program);
+
+  program
+}
+
+/// Reads the JSON plugin config, which uses the option names of the NAPI-RS
+/// compiler.
+pub fn parse_config(config: &str) -> StyleXOptionsParams {
+  serde_json::from_str::<StyleXOptionsParams>(config)
+    .unwrap_or_else(|error| panic!("Invalid StyleX plugin config: {}", error))
+}
+
+/// Runs the StyleX transform and attaches the collected rules to the program
+/// as a leading comment, since a Wasm plugin has no other channel to return
+/// data to the host bundler.
+pub fn stylex_plugin<C: Comments>(
+  comments: C,
+  plugin_pass: PluginPass,
+  config: &mut StyleXOptionsParams,
+) -> impl Pass + use<C> {
+  let mut stylex = StyleXTransform::new(comments, plugin_pass, config);
+
+  fn_pass(move |program| {
+    fold_pass(&mut stylex).process(program);
+
+    let rules = collect_rules(&stylex);
+
+    if rules.is_empty() {
+      return;
+    }
+
+    stylex.comments.add_leading(
+      program.span().lo,
+      Comment {
+        kind: CommentKind::Block,
+        span: program.span(),
+        text: format!(
+          "{}{}{}",
+          METADATA_START,
+          escape_comment_payload(&Value::Array(rules).to_string()),
+          METADATA_END
+        )
+        .into(),
+      },
+    );
+  })
+}
+
+/// A `*/` in a rule, e.g. in a `content` value, would end the comment early
+/// and leave the rest of the payload as code. `*` only appears inside JSON
+/// strings, so it is escaped as `\u002a`, which decodes back to the same rule.
+fn escape_comment_payload(payload: &str) -> String {
+  payload.replace('*', "\\u002a")
+}
+
+/// Serializes the metadata in the `[className, { ltr, rtl }, priority]` shape
+/// accepted by `processStylexRules`.
+fn collect_rules<C: Comments>(stylex: &StyleXTransform<C>) -> Vec<Value> {
+  stylex
+    .state
+    .metadata
+    .values()
+    .flatten()
+    .map(|meta| {
+      let mut style = match meta.get_style() {
+        InjectableStyleBaseKind::Regular(style) => json!({
+          "ltr": style.ltr,
+          "rtl": style.rtl,
+        }),
+        InjectableStyleBaseKind::Const(style) => json!({
+          "ltr": style.ltr,
+          "rtl": style.rtl,
+          "constKey": style.const_key,
+          "constVal": style.const_value,
+        }),
+      };
+
+      if let Some(source) = meta.get_source() {
+        style["source"] = json!(source);
+      }
+
+      json!([meta.get_class_name(), style, meta.get_priority()])
+    })
+    .collect()
+}
@@ -0,0 +1,8 @@
+/*__stylex_metadata_start__[["x1e2nbdu",{"ltr":".x1e2nbdu{color:red}","rtl":null},3000.0],["xfawy5m",{"ltr":".xfawy5m{padding:4px}","rtl":null},1000.0]]__stylex_metadata_end__*/ import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu",
+        kmVPX3: "xfawy5m",
+        $$css: true
+    }
+};
@@ -0,0 +1,7 @@
+/*__stylex_metadata_start__[["xgh2fve",{"ltr":".xgh2fve{content:\"\u002a/ alert(1) /\u002a\"}","rtl":null},3000.0]]__stylex_metadata_end__*/ import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kah6P1: "xgh2fve",
+        $$css: true
+    }
+};
@@ -0,0 +1,117 @@
+use stylex_shared::shared::structures::{
+  named_import_source::{ImportSources, NamedImportSource},
+  plugin_pass::PluginPass,
+  stylex_options::StyleXOptionsParams,
+};
+use stylex_swc_plugin::{parse_config, stylex_plugin};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real("/app/src/index.tsx".into()),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| stylex_plugin(
+    tr.comments.clone(),
+    plugin_pass(),
+    &mut StyleXOptionsParams::default()
+  ),
+  emits_metadata_comment,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'red',
+        padding: 4,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| stylex_plugin(
+    tr.comments.clone(),
+    plugin_pass(),
+    &mut StyleXOptionsParams::default()
+  ),
+  skips_metadata_comment_without_styles,
+  r#"
+    export const value = 1;
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| stylex_plugin(
+    tr.comments.clone(),
+    plugin_pass(),
+    &mut StyleXOptionsParams::default()
+  ),
+  escapes_comment_end_in_metadata_comment,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        content: '"*/ alert(1) /*"',
+      },
+    });
+  "#
+);
+
+#[test]
+fn parses_napi_style_config() {
+  let config = parse_config(
+    r#"{
+      "dev": false,
+      "importSources": ["@stylexjs/stylex", { "as": "css", "from": "@acme/styles" }],
+      "aliases": { "@/*": "src/*", "~/*": ["lib/*", "vendor/*"] },
+      "enableLTRRTLComments": true,
+      "unstable_moduleResolution": { "type": "commonJS", "rootDir": "/app" }
+    }"#,
+  );
+
+  assert_eq!(
+    config.import_sources,
+    Some(vec![
+      ImportSources::Regular("@stylexjs/stylex".to_string()),
+      ImportSources::Named(NamedImportSource {
+        r#as: "css".to_string(),
+        from: "@acme/styles".to_string(),
+      }),
+    ])
+  );
+
+  let aliases = config.aliases.unwrap();
+
+  assert_eq!(aliases["@/*"], vec!["src/*"]);
+  assert_eq!(aliases["~/*"], vec!["lib/*", "vendor/*"]);
+  assert_eq!(config.enable_ltr_rtl_comments, Some(true));
+  assert_eq!(
+    config
+      .unstable_module_resolution
+      .unwrap()
+      .root_dir
+      .as_deref(),
+    Some("/app")
+  );
+}